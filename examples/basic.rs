@@ -1,20 +1,22 @@
 use raylib::rcore;
 use raylib::rtext;
 pub fn main() {
-    rcore::init_window(800, 450, "raylib-rs example - basic window");
+    let mut rl = rcore::init()
+        .size(800, 450)
+        .title("raylib-rs example - basic window")
+        .build()
+        .unwrap();
 
-    while !rcore::window_should_close() {
-        rcore::begin_drawing();
-        rcore::clear_background(raylib::colors::WHITE);
+    while !rcore::window_should_close(&rl) {
+        let mut d = rcore::begin_drawing(&mut rl);
+        rcore::clear_background(&mut d, raylib::colors::WHITE);
         rtext::draw_text(
+            &mut d,
             "Congrats! you created your first window!",
             190,
             200,
             20,
             raylib::colors::BLACK,
         );
-        rcore::end_drawing();
     }
-
-    rcore::close_window();
 }
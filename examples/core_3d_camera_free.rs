@@ -4,62 +4,42 @@ use raylib::rcore;
 use raylib::rcore::is_key_pressed;
 use raylib::rmodels;
 use raylib::rtext;
-use raylib::Camera;
-use raylib::Vector3;
+use raylib::{Camera, Vector3};
 
 pub fn main() {
-    rcore::init_window(800, 450, "raylib-ffi example - camera free");
+    let mut rl = rcore::init()
+        .size(800, 450)
+        .title("raylib-ffi example - camera free")
+        .target_fps(60)
+        .build()
+        .unwrap();
     let mut camera = Camera {
-        position: Vector3 {
-            x: 10.0,
-            y: 10.0,
-            z: 10.0,
-        },
-        target: Vector3 {
-            x: 0.0,
-            y: 0.0,
-            z: 0.0,
-        },
-        up: Vector3 {
-            x: 0.0,
-            y: 1.0,
-            z: 0.0,
-        },
+        position: Vector3::new(10.0, 10.0, 10.0),
+        target: Vector3::zero(),
+        up: Vector3::new(0.0, 1.0, 0.0),
         fovy: 45.0,
         projection: CameraProjection::Perspective as i32,
     };
 
-    let cube_position = Vector3 {
-        x: 0.0,
-        y: 0.0,
-        z: 0.0,
-    };
+    let cube_position = Vector3::zero();
 
-    rcore::disable_cursor();
-    rcore::set_target_fps(60);
-    while !rcore::window_should_close() {
-        rcore::clear_background(colors::WHITE);
-        rcore::update_camera(&mut camera, CameraMode::Free);
-        if is_key_pressed(KeyboardKey::Z) {
-            camera.target = Vector3 {
-                x: 0.0,
-                y: 0.0,
-                z: 0.0,
-            };
+    rcore::disable_cursor(&rl);
+    while !rcore::window_should_close(&rl) {
+        rcore::update_camera(&rl, &mut camera, CameraMode::Free);
+        if is_key_pressed(&rl, KeyboardKey::Z) {
+            camera.target = Vector3::zero();
         }
 
-        rcore::begin_drawing();
-
-        rcore::begin_mode_3d(camera);
-        rmodels::draw_cube(cube_position, 2.0, 2.0, 2.0, colors::RED);
-        rmodels::draw_cube_wires(cube_position, 2.0, 2.0, 2.0, colors::MAROON);
-        rmodels::draw_grid(10, 1.0);
+        let mut d = rcore::begin_drawing(&mut rl);
+        rcore::clear_background(&mut d, colors::WHITE);
 
-        rcore::end_mode_3d();
-        rtext::draw_fps(5, 5);
+        {
+            let mut m = rcore::begin_mode_3d(&mut d, camera);
+            rmodels::draw_cube(&mut m, cube_position, 2.0, 2.0, 2.0, colors::RED);
+            rmodels::draw_cube_wires(&mut m, cube_position, 2.0, 2.0, 2.0, colors::MAROON);
+            rmodels::draw_grid(&mut m, 10, 1.0);
+        }
 
-        rcore::end_drawing();
+        rtext::draw_fps(&mut d, 5, 5);
     }
-
-    rcore::close_window();
 }
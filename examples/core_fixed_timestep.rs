@@ -0,0 +1,66 @@
+use raylib::app::{App, GameLoop};
+use raylib::rcore::{self, DrawHandle, RaylibHandle};
+use raylib::rshapes;
+use raylib::rtext;
+
+const SCREEN_WIDTH: i32 = 800;
+const SCREEN_HEIGHT: i32 = 450;
+const BOX_SIZE: i32 = 40;
+
+struct Bouncer {
+    previous_x: f32,
+    x: f32,
+    speed: f32,
+}
+
+impl App for Bouncer {
+    fn update(&mut self, _rl: &mut RaylibHandle, dt: f32) {
+        self.previous_x = self.x;
+        self.x += self.speed * dt;
+        if self.x < 0.0 || self.x > (SCREEN_WIDTH - BOX_SIZE) as f32 {
+            self.speed = -self.speed;
+        }
+    }
+
+    fn draw(&mut self, d: &mut DrawHandle<'_>, alpha: f32) {
+        // Interpolate between the last two updates, the simulation only runs at 20 Hz
+        let x = self.previous_x + (self.x - self.previous_x) * alpha;
+
+        rcore::clear_background(d, raylib::colors::RAYWHITE);
+        rshapes::draw_rectangle(
+            d,
+            x as i32,
+            SCREEN_HEIGHT / 2 - BOX_SIZE / 2,
+            BOX_SIZE,
+            BOX_SIZE,
+            raylib::colors::MAROON,
+        );
+        rtext::draw_text(
+            d,
+            "20 updates per second, drawn at 60 FPS",
+            10,
+            40,
+            20,
+            raylib::colors::DARKGRAY,
+        );
+        rtext::draw_fps(d, 10, 10);
+    }
+}
+
+pub fn main() {
+    let mut rl = rcore::init()
+        .size(SCREEN_WIDTH, SCREEN_HEIGHT)
+        .title("raylib-rs example - fixed timestep")
+        .target_fps(60)
+        .build()
+        .unwrap();
+
+    let mut bouncer = Bouncer {
+        previous_x: 0.0,
+        x: 0.0,
+        speed: 300.0,
+    };
+    GameLoop::new(20)
+        .pause_when_unfocused(true)
+        .run(&mut rl, &mut bouncer);
+}
@@ -0,0 +1,303 @@
+//! Fixed-timestep game loop
+//!
+//! `run()` owns the `while !window_should_close()` loop: it calls `App::update()` at a
+//! fixed rate, catching up on slow frames up to a limit, and `App::draw()` once per frame
+//! with the interpolation factor between the last two updates.
+
+use crate::platform::Platform;
+use crate::rcore::{self, DrawHandle, RaylibHandle};
+
+/// Default limit on the time simulated in a single frame, in seconds
+pub const DEFAULT_MAX_FRAME_TIME: f64 = 0.25;
+
+/// Source of the time read by the game loop, in seconds
+pub trait Clock {
+    /// Get elapsed time in seconds
+    fn time(&self) -> f64;
+}
+
+impl<P: Platform> Clock for P {
+    fn time(&self) -> f64 {
+        self.get_time()
+    }
+}
+
+/// Clock advanced by hand, to drive a `GameLoop` without a window
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ManualClock {
+    time: f64,
+}
+
+impl ManualClock {
+    /// Create a clock at time 0.0
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Advance the time by `seconds`
+    pub fn advance(&mut self, seconds: f64) {
+        self.time += seconds;
+    }
+
+    /// Jump to `time` in seconds, going back in time runs no update
+    pub fn set_time(&mut self, time: f64) {
+        self.time = time;
+    }
+}
+
+impl Clock for ManualClock {
+    fn time(&self) -> f64 {
+        self.time
+    }
+}
+
+/// Accumulator splitting the elapsed time into fixed steps
+#[derive(Debug, Clone)]
+pub struct FixedTimestep {
+    step: f64,
+    max_frame_time: f64,
+    accumulator: f64,
+    last_time: Option<f64>,
+}
+
+impl FixedTimestep {
+    /// Create a timestep running `updates_per_second` updates per second
+    ///
+    /// Panics if `updates_per_second` is 0.
+    pub fn new(updates_per_second: u32) -> Self {
+        assert!(updates_per_second > 0, "updates_per_second must not be 0");
+        Self {
+            step: 1.0 / updates_per_second as f64,
+            max_frame_time: DEFAULT_MAX_FRAME_TIME,
+            accumulator: 0.0,
+            last_time: None,
+        }
+    }
+
+    /// Set the limit on the time simulated in a single frame
+    ///
+    /// Longer frames are clamped so a slow frame can not trigger ever more updates
+    /// (spiral of death), the simulation slows down instead.
+    ///
+    /// Panics if `seconds` is not positive (0.0 would stop every update) or is NaN.
+    pub fn set_max_frame_time(&mut self, seconds: f64) {
+        assert!(seconds > 0.0, "max frame time must be a positive number");
+        self.max_frame_time = seconds;
+    }
+
+    /// Time of a step in seconds, the `dt` passed to every update
+    pub fn step(&self) -> f32 {
+        self.step as f32
+    }
+
+    /// Advance to `now`, returns the number of updates to run
+    ///
+    /// The first call only starts the timestep and returns 0.
+    pub fn advance(&mut self, now: f64) -> u32 {
+        let frame_time = match self.last_time {
+            Some(last_time) => (now - last_time).clamp(0.0, self.max_frame_time),
+            None => 0.0,
+        };
+        self.last_time = Some(now);
+        self.accumulator += frame_time;
+
+        let mut updates = 0;
+        while self.accumulator >= self.step {
+            self.accumulator -= self.step;
+            updates += 1;
+        }
+        updates
+    }
+
+    /// Move to `now` without running updates, the skipped time is never caught up
+    pub fn skip(&mut self, now: f64) {
+        self.last_time = Some(now);
+    }
+
+    /// Interpolation factor between the previous and the current update, from 0.0 to 1.0
+    /// (excluded)
+    pub fn alpha(&self) -> f32 {
+        // A leftover just below a step would round up to 1.0 as f32
+        ((self.accumulator / self.step) as f32).min(1.0 - f32::EPSILON / 2.0)
+    }
+}
+
+/// Game run by `run()`
+pub trait App {
+    /// Advance the simulation by one fixed step of `dt` seconds
+    fn update(&mut self, rl: &mut RaylibHandle, dt: f32);
+    /// Draw the current frame, `alpha` is how far the frame is between the previous and
+    /// the current update (0.0 to 1.0) to interpolate positions
+    fn draw(&mut self, d: &mut DrawHandle<'_>, alpha: f32);
+}
+
+/// Fixed-timestep game loop configuration and state
+///
+/// The frame rate is still set with `rcore::set_target_fps()`, independently of the update rate.
+#[derive(Debug, Clone)]
+pub struct GameLoop {
+    timestep: FixedTimestep,
+    pause_when_unfocused: bool,
+    paused: bool,
+}
+
+impl GameLoop {
+    /// Create a game loop running `updates_per_second` updates per second
+    pub fn new(updates_per_second: u32) -> Self {
+        Self {
+            timestep: FixedTimestep::new(updates_per_second),
+            pause_when_unfocused: false,
+            paused: false,
+        }
+    }
+
+    /// Set the limit on the time simulated in a single frame, see `FixedTimestep`
+    ///
+    /// Panics if `seconds` is not positive or is NaN.
+    pub fn max_frame_time(mut self, seconds: f64) -> Self {
+        self.timestep.set_max_frame_time(seconds);
+        self
+    }
+
+    /// Stop updating while the window is not focused, frames are still drawn
+    pub fn pause_when_unfocused(mut self, pause: bool) -> Self {
+        self.pause_when_unfocused = pause;
+        self
+    }
+
+    /// Check if the last frame was paused
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Fixed timestep of the loop
+    pub fn timestep(&self) -> &FixedTimestep {
+        &self.timestep
+    }
+
+    /// Advance the loop by one frame, returns the number of updates to run
+    pub fn advance(&mut self, clock: &impl Clock, focused: bool) -> u32 {
+        self.paused = self.pause_when_unfocused && !focused;
+        if self.paused {
+            self.timestep.skip(clock.time());
+            0
+        } else {
+            self.timestep.advance(clock.time())
+        }
+    }
+
+    /// Run the loop until the window should close
+    pub fn run(mut self, rl: &mut RaylibHandle, app: &mut impl App) {
+        let dt = self.timestep.step();
+        while !rcore::window_should_close(rl) {
+            let focused = rcore::is_window_focused(rl);
+            let updates = self.advance(rl, focused);
+            for _ in 0..updates {
+                app.update(rl, dt);
+            }
+            let alpha = self.timestep.alpha();
+            let mut d = rcore::begin_drawing(rl);
+            app.draw(&mut d, alpha);
+        }
+    }
+}
+
+/// Run `app` at `updates_per_second` updates per second until the window should close
+pub fn run(rl: &mut RaylibHandle, updates_per_second: u32, app: &mut impl App) {
+    GameLoop::new(updates_per_second).run(rl, app);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timestep_runs_whole_steps() {
+        // 0.25 s steps are exact in binary, so no rounding is involved
+        let mut timestep = FixedTimestep::new(4);
+        timestep.set_max_frame_time(10.0);
+        assert_eq!(timestep.step(), 0.25);
+        assert_eq!(timestep.advance(0.0), 0);
+        assert_eq!(timestep.advance(1.0), 4);
+        assert_eq!(timestep.alpha(), 0.0);
+        assert_eq!(timestep.advance(1.125), 0);
+        assert_eq!(timestep.alpha(), 0.5);
+        assert_eq!(timestep.advance(1.375), 1);
+        assert_eq!(timestep.alpha(), 0.5);
+    }
+
+    #[test]
+    fn timestep_clamps_long_frames() {
+        let mut timestep = FixedTimestep::new(8);
+        timestep.advance(0.0);
+        // DEFAULT_MAX_FRAME_TIME (0.25 s) of a 100 s frame is simulated
+        assert_eq!(timestep.advance(100.0), 2);
+        assert_eq!(timestep.alpha(), 0.0);
+        timestep.set_max_frame_time(0.5);
+        assert_eq!(timestep.advance(200.0), 4);
+        // Time going backwards runs no update
+        assert_eq!(timestep.advance(150.0), 0);
+    }
+
+    #[test]
+    fn timestep_alpha_stays_below_one() {
+        let mut timestep = FixedTimestep::new(60);
+        let mut clock = ManualClock::new();
+        timestep.advance(clock.time());
+        for frame in 0..1000 {
+            clock.advance(0.001 + (frame % 7) as f64 * 0.003);
+            timestep.advance(clock.time());
+            let alpha = timestep.alpha();
+            assert!(
+                (0.0..1.0).contains(&alpha),
+                "alpha {} at frame {}",
+                alpha,
+                frame
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn negative_max_frame_time_panics() {
+        FixedTimestep::new(60).set_max_frame_time(-1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_max_frame_time_panics() {
+        FixedTimestep::new(60).set_max_frame_time(0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn nan_max_frame_time_panics() {
+        GameLoop::new(60).max_frame_time(f64::NAN);
+    }
+
+    #[test]
+    fn game_loop_pauses_when_unfocused() {
+        let mut game_loop = GameLoop::new(4).pause_when_unfocused(true);
+        let mut clock = ManualClock::new();
+        assert_eq!(game_loop.advance(&clock, true), 0);
+
+        clock.advance(0.5);
+        assert_eq!(game_loop.advance(&clock, false), 0);
+        assert!(game_loop.is_paused());
+
+        // The paused time is skipped, not caught up
+        clock.advance(0.25);
+        assert_eq!(game_loop.advance(&clock, true), 1);
+        assert!(!game_loop.is_paused());
+    }
+
+    #[test]
+    fn game_loop_runs_unfocused_by_default() {
+        let mut game_loop = GameLoop::new(4);
+        let mut clock = ManualClock::new();
+        game_loop.advance(&clock, true);
+        clock.set_time(0.25);
+        assert_eq!(game_loop.advance(&clock, false), 1);
+        assert!(!game_loop.is_paused());
+    }
+}
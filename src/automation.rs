@@ -0,0 +1,904 @@
+//! Automation events: owned event lists, recording and replay
+//!
+//! raylib records input events into an `AutomationEventList` through a pointer it keeps
+//! until recording is stopped. `Recorder` mutably borrows an `AutomationEvents` list for the
+//! whole recording, so the list can not be moved, read or dropped while raylib writes to it.
+//!
+//! Event lists can also be built, read and written in Rust without a window: the
+//! `AutomationEvent` constructors script input, `parse_events()` and `write_events()` handle
+//! the text format of LoadAutomationEventList() and ExportAutomationEventList().
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicPtr, Ordering};
+
+use crate::enums::{AutomationEventType, GamepadAxis, GamepadButton, Gesture};
+use crate::enums::{KeyboardKey, MouseButton};
+use crate::rcore::{self, RaylibHandle};
+use crate::{Error, LoadError};
+
+/// Maximum number of automation events raylib records to a list (MAX_AUTOMATION_EVENTS)
+pub const MAX_AUTOMATION_EVENTS: usize = 16384;
+
+/// Input state array sizes of raylib (config.h), events are checked against them
+const MAX_KEYBOARD_KEYS: i32 = 512;
+const MAX_MOUSE_BUTTONS: i32 = 8;
+const MAX_GAMEPADS: i32 = 4;
+const MAX_GAMEPAD_AXIS: i32 = 8;
+const MAX_GAMEPAD_BUTTONS: i32 = 32;
+const MAX_TOUCH_POINTS: i32 = 8;
+
+/// Event type names written by ExportAutomationEventList(), indexed by event type
+const EVENT_TYPE_NAMES: [&str; 24] = [
+    "EVENT_NONE",
+    "INPUT_KEY_UP",
+    "INPUT_KEY_DOWN",
+    "INPUT_KEY_PRESSED",
+    "INPUT_KEY_RELEASED",
+    "INPUT_MOUSE_BUTTON_UP",
+    "INPUT_MOUSE_BUTTON_DOWN",
+    "INPUT_MOUSE_POSITION",
+    "INPUT_MOUSE_WHEEL_MOTION",
+    "INPUT_GAMEPAD_CONNECT",
+    "INPUT_GAMEPAD_DISCONNECT",
+    "INPUT_GAMEPAD_BUTTON_UP",
+    "INPUT_GAMEPAD_BUTTON_DOWN",
+    "INPUT_GAMEPAD_AXIS_MOTION",
+    "INPUT_TOUCH_UP",
+    "INPUT_TOUCH_DOWN",
+    "INPUT_TOUCH_POSITION",
+    "INPUT_GESTURE",
+    "WINDOW_CLOSE",
+    "WINDOW_MAXIMIZE",
+    "WINDOW_MINIMIZE",
+    "WINDOW_RESIZE",
+    "ACTION_TAKE_SCREENSHOT",
+    "ACTION_SETTARGETFPS",
+];
+
+/// List raylib is recording to, null when not recording
+static RECORDING_LIST: AtomicPtr<raylib_ffi::AutomationEventList> =
+    AtomicPtr::new(std::ptr::null_mut());
+
+/// Automation event, an input or window event recorded at a frame
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct AutomationEvent {
+    raw: raylib_ffi::AutomationEvent,
+}
+
+impl AutomationEvent {
+    /// Create an event played at `frame`, see `AutomationEventType` for the params
+    ///
+    /// Fails with `Error::InvalidAutomationEvent` if a key, button, gamepad, axis or touch
+    /// id parameter is out of the range raylib supports.
+    pub fn new(
+        frame: u32,
+        event_type: AutomationEventType,
+        params: [i32; 4],
+    ) -> crate::Result<Self> {
+        let event = Self::new_unchecked(frame, event_type, params);
+        event.validate()?;
+        Ok(event)
+    }
+
+    /// Create an event from parameters that are known to be in range
+    fn new_unchecked(frame: u32, event_type: AutomationEventType, params: [i32; 4]) -> Self {
+        Self {
+            raw: raylib_ffi::AutomationEvent {
+                frame,
+                type_: event_type as u32,
+                params,
+            },
+        }
+    }
+
+    /// Key released at `frame`
+    pub fn key_up(frame: u32, key: KeyboardKey) -> Self {
+        Self::new_unchecked(
+            frame,
+            AutomationEventType::InputKeyUp,
+            [key as i32, 0, 0, 0],
+        )
+    }
+
+    /// Key held down at `frame`, it stays down until a `key_up()` event
+    pub fn key_down(frame: u32, key: KeyboardKey) -> Self {
+        Self::new_unchecked(
+            frame,
+            AutomationEventType::InputKeyDown,
+            [key as i32, 0, 0, 0],
+        )
+    }
+
+    /// Key pressed at `frame` and released the frame after
+    pub fn key_press(frame: u32, key: KeyboardKey) -> [Self; 2] {
+        [Self::key_down(frame, key), Self::key_up(frame + 1, key)]
+    }
+
+    /// Mouse button released at `frame`
+    pub fn mouse_button_up(frame: u32, button: MouseButton) -> Self {
+        Self::new_unchecked(
+            frame,
+            AutomationEventType::InputMouseButtonUp,
+            [button as i32, 0, 0, 0],
+        )
+    }
+
+    /// Mouse button held down at `frame`, it stays down until a `mouse_button_up()` event
+    pub fn mouse_button_down(frame: u32, button: MouseButton) -> Self {
+        Self::new_unchecked(
+            frame,
+            AutomationEventType::InputMouseButtonDown,
+            [button as i32, 0, 0, 0],
+        )
+    }
+
+    /// Mouse moved to `x`, `y` at `frame`
+    pub fn mouse_position(frame: u32, x: i32, y: i32) -> Self {
+        Self::new_unchecked(frame, AutomationEventType::InputMousePosition, [x, y, 0, 0])
+    }
+
+    /// Mouse wheel moved at `frame`
+    ///
+    /// raylib 5.0 only plays the X movement of this event.
+    pub fn mouse_wheel_motion(frame: u32, x: i32, y: i32) -> Self {
+        Self::new_unchecked(
+            frame,
+            AutomationEventType::InputMouseWheelMotion,
+            [x, y, 0, 0],
+        )
+    }
+
+    /// Gamepad connected at `frame`, fails if `gamepad` is not below MAX_GAMEPADS
+    pub fn gamepad_connect(frame: u32, gamepad: i32) -> crate::Result<Self> {
+        Self::new(
+            frame,
+            AutomationEventType::InputGamepadConnect,
+            [gamepad, 0, 0, 0],
+        )
+    }
+
+    /// Gamepad disconnected at `frame`, fails if `gamepad` is not below MAX_GAMEPADS
+    pub fn gamepad_disconnect(frame: u32, gamepad: i32) -> crate::Result<Self> {
+        Self::new(
+            frame,
+            AutomationEventType::InputGamepadDisconnect,
+            [gamepad, 0, 0, 0],
+        )
+    }
+
+    /// Gamepad button released at `frame`, fails if `gamepad` is not below MAX_GAMEPADS
+    pub fn gamepad_button_up(
+        frame: u32,
+        gamepad: i32,
+        button: GamepadButton,
+    ) -> crate::Result<Self> {
+        Self::new(
+            frame,
+            AutomationEventType::InputGamepadButtonUp,
+            [gamepad, button as i32, 0, 0],
+        )
+    }
+
+    /// Gamepad button held down at `frame`, it stays down until a `gamepad_button_up()` event
+    ///
+    /// Fails if `gamepad` is not below MAX_GAMEPADS.
+    pub fn gamepad_button_down(
+        frame: u32,
+        gamepad: i32,
+        button: GamepadButton,
+    ) -> crate::Result<Self> {
+        Self::new(
+            frame,
+            AutomationEventType::InputGamepadButtonDown,
+            [gamepad, button as i32, 0, 0],
+        )
+    }
+
+    /// Gamepad axis moved to `value` (-1.0 to 1.0) at `frame`
+    ///
+    /// Fails if `gamepad` is not below MAX_GAMEPADS.
+    pub fn gamepad_axis_motion(
+        frame: u32,
+        gamepad: i32,
+        axis: GamepadAxis,
+        value: f32,
+    ) -> crate::Result<Self> {
+        // Stored the way raylib plays it back: value*32768
+        let value = (value.clamp(-1.0, 1.0) * 32768.0) as i32;
+        Self::new(
+            frame,
+            AutomationEventType::InputGamepadAxisMotion,
+            [gamepad, axis as i32, value, 0],
+        )
+    }
+
+    /// Touch point released at `frame`, fails if `id` is not below MAX_TOUCH_POINTS
+    pub fn touch_up(frame: u32, id: i32) -> crate::Result<Self> {
+        Self::new(frame, AutomationEventType::InputTouchUp, [id, 0, 0, 0])
+    }
+
+    /// Touch point pressed at `frame`, fails if `id` is not below MAX_TOUCH_POINTS
+    pub fn touch_down(frame: u32, id: i32) -> crate::Result<Self> {
+        Self::new(frame, AutomationEventType::InputTouchDown, [id, 0, 0, 0])
+    }
+
+    /// Touch point moved to `x`, `y` at `frame`, fails if `id` is not below MAX_TOUCH_POINTS
+    pub fn touch_position(frame: u32, id: i32, x: i32, y: i32) -> crate::Result<Self> {
+        Self::new(
+            frame,
+            AutomationEventType::InputTouchPosition,
+            [id, x, y, 0],
+        )
+    }
+
+    /// Gesture detected at `frame`
+    pub fn gesture(frame: u32, gesture: Gesture) -> Self {
+        Self::new_unchecked(
+            frame,
+            AutomationEventType::InputGesture,
+            [gesture as i32, 0, 0, 0],
+        )
+    }
+
+    /// Window close requested at `frame`
+    pub fn window_close(frame: u32) -> Self {
+        Self::new_unchecked(frame, AutomationEventType::WindowClose, [0; 4])
+    }
+
+    /// Window maximized at `frame`
+    pub fn window_maximize(frame: u32) -> Self {
+        Self::new_unchecked(frame, AutomationEventType::WindowMaximize, [0; 4])
+    }
+
+    /// Window minimized at `frame`
+    pub fn window_minimize(frame: u32) -> Self {
+        Self::new_unchecked(frame, AutomationEventType::WindowMinimize, [0; 4])
+    }
+
+    /// Window resized at `frame`
+    pub fn window_resize(frame: u32, width: i32, height: i32) -> Self {
+        Self::new_unchecked(
+            frame,
+            AutomationEventType::WindowResize,
+            [width, height, 0, 0],
+        )
+    }
+
+    /// Screenshot taken at `frame`
+    pub fn take_screenshot(frame: u32) -> Self {
+        Self::new_unchecked(frame, AutomationEventType::ActionTakeScreenshot, [0; 4])
+    }
+
+    /// Target FPS set at `frame`
+    pub fn set_target_fps(frame: u32, fps: i32) -> Self {
+        Self::new_unchecked(
+            frame,
+            AutomationEventType::ActionSetTargetFps,
+            [fps, 0, 0, 0],
+        )
+    }
+
+    /// Check that raylib can play the event
+    ///
+    /// PlayAutomationEvent() indexes its input state arrays with the event parameters
+    /// without bounds checks, fails with `Error::InvalidAutomationEvent` if the type is
+    /// unknown or a key, button, gamepad, axis or touch id parameter is out of range.
+    pub fn validate(&self) -> crate::Result<()> {
+        let event_type = self.event_type().map_err(|_| {
+            Error::InvalidAutomationEvent(format!("unknown event type {}", self.raw.type_))
+        })?;
+        let [p0, p1, ..] = self.raw.params;
+        let checks: &[(&str, i32, i32)] = match event_type {
+            AutomationEventType::InputKeyUp | AutomationEventType::InputKeyDown => {
+                &[("key", p0, MAX_KEYBOARD_KEYS)]
+            }
+            AutomationEventType::InputMouseButtonUp | AutomationEventType::InputMouseButtonDown => {
+                &[("mouse button", p0, MAX_MOUSE_BUTTONS)]
+            }
+            AutomationEventType::InputGamepadConnect
+            | AutomationEventType::InputGamepadDisconnect => &[("gamepad", p0, MAX_GAMEPADS)],
+            AutomationEventType::InputGamepadButtonUp
+            | AutomationEventType::InputGamepadButtonDown => &[
+                ("gamepad", p0, MAX_GAMEPADS),
+                ("gamepad button", p1, MAX_GAMEPAD_BUTTONS),
+            ],
+            AutomationEventType::InputGamepadAxisMotion => &[
+                ("gamepad", p0, MAX_GAMEPADS),
+                ("gamepad axis", p1, MAX_GAMEPAD_AXIS),
+            ],
+            AutomationEventType::InputTouchUp
+            | AutomationEventType::InputTouchDown
+            | AutomationEventType::InputTouchPosition => &[("touch id", p0, MAX_TOUCH_POINTS)],
+            _ => &[],
+        };
+        for &(name, value, max) in checks {
+            if !(0..max).contains(&value) {
+                return Err(Error::InvalidAutomationEvent(format!(
+                    "{} {} out of range 0..{} in {} event",
+                    name, value, max, EVENT_TYPE_NAMES[event_type as usize]
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Event frame
+    pub fn frame(&self) -> u32 {
+        self.raw.frame
+    }
+
+    /// Event type, as stored in the event
+    pub fn raw_type(&self) -> u32 {
+        self.raw.type_
+    }
+
+    /// Event type, `Error::UnknownEnumValue` if raylib does not know the type
+    pub fn event_type(&self) -> crate::Result<AutomationEventType> {
+        AutomationEventType::try_from(self.raw.type_ as i32)
+    }
+
+    /// Event parameters, unused parameters are 0
+    pub fn params(&self) -> [i32; 4] {
+        self.raw.params
+    }
+
+    /// Get a copy of the raw event
+    pub fn as_raw(&self) -> raylib_ffi::AutomationEvent {
+        self.raw
+    }
+}
+
+impl From<raylib_ffi::AutomationEvent> for AutomationEvent {
+    fn from(raw: raylib_ffi::AutomationEvent) -> Self {
+        Self { raw }
+    }
+}
+
+impl From<AutomationEvent> for raylib_ffi::AutomationEvent {
+    fn from(event: AutomationEvent) -> Self {
+        event.raw
+    }
+}
+
+impl PartialEq for AutomationEvent {
+    fn eq(&self, other: &Self) -> bool {
+        self.raw.frame == other.raw.frame
+            && self.raw.type_ == other.raw.type_
+            && self.raw.params == other.raw.params
+    }
+}
+
+impl Eq for AutomationEvent {}
+
+impl fmt::Debug for AutomationEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut s = f.debug_struct("AutomationEvent");
+        s.field("frame", &self.raw.frame);
+        match self.event_type() {
+            Ok(event_type) => s.field("type", &event_type),
+            Err(_) => s.field("type", &self.raw.type_),
+        };
+        s.field("params", &self.raw.params).finish()
+    }
+}
+
+/// Automation events list, unloaded on drop
+///
+/// Holds up to `capacity()` events (MAX_AUTOMATION_EVENTS for lists loaded by raylib).
+#[derive(Debug)]
+pub struct AutomationEvents {
+    // Boxed so the address raylib records to does not change when the list is moved
+    raw: Box<raylib_ffi::AutomationEventList>,
+}
+
+impl AutomationEvents {
+    /// Create an empty list, with capacity for MAX_AUTOMATION_EVENTS events
+    pub fn new() -> Self {
+        unsafe { Self::from_raw(raylib_ffi::LoadAutomationEventList(std::ptr::null())) }
+    }
+
+    /// Take ownership of a raw list, it will be unloaded on drop
+    ///
+    /// # Safety
+    /// `raw` must be a valid list loaded by raylib that is not owned elsewhere
+    pub unsafe fn from_raw(raw: raylib_ffi::AutomationEventList) -> Self {
+        Self { raw: Box::new(raw) }
+    }
+
+    /// Release ownership of the raw list, it will no longer be unloaded on drop
+    pub fn into_raw(self) -> raylib_ffi::AutomationEventList {
+        let mut this = std::mem::ManuallyDrop::new(self);
+        this.stop_recording();
+        let raw = unsafe { std::ptr::read(&this.raw) };
+        *raw
+    }
+
+    /// Get a copy of the raw list, only valid while `self` is alive
+    pub fn as_raw(&self) -> raylib_ffi::AutomationEventList {
+        *self.raw
+    }
+
+    /// Create a list holding `events`, with capacity for at least MAX_AUTOMATION_EVENTS events
+    pub fn from_events(events: &[AutomationEvent]) -> Self {
+        let capacity = events.len().max(MAX_AUTOMATION_EVENTS);
+        unsafe {
+            // Allocated by raylib, UnloadAutomationEventList() frees it
+            let ptr = raylib_ffi::MemAlloc(
+                (capacity * std::mem::size_of::<raylib_ffi::AutomationEvent>()) as u32,
+            ) as *mut raylib_ffi::AutomationEvent;
+            if !ptr.is_null() {
+                std::ptr::copy_nonoverlapping(
+                    events.as_ptr() as *const raylib_ffi::AutomationEvent,
+                    ptr,
+                    events.len(),
+                );
+            }
+            Self::from_raw(raylib_ffi::AutomationEventList {
+                capacity: if ptr.is_null() { 0 } else { capacity as u32 },
+                count: if ptr.is_null() {
+                    0
+                } else {
+                    events.len() as u32
+                },
+                events: ptr,
+            })
+        }
+    }
+
+    /// Number of events in the list
+    pub fn len(&self) -> usize {
+        self.raw.count as usize
+    }
+
+    /// Check if the list has no events
+    pub fn is_empty(&self) -> bool {
+        self.raw.count == 0
+    }
+
+    /// Maximum number of events the list can hold
+    pub fn capacity(&self) -> usize {
+        self.raw.capacity as usize
+    }
+
+    /// Events of the list, in recording order
+    pub fn as_slice(&self) -> &[AutomationEvent] {
+        if self.raw.events.is_null() {
+            return &[];
+        }
+        // AutomationEvent is a transparent wrapper of the raw event
+        unsafe {
+            std::slice::from_raw_parts(
+                self.raw.events as *const AutomationEvent,
+                self.raw.count as usize,
+            )
+        }
+    }
+
+    /// Iterate over the events, in recording order
+    pub fn iter(&self) -> std::slice::Iter<'_, AutomationEvent> {
+        self.as_slice().iter()
+    }
+
+    /// Add an event at the end of the list, returns false if the list is full
+    pub fn push(&mut self, event: AutomationEvent) -> bool {
+        if self.raw.events.is_null() || self.raw.count >= self.raw.capacity {
+            return false;
+        }
+        unsafe {
+            *self.raw.events.add(self.raw.count as usize) = event.raw;
+        }
+        self.raw.count += 1;
+        true
+    }
+
+    /// Remove every event, keeping the capacity
+    pub fn clear(&mut self) {
+        self.raw.count = 0;
+    }
+
+    /// Export automation events list as a text file
+    pub fn export(&self, file_name: &str) -> bool {
+        rcore::export_automation_event_list(self, file_name)
+    }
+
+    /// Make raylib forget the list if it is still recording to it
+    ///
+    /// Only needed if a `Recorder` was leaked, it stops recording when dropped.
+    fn stop_recording(&mut self) {
+        let list: *mut raylib_ffi::AutomationEventList = &mut *self.raw;
+        if RECORDING_LIST
+            .compare_exchange(
+                list,
+                std::ptr::null_mut(),
+                Ordering::SeqCst,
+                Ordering::SeqCst,
+            )
+            .is_ok()
+        {
+            unsafe {
+                raylib_ffi::StopAutomationEventRecording();
+                raylib_ffi::SetAutomationEventList(std::ptr::null_mut());
+            }
+        }
+    }
+}
+
+impl Default for AutomationEvents {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> IntoIterator for &'a AutomationEvents {
+    type Item = &'a AutomationEvent;
+    type IntoIter = std::slice::Iter<'a, AutomationEvent>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl Drop for AutomationEvents {
+    fn drop(&mut self) {
+        self.stop_recording();
+        unsafe { raylib_ffi::UnloadAutomationEventList(&mut *self.raw) }
+    }
+}
+
+/// Records the input events of every frame into a list, stops recording on drop
+///
+/// Events are recorded when the frame ends (EndDrawing()), with the frame number counted
+/// from the start of the recording, or from one past the last event already in the list.
+#[derive(Debug)]
+pub struct Recorder<'a> {
+    events: &'a mut AutomationEvents,
+    // raylib must be driven from the thread that created the window
+    _marker: PhantomData<*const ()>,
+}
+
+impl<'a> Recorder<'a> {
+    /// Start recording into `events`, appending to the events already in the list
+    ///
+    /// Frame numbers continue from one past the last event in the list, so the list stays
+    /// sorted by frame. Fails with `Error::AlreadyRecording` if another recorder is active.
+    pub fn start(_rl: &RaylibHandle, events: &'a mut AutomationEvents) -> crate::Result<Self> {
+        let base_frame = events
+            .as_slice()
+            .last()
+            .map_or(0, |event| event.frame().saturating_add(1))
+            .min(i32::MAX as u32);
+        let list: *mut raylib_ffi::AutomationEventList = &mut *events.raw;
+        RECORDING_LIST
+            .compare_exchange(
+                std::ptr::null_mut(),
+                list,
+                Ordering::SeqCst,
+                Ordering::SeqCst,
+            )
+            .map_err(|_| Error::AlreadyRecording)?;
+        unsafe {
+            raylib_ffi::SetAutomationEventList(list);
+            raylib_ffi::SetAutomationEventBaseFrame(base_frame as i32);
+            raylib_ffi::StartAutomationEventRecording();
+        }
+        Ok(Self {
+            events,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Number of events recorded so far
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Check if no event was recorded yet
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Check if the list is full, the next events will not be recorded
+    pub fn is_full(&self) -> bool {
+        self.events.len() >= self.events.capacity()
+    }
+
+    /// Stop recording, same as dropping the recorder
+    pub fn stop(self) {}
+}
+
+impl Drop for Recorder<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            raylib_ffi::StopAutomationEventRecording();
+            raylib_ffi::SetAutomationEventList(std::ptr::null_mut());
+        }
+        RECORDING_LIST.store(std::ptr::null_mut(), Ordering::SeqCst);
+    }
+}
+
+/// Replays recorded events, frame by frame
+#[derive(Debug, Clone)]
+pub struct Player<'a> {
+    events: &'a [AutomationEvent],
+    next: usize,
+    frame: u32,
+}
+
+impl<'a> Player<'a> {
+    /// Create a player starting at frame 0
+    ///
+    /// Fails with `Error::InvalidAutomationEvent` if raylib can not play one of the events
+    /// (see `AutomationEvent::validate()`) or if their frames go down.
+    pub fn new(events: &'a AutomationEvents) -> crate::Result<Self> {
+        Self::from_slice(events.as_slice())
+    }
+
+    /// Create a player for events sorted by frame, i.e. built with the `AutomationEvent`
+    /// constructors or read by `parse_events()`
+    ///
+    /// Fails with `Error::InvalidAutomationEvent` if raylib can not play one of the events
+    /// or if they are not sorted by frame.
+    pub fn from_slice(events: &'a [AutomationEvent]) -> crate::Result<Self> {
+        events.iter().try_for_each(AutomationEvent::validate)?;
+        if let Some(pair) = events
+            .windows(2)
+            .find(|pair| pair[1].frame() < pair[0].frame())
+        {
+            return Err(Error::InvalidAutomationEvent(format!(
+                "event at frame {} follows an event at frame {}",
+                pair[1].frame(),
+                pair[0].frame()
+            )));
+        }
+        Ok(Self {
+            events,
+            next: 0,
+            frame: 0,
+        })
+    }
+
+    /// Play the events of the current frame and move to the next frame, returns the
+    /// events played
+    ///
+    /// Call it once per frame, before the input is read.
+    pub fn play_frame(&mut self, _rl: &RaylibHandle) -> &'a [AutomationEvent] {
+        let start = self.next;
+        while let Some(event) = self.events.get(self.next) {
+            if event.frame() > self.frame {
+                break;
+            }
+            // Every event was validated when the player was created
+            unsafe { raylib_ffi::PlayAutomationEvent(event.raw) }
+            self.next += 1;
+        }
+        self.frame += 1;
+        &self.events[start..self.next]
+    }
+
+    /// Frame that the next `play_frame()` call plays
+    pub fn frame(&self) -> u32 {
+        self.frame
+    }
+
+    /// Check if every event was played
+    pub fn is_finished(&self) -> bool {
+        self.next >= self.events.len()
+    }
+
+    /// Go back to frame 0
+    pub fn restart(&mut self) {
+        self.next = 0;
+        self.frame = 0;
+    }
+}
+
+/// Parse automation events from raylib's text format, as read by LoadAutomationEventList()
+///
+/// Only the lines starting with `e` (events) are read, the event count (`c` line) and
+/// comments are ignored. Fails with `Error::Parse` on a malformed event line or an event
+/// raylib can not play (see `AutomationEvent::validate()`).
+pub fn parse_events(text: &str) -> crate::Result<Vec<AutomationEvent>> {
+    let mut events = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let Some(fields) = line.strip_prefix('e') else {
+            continue;
+        };
+        let mut values = [0i32; 6];
+        let mut fields = fields.split_whitespace();
+        for value in values.iter_mut() {
+            let field = fields.next().ok_or_else(|| Error::Parse {
+                line: index + 1,
+                message: String::from("expected 6 event values"),
+            })?;
+            *value = field.parse().map_err(|_| Error::Parse {
+                line: index + 1,
+                message: format!("invalid event value: {}", field),
+            })?;
+        }
+        // Frame and type are unsigned in C but read with %d
+        let event = AutomationEvent::from(raylib_ffi::AutomationEvent {
+            frame: values[0] as u32,
+            type_: values[1] as u32,
+            params: [values[2], values[3], values[4], values[5]],
+        });
+        event.validate().map_err(|err| Error::Parse {
+            line: index + 1,
+            message: match err {
+                Error::InvalidAutomationEvent(message) => message,
+                err => err.to_string(),
+            },
+        })?;
+        events.push(event);
+    }
+    Ok(events)
+}
+
+/// Write automation events in raylib's text format, as ExportAutomationEventList()
+pub fn write_events(events: &[AutomationEvent]) -> String {
+    let mut text = String::from(
+        "#\n\
+         # Automation events exporter v1.0 - raylib automation events list\n\
+         #\n\
+         #    c <events_count>\n\
+         #    e <frame> <event_type> <param0> <param1> <param2> <param3> // <event_type_name>\n\
+         #\n\
+         # more info and bugs-report:  github.com/raysan5/raylib\n\
+         # feedback and support:       ray[at]raylib.com\n\
+         #\n\
+         # Copyright (c) 2023-2024 Ramon Santamaria (@raysan5)\n\
+         #\n\n",
+    );
+    text.push_str(&format!("c {}\n", events.len()));
+    for event in events {
+        let [p0, p1, p2, p3] = event.params();
+        let name = EVENT_TYPE_NAMES
+            .get(event.raw_type() as usize)
+            .unwrap_or(&"UNKNOWN");
+        text.push_str(&format!(
+            "e {} {} {} {} {} {} // Event: {}\n",
+            event.frame() as i32,
+            event.raw_type() as i32,
+            p0,
+            p1,
+            p2,
+            p3,
+            name
+        ));
+    }
+    text
+}
+
+/// Load automation events from a text file (.rae), see `parse_events()`
+pub fn load_events(file_name: &str) -> crate::Result<Vec<AutomationEvent>> {
+    let text = std::fs::read_to_string(file_name).map_err(|err| match err.kind() {
+        std::io::ErrorKind::NotFound => Error::load(file_name, LoadError::FileNotFound),
+        _ => Error::load(file_name, LoadError::InvalidData),
+    })?;
+    parse_events(&text)
+}
+
+/// Save automation events to a text file (.rae), see `write_events()`
+pub fn save_events(file_name: &str, events: &[AutomationEvent]) -> bool {
+    std::fs::write(file_name, write_events(events)).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scripted_events() -> Vec<AutomationEvent> {
+        let mut events = AutomationEvent::key_press(2, KeyboardKey::Space).to_vec();
+        events.extend([
+            AutomationEvent::mouse_position(3, 120, -40),
+            AutomationEvent::mouse_button_down(3, MouseButton::Left),
+            AutomationEvent::gamepad_connect(4, 3).unwrap(),
+            AutomationEvent::gamepad_button_down(5, 3, GamepadButton::RightFaceDown).unwrap(),
+            AutomationEvent::gamepad_axis_motion(5, 0, GamepadAxis::LeftY, -0.5).unwrap(),
+            AutomationEvent::touch_position(6, 7, 10, 20).unwrap(),
+            AutomationEvent::window_resize(7, 1280, 720),
+            AutomationEvent::set_target_fps(8, 30),
+            AutomationEvent::window_close(9),
+        ]);
+        events
+    }
+
+    #[test]
+    fn write_then_parse_round_trips() {
+        let events = scripted_events();
+        let text = write_events(&events);
+        assert!(text.contains(&format!("c {}\n", events.len())));
+        assert!(text.contains("e 3 7 120 -40 0 0 // Event: INPUT_MOUSE_POSITION\n"));
+        assert_eq!(parse_events(&text).unwrap(), events);
+        assert_eq!(write_events(&parse_events(&text).unwrap()), text);
+    }
+
+    #[test]
+    fn parse_skips_comments_and_count() {
+        let text = "# comment\nc 1\n\ne 10 2 32 0 0 0\n";
+        assert_eq!(
+            parse_events(text).unwrap(),
+            vec![AutomationEvent::key_down(10, KeyboardKey::Space)]
+        );
+        assert!(parse_events("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn parse_rejects_malformed_lines() {
+        for (text, line) in [
+            ("e 1 2 3\n", 1),
+            ("c 1\ne 1 2 x 0 0 0\n", 2),
+            ("e 1 2 3 0 0 0\ne\n", 2),
+            ("e 1 2 99999999999 0 0 0\n", 1),
+        ] {
+            match parse_events(text) {
+                Err(Error::Parse { line: l, .. }) => assert_eq!(l, line, "{:?}", text),
+                other => panic!("{:?} parsed as {:?}", text, other),
+            }
+        }
+    }
+
+    #[test]
+    fn parse_rejects_out_of_range_values() {
+        for text in [
+            "e 0 24 0 0 0 0",
+            "e 0 -1 0 0 0 0",
+            "e 0 2 512 0 0 0",
+            "e 0 1 -1 0 0 0",
+            "e 0 6 8 0 0 0",
+            "e 0 9 4 0 0 0",
+            "e 0 12 0 32 0 0",
+            "e 0 13 0 8 0 0",
+            "e 0 16 8 0 0 0",
+        ] {
+            assert!(
+                matches!(parse_events(text), Err(Error::Parse { line: 1, .. })),
+                "{:?} was accepted",
+                text
+            );
+        }
+        // Parameters of other event types are not indices
+        assert!(parse_events("e 0 7 -5 100000 0 0").is_ok());
+    }
+
+    #[test]
+    fn constructors_check_ranges() {
+        assert!(AutomationEvent::gamepad_connect(0, MAX_GAMEPADS).is_err());
+        assert!(AutomationEvent::gamepad_disconnect(0, -1).is_err());
+        assert!(AutomationEvent::gamepad_button_up(0, 4, GamepadButton::LeftFaceUp).is_err());
+        assert!(AutomationEvent::gamepad_axis_motion(0, 9, GamepadAxis::LeftX, 1.0).is_err());
+        assert!(AutomationEvent::touch_down(0, MAX_TOUCH_POINTS).is_err());
+        assert!(AutomationEvent::touch_up(0, 0).is_ok());
+        assert!(
+            AutomationEvent::new(0, AutomationEventType::InputKeyDown, [512, 0, 0, 0]).is_err()
+        );
+        assert!(AutomationEvent::new(0, AutomationEventType::InputKeyDown, [511, 0, 0, 0]).is_ok());
+        assert!(matches!(
+            AutomationEvent::new(0, AutomationEventType::InputMouseButtonUp, [8, 0, 0, 0]),
+            Err(Error::InvalidAutomationEvent(_))
+        ));
+    }
+
+    #[test]
+    fn gamepad_axis_value_is_scaled() {
+        let event = AutomationEvent::gamepad_axis_motion(0, 0, GamepadAxis::LeftX, 2.0).unwrap();
+        assert_eq!(event.params(), [0, GamepadAxis::LeftX as i32, 32768, 0]);
+    }
+
+    #[test]
+    fn player_rejects_unsorted_events() {
+        let events = scripted_events();
+        let player = Player::from_slice(&events).unwrap();
+        assert_eq!(player.frame(), 0);
+        assert!(!player.is_finished());
+
+        let mut unsorted = events.clone();
+        unsorted.push(AutomationEvent::key_down(3, KeyboardKey::Enter));
+        assert!(matches!(
+            Player::from_slice(&unsorted),
+            Err(Error::InvalidAutomationEvent(_))
+        ));
+        assert!(Player::from_slice(&[]).unwrap().is_finished());
+    }
+}
@@ -0,0 +1,147 @@
+use std::ffi::CString;
+use std::fmt;
+use std::path::Path;
+
+/// Errors returned by the safe raylib wrappers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A window (and its `RaylibHandle`) already exists
+    AlreadyInitialized,
+    /// InitWindow() returned without creating a window and OpenGL context
+    WindowInitFailed,
+    /// An audio device (and its `AudioDevice`) already exists
+    AudioDeviceAlreadyInitialized,
+    /// InitAudioDevice() failed to initialize the audio device and context
+    AudioDeviceInitFailed,
+    /// A resource could not be loaded
+    Load {
+        /// Path of the resource, or a short description for in-memory data
+        path: String,
+        /// Why loading failed
+        reason: LoadError,
+    },
+    /// A shader uniform with this name does not exist
+    UniformNotFound(String),
+    /// An integer returned by raylib does not match any variant of the enum
+    UnknownEnumValue {
+        /// Name of the enum
+        name: &'static str,
+        /// The unmatched value
+        value: i32,
+    },
+    /// No monitor is connected at this index
+    MonitorNotFound(i32),
+    /// An automation events `Recorder` is already recording
+    AlreadyRecording,
+    /// An automation event has an unknown type or a parameter raylib can not play
+    InvalidAutomationEvent(String),
+    /// Text data could not be parsed
+    Parse {
+        /// Line of the error, starting at 1
+        line: usize,
+        /// What is wrong with the line
+        message: String,
+    },
+}
+
+/// Reason a resource failed to load
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LoadError {
+    /// The file does not exist
+    FileNotFound,
+    /// The file type is not supported by the loader
+    UnsupportedFormat,
+    /// The data was read but could not be decoded
+    InvalidData,
+    /// The data could not be uploaded to the GPU (texture, shader program...)
+    GpuUploadFailed,
+    /// The audio buffer could not be created (sound, music, audio stream...)
+    AudioBufferFailed,
+    /// A string argument contains an interior NUL byte
+    InteriorNul,
+}
+
+impl Error {
+    pub(crate) fn load(path: &str, reason: LoadError) -> Self {
+        Error::Load {
+            path: path.to_string(),
+            reason,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::AlreadyInitialized => write!(f, "raylib window is already initialized"),
+            Error::WindowInitFailed => write!(f, "failed to initialize raylib window"),
+            Error::AudioDeviceAlreadyInitialized => {
+                write!(f, "raylib audio device is already initialized")
+            }
+            Error::AudioDeviceInitFailed => write!(f, "failed to initialize raylib audio device"),
+            Error::Load { path, reason } => write!(f, "failed to load [{}]: {}", path, reason),
+            Error::UniformNotFound(name) => write!(f, "shader uniform [{}] not found", name),
+            Error::UnknownEnumValue { name, value } => {
+                write!(f, "unknown {} value: {}", name, value)
+            }
+            Error::MonitorNotFound(monitor) => write!(f, "monitor {} not found", monitor),
+            Error::AlreadyRecording => write!(f, "automation events are already being recorded"),
+            Error::InvalidAutomationEvent(message) => {
+                write!(f, "invalid automation event: {}", message)
+            }
+            Error::Parse { line, message } => {
+                write!(f, "parse error at line {}: {}", line, message)
+            }
+        }
+    }
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reason = match self {
+            LoadError::FileNotFound => "file not found",
+            LoadError::UnsupportedFormat => "unsupported file format",
+            LoadError::InvalidData => "invalid or corrupted data",
+            LoadError::GpuUploadFailed => "GPU upload failed",
+            LoadError::AudioBufferFailed => "audio buffer creation failed",
+            LoadError::InteriorNul => "string contains an interior NUL byte",
+        };
+        f.write_str(reason)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Result type used by the safe raylib wrappers
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Convert a string argument of the resource at `path` to a C string
+pub(crate) fn c_string(path: &str, text: &str) -> Result<CString> {
+    CString::new(text).map_err(|_| Error::load(path, LoadError::InteriorNul))
+}
+
+/// Check that `path` exists and has one of the `extensions` (any extension if empty),
+/// returning it as a C string
+pub(crate) fn check_file(path: &str, extensions: &[&str]) -> Result<CString> {
+    let c_path = c_string(path, path)?;
+    if !Path::new(path).is_file() {
+        return Err(Error::load(path, LoadError::FileNotFound));
+    }
+    if !extensions.is_empty() && !has_extension(path, extensions) {
+        return Err(Error::load(path, LoadError::UnsupportedFormat));
+    }
+    Ok(c_path)
+}
+
+/// Check file extension (including point: .png, .wav), case-insensitive like IsFileExtension()
+///
+/// `path` can also be a bare file type as used by the *FromMemory() loaders, i.e. '.png'
+pub(crate) fn has_extension(path: &str, extensions: &[&str]) -> bool {
+    let ext = Path::new(path).extension().and_then(|ext| ext.to_str());
+    match ext.or_else(|| path.strip_prefix('.')) {
+        Some(ext) => extensions
+            .iter()
+            .any(|e| e.trim_start_matches('.').eq_ignore_ascii_case(ext)),
+        None => false,
+    }
+}
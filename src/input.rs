@@ -0,0 +1,960 @@
+//! Input layers over the polling rcore input functions
+//!
+//! raylib only exposes input through polling. `InputState` is updated once per frame from a
+//! `Platform` (the `RaylibHandle` or a `MockPlatform`) and turns the key/char queues and the state changes of mouse, gamepads and touch points into
+//! a list of events, so UI code can iterate events instead of polling every key.
+//!
+//! `InputMap` binds user-defined actions to keys, mouse buttons and gamepad buttons/axes,
+//! reading them from an `InputSource` so the mapping can be driven by `MockInput`.
+
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use crate::enums::{GamepadAxis, GamepadButton, KeyboardKey, MouseButton};
+use crate::platform::Platform;
+use crate::Vector2;
+
+/// Maximum number of gamepads supported by raylib
+const MAX_GAMEPADS: i32 = 4;
+
+/// Input event, generated by `InputState::update()`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InputEvent {
+    /// Key pressed, in the order the keys were queued
+    KeyDown(KeyboardKey),
+    /// Key released
+    KeyUp(KeyboardKey),
+    /// Character entered (unicode), in the order the characters were queued
+    Char(char),
+    /// Mouse moved
+    MouseMove {
+        /// Mouse position after the move
+        position: Vector2,
+        /// Mouse delta since the last frame
+        delta: Vector2,
+    },
+    /// Mouse button pressed or released
+    MouseButton { button: MouseButton, pressed: bool },
+    /// Mouse wheel moved, movement for both X and Y
+    Wheel(Vector2),
+    /// Gamepad button pressed or released
+    GamepadButton {
+        gamepad: i32,
+        button: GamepadButton,
+        pressed: bool,
+    },
+    /// Gamepad axis value changed
+    GamepadAxis {
+        gamepad: i32,
+        axis: GamepadAxis,
+        value: f32,
+    },
+    /// Touch point started, moved or ended
+    Touch {
+        id: i32,
+        position: Vector2,
+        phase: TouchPhase,
+    },
+}
+
+/// Phase of a touch point in an `InputEvent::Touch`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TouchPhase {
+    /// The touch point appeared this frame
+    Started,
+    /// The touch point moved since the last frame
+    Moved,
+    /// The touch point was lifted, the position is the last known one
+    Ended,
+}
+
+/// Input snapshot taken once per frame
+///
+/// Events only keep their order within the same kind (keys, chars...), raylib does not
+/// record the order of events across devices.
+#[derive(Debug, Clone)]
+pub struct InputState {
+    events: Vec<InputEvent>,
+    keys_down: Vec<KeyboardKey>,
+    mouse_position: Vector2,
+    mouse_delta: Vector2,
+    mouse_wheel: Vector2,
+    gamepad_axes: Vec<(i32, GamepadAxis, f32)>,
+    touch_points: Vec<(i32, Vector2)>,
+}
+
+impl Default for InputState {
+    fn default() -> Self {
+        Self {
+            events: Vec::new(),
+            keys_down: Vec::new(),
+            mouse_position: Vector2 { x: 0.0, y: 0.0 },
+            mouse_delta: Vector2 { x: 0.0, y: 0.0 },
+            mouse_wheel: Vector2 { x: 0.0, y: 0.0 },
+            gamepad_axes: Vec::new(),
+            touch_points: Vec::new(),
+        }
+    }
+}
+
+impl InputState {
+    /// Create an empty input state, call `update()` once per frame to fill it
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Take the snapshot for the current frame, draining the key and char queues
+    ///
+    /// Events from the previous frame are cleared. `platform` is usually the `RaylibHandle`.
+    pub fn update(&mut self, platform: &mut impl Platform) {
+        self.events.clear();
+        self.update_keyboard(platform);
+        self.update_mouse(platform);
+        self.update_gamepads(platform);
+        self.update_touch(platform);
+    }
+
+    fn update_keyboard(&mut self, platform: &mut impl Platform) {
+        // Keys pressed in a previous frame, checked before the queue adds new ones
+        let events = &mut self.events;
+        self.keys_down.retain(|&key| {
+            let up = platform.is_key_up(key);
+            if up {
+                events.push(InputEvent::KeyUp(key));
+            }
+            !up
+        });
+
+        while let Some(key) = platform.get_key_pressed() {
+            // Keycodes without a KeyboardKey variant are skipped
+            if let Ok(key) = key {
+                self.events.push(InputEvent::KeyDown(key));
+                if !self.keys_down.contains(&key) {
+                    self.keys_down.push(key);
+                }
+            }
+        }
+
+        loop {
+            let codepoint = platform.get_char_pressed();
+            if codepoint == 0 {
+                break;
+            }
+            if let Some(c) = char::from_u32(codepoint as u32) {
+                self.events.push(InputEvent::Char(c));
+            }
+        }
+    }
+
+    fn update_mouse(&mut self, platform: &impl Platform) {
+        self.mouse_position = platform.get_mouse_position();
+        self.mouse_delta = platform.get_mouse_delta();
+        if self.mouse_delta.x != 0.0 || self.mouse_delta.y != 0.0 {
+            self.events.push(InputEvent::MouseMove {
+                position: self.mouse_position,
+                delta: self.mouse_delta,
+            });
+        }
+
+        for &button in MouseButton::VARIANTS {
+            if platform.is_mouse_button_pressed(button) {
+                self.events.push(InputEvent::MouseButton {
+                    button,
+                    pressed: true,
+                });
+            } else if platform.is_mouse_button_released(button) {
+                self.events.push(InputEvent::MouseButton {
+                    button,
+                    pressed: false,
+                });
+            }
+        }
+
+        self.mouse_wheel = platform.get_mouse_wheel_move_v();
+        if self.mouse_wheel.x != 0.0 || self.mouse_wheel.y != 0.0 {
+            self.events.push(InputEvent::Wheel(self.mouse_wheel));
+        }
+    }
+
+    fn update_gamepads(&mut self, platform: &impl Platform) {
+        for gamepad in 0..MAX_GAMEPADS {
+            if !platform.is_gamepad_available(gamepad) {
+                self.gamepad_axes.retain(|&(g, _, _)| g != gamepad);
+                continue;
+            }
+
+            for &button in GamepadButton::VARIANTS {
+                if button == GamepadButton::Unknown {
+                    continue;
+                }
+                if platform.is_gamepad_button_pressed(gamepad, button) {
+                    self.events.push(InputEvent::GamepadButton {
+                        gamepad,
+                        button,
+                        pressed: true,
+                    });
+                } else if platform.is_gamepad_button_released(gamepad, button) {
+                    self.events.push(InputEvent::GamepadButton {
+                        gamepad,
+                        button,
+                        pressed: false,
+                    });
+                }
+            }
+
+            let axis_count = platform.get_gamepad_axis_count(gamepad);
+            for &axis in GamepadAxis::VARIANTS {
+                if axis as i32 >= axis_count {
+                    continue;
+                }
+                let value = platform.get_gamepad_axis_movement(gamepad, axis);
+                match self
+                    .gamepad_axes
+                    .iter_mut()
+                    .find(|(g, a, _)| *g == gamepad && *a == axis)
+                {
+                    Some((_, _, last)) if *last == value => continue,
+                    Some((_, _, last)) => *last = value,
+                    // A newly connected gamepad only reports the axes that are not at rest
+                    None => {
+                        self.gamepad_axes.push((gamepad, axis, value));
+                        if value == 0.0 {
+                            continue;
+                        }
+                    }
+                }
+                self.events.push(InputEvent::GamepadAxis {
+                    gamepad,
+                    axis,
+                    value,
+                });
+            }
+        }
+    }
+
+    fn update_touch(&mut self, platform: &impl Platform) {
+        let count = platform.get_touch_point_count();
+        let points: Vec<(i32, Vector2)> = (0..count)
+            .map(|index| {
+                (
+                    platform.get_touch_point_id(index),
+                    platform.get_touch_position(index),
+                )
+            })
+            .collect();
+
+        for &(id, position) in &self.touch_points {
+            if !points.iter().any(|&(new_id, _)| new_id == id) {
+                self.events.push(InputEvent::Touch {
+                    id,
+                    position,
+                    phase: TouchPhase::Ended,
+                });
+            }
+        }
+
+        for &(id, position) in &points {
+            let phase = match self.touch_points.iter().find(|&&(old_id, _)| old_id == id) {
+                None => TouchPhase::Started,
+                Some(&(_, old)) if old.x != position.x || old.y != position.y => TouchPhase::Moved,
+                Some(_) => continue,
+            };
+            self.events.push(InputEvent::Touch {
+                id,
+                position,
+                phase,
+            });
+        }
+
+        self.touch_points = points;
+    }
+
+    /// Events of the last `update()`
+    pub fn events(&self) -> &[InputEvent] {
+        &self.events
+    }
+
+    /// Keys currently held down, in the order they were pressed
+    pub fn keys_down(&self) -> &[KeyboardKey] {
+        &self.keys_down
+    }
+
+    /// Check if a key is being held down
+    pub fn is_key_down(&self, key: KeyboardKey) -> bool {
+        self.keys_down.contains(&key)
+    }
+
+    /// Mouse position at the last `update()`
+    pub fn mouse_position(&self) -> Vector2 {
+        self.mouse_position
+    }
+
+    /// Mouse delta between the last two frames
+    pub fn mouse_delta(&self) -> Vector2 {
+        self.mouse_delta
+    }
+
+    /// Mouse wheel movement of the last frame for both X and Y
+    pub fn mouse_wheel(&self) -> Vector2 {
+        self.mouse_wheel
+    }
+}
+
+/// Apply a dead zone to a gamepad axis value
+///
+/// Values inside `[-dead_zone, dead_zone]` return 0.0, the rest of the range is rescaled
+/// so the output still goes from 0.0 to 1.0 (or -1.0).
+pub fn apply_dead_zone(value: f32, dead_zone: f32) -> f32 {
+    let magnitude = value.abs();
+    if magnitude <= dead_zone {
+        0.0
+    } else if dead_zone >= 1.0 {
+        value.signum()
+    } else {
+        value.signum() * ((magnitude - dead_zone) / (1.0 - dead_zone)).min(1.0)
+    }
+}
+
+/// Source of the raw input state read by `InputMap::update()`
+///
+/// Implemented by every `Platform` (including `RaylibHandle`) and by `MockInput`.
+pub trait InputSource {
+    /// Check if a key is being pressed
+    fn is_key_down(&self, key: KeyboardKey) -> bool;
+    /// Check if a mouse button is being pressed
+    fn is_mouse_button_down(&self, button: MouseButton) -> bool;
+    /// Check if a gamepad button is being pressed
+    fn is_gamepad_button_down(&self, gamepad: i32, button: GamepadButton) -> bool;
+    /// Get axis movement value for a gamepad axis
+    fn gamepad_axis_movement(&self, gamepad: i32, axis: GamepadAxis) -> f32;
+}
+
+impl<P: Platform> InputSource for P {
+    fn is_key_down(&self, key: KeyboardKey) -> bool {
+        Platform::is_key_down(self, key)
+    }
+
+    fn is_mouse_button_down(&self, button: MouseButton) -> bool {
+        Platform::is_mouse_button_down(self, button)
+    }
+
+    fn is_gamepad_button_down(&self, gamepad: i32, button: GamepadButton) -> bool {
+        Platform::is_gamepad_button_down(self, gamepad, button)
+    }
+
+    fn gamepad_axis_movement(&self, gamepad: i32, axis: GamepadAxis) -> f32 {
+        self.get_gamepad_axis_movement(gamepad, axis)
+    }
+}
+
+/// Input source with a state set by hand, to drive an `InputMap` without a window
+#[derive(Debug, Clone, Default)]
+pub struct MockInput {
+    keys: HashSet<KeyboardKey>,
+    mouse_buttons: HashSet<MouseButton>,
+    gamepad_buttons: HashSet<(i32, GamepadButton)>,
+    gamepad_axes: HashMap<(i32, GamepadAxis), f32>,
+}
+
+impl MockInput {
+    /// Create a mock input with no key or button held and every axis centered
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Hold a key down until `release_key()`
+    pub fn press_key(&mut self, key: KeyboardKey) {
+        self.keys.insert(key);
+    }
+
+    /// Release a key held with `press_key()`
+    pub fn release_key(&mut self, key: KeyboardKey) {
+        self.keys.remove(&key);
+    }
+
+    /// Hold a mouse button down until `release_mouse_button()`
+    pub fn press_mouse_button(&mut self, button: MouseButton) {
+        self.mouse_buttons.insert(button);
+    }
+
+    /// Release a mouse button held with `press_mouse_button()`
+    pub fn release_mouse_button(&mut self, button: MouseButton) {
+        self.mouse_buttons.remove(&button);
+    }
+
+    /// Hold a gamepad button down until `release_gamepad_button()`
+    pub fn press_gamepad_button(&mut self, gamepad: i32, button: GamepadButton) {
+        self.gamepad_buttons.insert((gamepad, button));
+    }
+
+    /// Release a gamepad button held with `press_gamepad_button()`
+    pub fn release_gamepad_button(&mut self, gamepad: i32, button: GamepadButton) {
+        self.gamepad_buttons.remove(&(gamepad, button));
+    }
+
+    /// Set the raw movement of a gamepad axis (-1.0 to 1.0), before the dead zone
+    pub fn set_gamepad_axis(&mut self, gamepad: i32, axis: GamepadAxis, value: f32) {
+        self.gamepad_axes.insert((gamepad, axis), value);
+    }
+
+    /// Release every key and button and center every axis
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
+impl InputSource for MockInput {
+    fn is_key_down(&self, key: KeyboardKey) -> bool {
+        self.keys.contains(&key)
+    }
+
+    fn is_mouse_button_down(&self, button: MouseButton) -> bool {
+        self.mouse_buttons.contains(&button)
+    }
+
+    fn is_gamepad_button_down(&self, gamepad: i32, button: GamepadButton) -> bool {
+        self.gamepad_buttons.contains(&(gamepad, button))
+    }
+
+    fn gamepad_axis_movement(&self, gamepad: i32, axis: GamepadAxis) -> f32 {
+        self.gamepad_axes
+            .get(&(gamepad, axis))
+            .copied()
+            .unwrap_or(0.0)
+    }
+}
+
+/// Input bound to an action in an `InputMap`
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Binding {
+    Key(KeyboardKey),
+    MouseButton(MouseButton),
+    GamepadButton {
+        gamepad: i32,
+        button: GamepadButton,
+    },
+    /// Held while the axis value (after the dead zone) is past `threshold`,
+    /// the sign of `threshold` selects the direction
+    GamepadAxis {
+        gamepad: i32,
+        axis: GamepadAxis,
+        threshold: f32,
+    },
+}
+
+impl Binding {
+    /// Value of the binding from 0.0 to 1.0, and whether it counts as held
+    fn read(&self, source: &impl InputSource, dead_zone: f32) -> (f32, bool) {
+        let digital = |down: bool| (if down { 1.0 } else { 0.0 }, down);
+        match *self {
+            Binding::Key(key) => digital(source.is_key_down(key)),
+            Binding::MouseButton(button) => digital(source.is_mouse_button_down(button)),
+            Binding::GamepadButton { gamepad, button } => {
+                digital(source.is_gamepad_button_down(gamepad, button))
+            }
+            Binding::GamepadAxis {
+                gamepad,
+                axis,
+                threshold,
+            } => {
+                let value = apply_dead_zone(source.gamepad_axis_movement(gamepad, axis), dead_zone);
+                // Only the movement in the direction of the threshold counts
+                let value = if threshold < 0.0 { -value } else { value }.max(0.0);
+                (value, value > 0.0 && value >= threshold.abs())
+            }
+        }
+    }
+}
+
+/// State of one action, updated by `InputMap::update()`
+#[derive(Debug, Clone, Copy, Default)]
+struct ActionState {
+    held: bool,
+    was_held: bool,
+    value: f32,
+}
+
+/// Default dead zone applied to gamepad axes
+pub const DEFAULT_DEAD_ZONE: f32 = 0.1;
+
+/// Maps user-defined actions to keyboard, mouse and gamepad bindings
+///
+/// Call `update()` once per frame, then query the actions. With the `serde` feature the
+/// bindings and dead zone can be saved to and loaded from rebinding files.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "A: serde::Serialize",
+        deserialize = "A: serde::Deserialize<'de> + Eq + Hash"
+    ))
+)]
+pub struct InputMap<A> {
+    bindings: HashMap<A, Vec<Binding>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default = "default_dead_zone",
+            deserialize_with = "deserialize_dead_zone"
+        )
+    )]
+    dead_zone: f32,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    state: HashMap<A, ActionState>,
+}
+
+#[cfg(feature = "serde")]
+fn default_dead_zone() -> f32 {
+    DEFAULT_DEAD_ZONE
+}
+
+/// Check if a dead zone is usable: from 0.0 to 1.0, not NaN
+fn is_valid_dead_zone(dead_zone: f32) -> bool {
+    (0.0..=1.0).contains(&dead_zone)
+}
+
+#[cfg(feature = "serde")]
+fn deserialize_dead_zone<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<f32, D::Error> {
+    let dead_zone = <f32 as serde::Deserialize>::deserialize(deserializer)?;
+    if !is_valid_dead_zone(dead_zone) {
+        return Err(serde::de::Error::custom(format!(
+            "dead zone must be from 0.0 to 1.0, got {}",
+            dead_zone
+        )));
+    }
+    Ok(dead_zone)
+}
+
+impl<A: Eq + Hash> Default for InputMap<A> {
+    fn default() -> Self {
+        Self {
+            bindings: HashMap::new(),
+            dead_zone: DEFAULT_DEAD_ZONE,
+            state: HashMap::new(),
+        }
+    }
+}
+
+impl<A: Eq + Hash + Clone> InputMap<A> {
+    /// Create an input map without bindings
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Bind an input to an action, an action can have any number of bindings
+    pub fn bind(&mut self, action: A, binding: Binding) {
+        let bindings = self.bindings.entry(action).or_default();
+        if !bindings.contains(&binding) {
+            bindings.push(binding);
+        }
+    }
+
+    /// Remove one binding of an action
+    pub fn unbind(&mut self, action: &A, binding: Binding) {
+        if let Some(bindings) = self.bindings.get_mut(action) {
+            bindings.retain(|b| *b != binding);
+        }
+    }
+
+    /// Remove every binding of an action
+    pub fn clear_bindings(&mut self, action: &A) {
+        self.bindings.remove(action);
+        self.state.remove(action);
+    }
+
+    /// Bindings of an action
+    pub fn bindings(&self, action: &A) -> &[Binding] {
+        self.bindings.get(action).map_or(&[], |b| b.as_slice())
+    }
+
+    /// Set the dead zone applied to every gamepad axis binding
+    ///
+    /// # Panics
+    /// If `dead_zone` is not from 0.0 to 1.0
+    pub fn set_dead_zone(&mut self, dead_zone: f32) {
+        assert!(
+            is_valid_dead_zone(dead_zone),
+            "set_dead_zone: dead zone must be from 0.0 to 1.0, got {}",
+            dead_zone
+        );
+        self.dead_zone = dead_zone;
+    }
+
+    /// Dead zone applied to every gamepad axis binding
+    pub fn dead_zone(&self) -> f32 {
+        self.dead_zone
+    }
+
+    /// Read the bindings of every action from `source`, call it once per frame
+    pub fn update(&mut self, source: &impl InputSource) {
+        for (action, bindings) in &self.bindings {
+            let (value, held) = bindings
+                .iter()
+                .map(|binding| binding.read(source, self.dead_zone))
+                .fold((0.0f32, false), |(value, held), (v, h)| {
+                    (value.max(v), held || h)
+                });
+            let state = self.state.entry(action.clone()).or_default();
+            state.was_held = state.held;
+            state.held = held;
+            state.value = value;
+        }
+    }
+
+    fn state(&self, action: &A) -> ActionState {
+        self.state.get(action).copied().unwrap_or_default()
+    }
+
+    /// Check if an action started being held this frame
+    pub fn is_pressed(&self, action: &A) -> bool {
+        let state = self.state(action);
+        state.held && !state.was_held
+    }
+
+    /// Check if an action is being held
+    pub fn is_held(&self, action: &A) -> bool {
+        self.state(action).held
+    }
+
+    /// Check if an action stopped being held this frame
+    pub fn is_released(&self, action: &A) -> bool {
+        let state = self.state(action);
+        !state.held && state.was_held
+    }
+
+    /// Value of an action from 0.0 to 1.0, the largest value of its bindings
+    ///
+    /// Buttons give 0.0 or 1.0, gamepad axes give their dead-zoned movement in the
+    /// direction of the binding threshold.
+    pub fn value(&self, action: &A) -> f32 {
+        self.state(action).value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::automation::AutomationEvent;
+    use crate::enums::AutomationEventType;
+    use crate::platform::MockPlatform;
+
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    enum Action {
+        Jump,
+        Left,
+        Right,
+    }
+
+    #[test]
+    fn action_press_hold_release() {
+        let mut map = InputMap::new();
+        map.bind(Action::Jump, Binding::Key(KeyboardKey::Space));
+        map.bind(
+            Action::Jump,
+            Binding::GamepadButton {
+                gamepad: 0,
+                button: GamepadButton::RightFaceDown,
+            },
+        );
+        let mut input = MockInput::new();
+
+        map.update(&input);
+        assert!(!map.is_pressed(&Action::Jump) && !map.is_held(&Action::Jump));
+
+        input.press_key(KeyboardKey::Space);
+        map.update(&input);
+        assert!(map.is_pressed(&Action::Jump));
+        assert!(map.is_held(&Action::Jump));
+        assert_eq!(map.value(&Action::Jump), 1.0);
+
+        map.update(&input);
+        assert!(!map.is_pressed(&Action::Jump));
+        assert!(map.is_held(&Action::Jump));
+
+        // Still held through the second binding
+        input.press_gamepad_button(0, GamepadButton::RightFaceDown);
+        input.release_key(KeyboardKey::Space);
+        map.update(&input);
+        assert!(map.is_held(&Action::Jump));
+        assert!(!map.is_released(&Action::Jump));
+
+        input.release_gamepad_button(0, GamepadButton::RightFaceDown);
+        map.update(&input);
+        assert!(map.is_released(&Action::Jump));
+        assert!(!map.is_held(&Action::Jump));
+        assert_eq!(map.value(&Action::Jump), 0.0);
+
+        map.update(&input);
+        assert!(!map.is_released(&Action::Jump));
+    }
+
+    #[test]
+    fn unbound_actions_are_idle() {
+        let mut map = InputMap::new();
+        map.bind(Action::Jump, Binding::MouseButton(MouseButton::Left));
+        let mut input = MockInput::new();
+        input.press_mouse_button(MouseButton::Left);
+        map.update(&input);
+        assert!(map.is_pressed(&Action::Jump));
+
+        map.clear_bindings(&Action::Jump);
+        map.update(&input);
+        assert!(!map.is_held(&Action::Jump));
+        assert!(!map.is_held(&Action::Left));
+        assert!(map.bindings(&Action::Jump).is_empty());
+    }
+
+    #[test]
+    fn dead_zone_rescales() {
+        assert_eq!(apply_dead_zone(0.05, 0.1), 0.0);
+        assert_eq!(apply_dead_zone(-0.1, 0.1), 0.0);
+        assert!((apply_dead_zone(0.55, 0.1) - 0.5).abs() < 1e-6);
+        assert!((apply_dead_zone(-0.55, 0.1) + 0.5).abs() < 1e-6);
+        assert_eq!(apply_dead_zone(1.0, 0.1), 1.0);
+        assert_eq!(apply_dead_zone(-1.5, 0.1), -1.0);
+        assert_eq!(apply_dead_zone(0.5, 1.0), 0.0);
+        assert_eq!(apply_dead_zone(0.5, 0.0), 0.5);
+    }
+
+    #[test]
+    fn axis_threshold_selects_direction() {
+        let mut map = InputMap::new();
+        for (action, threshold) in [(Action::Left, -0.5), (Action::Right, 0.5)] {
+            map.bind(
+                action,
+                Binding::GamepadAxis {
+                    gamepad: 1,
+                    axis: GamepadAxis::LeftX,
+                    threshold,
+                },
+            );
+        }
+        let mut input = MockInput::new();
+
+        input.set_gamepad_axis(1, GamepadAxis::LeftX, -1.0);
+        map.update(&input);
+        assert!(map.is_pressed(&Action::Left));
+        assert_eq!(map.value(&Action::Left), 1.0);
+        assert!(!map.is_held(&Action::Right));
+        assert_eq!(map.value(&Action::Right), 0.0);
+
+        // Past the dead zone but below the threshold: a value without a press
+        input.set_gamepad_axis(1, GamepadAxis::LeftX, 0.4);
+        map.update(&input);
+        assert!(map.is_released(&Action::Left));
+        assert!(!map.is_held(&Action::Right));
+        assert!((map.value(&Action::Right) - 1.0 / 3.0).abs() < 1e-6);
+
+        input.set_gamepad_axis(1, GamepadAxis::LeftX, 0.7);
+        map.update(&input);
+        assert!(map.is_pressed(&Action::Right));
+
+        // Another gamepad does not move the bound axis
+        input.clear();
+        input.set_gamepad_axis(0, GamepadAxis::LeftX, 1.0);
+        map.update(&input);
+        assert!(map.is_released(&Action::Right));
+    }
+
+    #[test]
+    fn input_state_events_in_order() {
+        let mut platform = MockPlatform::default();
+        let mut input = InputState::new();
+        platform.press_key(KeyboardKey::A);
+        platform.press_key(KeyboardKey::B);
+        // KEY_F13 has no KeyboardKey variant and is skipped
+        let f13 = AutomationEvent::new(0, AutomationEventType::InputKeyDown, [302, 0, 0, 0]);
+        platform.play_automation_event(&f13.unwrap());
+        platform.type_text("xy");
+        platform.set_mouse_position(Vector2 { x: 3.0, y: 4.0 });
+        platform.scroll(Vector2 { x: -1.0, y: 2.0 });
+        input.update(&mut platform);
+        assert_eq!(
+            input.events(),
+            [
+                InputEvent::KeyDown(KeyboardKey::A),
+                InputEvent::KeyDown(KeyboardKey::B),
+                InputEvent::Char('x'),
+                InputEvent::Char('y'),
+                InputEvent::MouseMove {
+                    position: Vector2 { x: 3.0, y: 4.0 },
+                    delta: Vector2 { x: 3.0, y: 4.0 },
+                },
+                InputEvent::Wheel(Vector2 { x: -1.0, y: 2.0 }),
+            ]
+        );
+        assert_eq!(input.keys_down(), [KeyboardKey::A, KeyboardKey::B]);
+        assert_eq!(input.mouse_wheel(), Vector2 { x: -1.0, y: 2.0 });
+    }
+
+    #[test]
+    fn input_state_key_up_on_a_later_frame() {
+        let mut platform = MockPlatform::default();
+        let mut input = InputState::new();
+        platform.press_key(KeyboardKey::Space);
+        input.update(&mut platform);
+        assert_eq!(input.events(), [InputEvent::KeyDown(KeyboardKey::Space)]);
+
+        platform.next_frame();
+        input.update(&mut platform);
+        assert!(input.events().is_empty());
+        assert!(input.is_key_down(KeyboardKey::Space));
+
+        platform.next_frame();
+        platform.release_key(KeyboardKey::Space);
+        input.update(&mut platform);
+        assert_eq!(input.events(), [InputEvent::KeyUp(KeyboardKey::Space)]);
+        assert!(input.keys_down().is_empty());
+    }
+
+    #[test]
+    fn input_state_gamepad_changes() {
+        let mut platform = MockPlatform::default();
+        let mut input = InputState::new();
+        platform.connect_gamepad(0);
+        input.update(&mut platform);
+        // Axes at rest are not reported when the gamepad connects
+        assert!(input.events().is_empty());
+
+        platform.next_frame();
+        platform.set_gamepad_axis(0, GamepadAxis::LeftX, 0.5);
+        platform.press_gamepad_button(0, GamepadButton::RightFaceDown);
+        input.update(&mut platform);
+        assert_eq!(
+            input.events(),
+            [
+                InputEvent::GamepadButton {
+                    gamepad: 0,
+                    button: GamepadButton::RightFaceDown,
+                    pressed: true,
+                },
+                InputEvent::GamepadAxis {
+                    gamepad: 0,
+                    axis: GamepadAxis::LeftX,
+                    value: 0.5,
+                },
+            ]
+        );
+
+        platform.next_frame();
+        input.update(&mut platform);
+        assert!(input.events().is_empty());
+
+        platform.next_frame();
+        platform.set_gamepad_axis(0, GamepadAxis::LeftX, 0.0);
+        input.update(&mut platform);
+        assert_eq!(
+            input.events(),
+            [InputEvent::GamepadAxis {
+                gamepad: 0,
+                axis: GamepadAxis::LeftX,
+                value: 0.0,
+            }]
+        );
+
+        // A gamepad connecting with an axis away from rest reports it
+        platform.connect_gamepad(1);
+        platform.set_gamepad_axis(1, GamepadAxis::RightTrigger, 1.0);
+        input.update(&mut platform);
+        assert_eq!(
+            input.events(),
+            [InputEvent::GamepadAxis {
+                gamepad: 1,
+                axis: GamepadAxis::RightTrigger,
+                value: 1.0,
+            }]
+        );
+    }
+
+    #[test]
+    fn input_state_touch_phases() {
+        let mut platform = MockPlatform::default();
+        let mut input = InputState::new();
+        let touch = |id, x, y, phase| InputEvent::Touch {
+            id,
+            position: Vector2 { x, y },
+            phase,
+        };
+
+        platform.set_touch_points(&[(7, Vector2 { x: 1.0, y: 1.0 })]);
+        input.update(&mut platform);
+        assert_eq!(input.events(), [touch(7, 1.0, 1.0, TouchPhase::Started)]);
+
+        input.update(&mut platform);
+        assert!(input.events().is_empty());
+
+        platform.set_touch_points(&[
+            (7, Vector2 { x: 2.0, y: 1.0 }),
+            (8, Vector2 { x: 5.0, y: 5.0 }),
+        ]);
+        input.update(&mut platform);
+        assert_eq!(
+            input.events(),
+            [
+                touch(7, 2.0, 1.0, TouchPhase::Moved),
+                touch(8, 5.0, 5.0, TouchPhase::Started),
+            ]
+        );
+
+        platform.set_touch_points(&[(8, Vector2 { x: 5.0, y: 5.0 })]);
+        input.update(&mut platform);
+        assert_eq!(input.events(), [touch(7, 2.0, 1.0, TouchPhase::Ended)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn negative_dead_zone_panics() {
+        InputMap::<Action>::new().set_dead_zone(-0.1);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn input_map_serde_round_trip() {
+        let mut map = InputMap::new();
+        map.bind(Action::Jump, Binding::Key(KeyboardKey::Space));
+        map.bind(
+            Action::Jump,
+            Binding::GamepadButton {
+                gamepad: 0,
+                button: GamepadButton::RightFaceDown,
+            },
+        );
+        map.bind(
+            Action::Left,
+            Binding::GamepadAxis {
+                gamepad: 1,
+                axis: GamepadAxis::LeftX,
+                threshold: -0.5,
+            },
+        );
+        map.bind(Action::Right, Binding::MouseButton(MouseButton::Right));
+        map.set_dead_zone(0.25);
+
+        let json = serde_json::to_string(&map).unwrap();
+        let loaded: InputMap<Action> = serde_json::from_str(&json).unwrap();
+        for action in [Action::Jump, Action::Left, Action::Right] {
+            assert_eq!(loaded.bindings(&action), map.bindings(&action));
+        }
+        assert_eq!(loaded.dead_zone(), 0.25);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn input_map_rejects_invalid_dead_zone() {
+        let load = |json: &str| serde_json::from_str::<InputMap<Action>>(json);
+        let loaded = load(r#"{"bindings":{"Jump":[{"Key":"Space"}]}}"#).unwrap();
+        assert_eq!(loaded.dead_zone(), DEFAULT_DEAD_ZONE);
+        assert_eq!(
+            loaded.bindings(&Action::Jump),
+            [Binding::Key(KeyboardKey::Space)]
+        );
+        assert!(load(r#"{"bindings":{},"dead_zone":-0.5}"#).is_err());
+        assert!(load(r#"{"bindings":{},"dead_zone":1.5}"#).is_err());
+        assert!(load(r#"{"bindings":{},"dead_zone":null}"#).is_err());
+    }
+}
@@ -6,28 +6,27 @@
 // TODO: Only re-export non ffi functions/types
 pub use raylib_ffi::*;
 
+pub mod automation;
+pub mod enums;
+mod error;
+pub use automation::{AutomationEvent, AutomationEvents};
+pub use error::{Error, LoadError, Result};
+pub use raudio::{AudioDevice, AudioStream, Music, Sound, Wave};
+pub use raymath::{BoundingBox, Camera, Camera2D, Camera3D, Ray, Transform};
+pub use raymath::{Matrix, Quaternion, Vector2, Vector3, Vector4};
+pub use rcore::{ConfigFlags, Monitor, Shader};
+pub use rmodels::{Material, MaterialRef, Mesh, Model, ModelAnimation, RayCollision};
+pub use rtext::Font;
+pub use rtextures::{Image, RenderTexture, Texture};
+
+pub mod app;
+pub mod input;
+pub mod platform;
+pub mod raudio;
+pub mod raymath;
+pub mod replay;
 pub mod rcore;
+pub mod rmodels;
 pub mod rshapes;
 pub mod rtext;
-
-
-pub mod rmodels {
-    use raylib_ffi::Color;
-    use raylib_ffi::Vector3;
-    // Basic geometric 3D shapes drawing functions
-    /// Draw cube
-    pub fn draw_cube(position: Vector3, width: f32, height: f32, length: f32, color: Color) {
-        unsafe { raylib_ffi::DrawCube(position, width, height, length, color) }
-    }
-
-    /// Draw cube wires
-    pub fn draw_cube_wires(position: Vector3, width: f32, height: f32, length: f32, color: Color) {
-        unsafe { raylib_ffi::DrawCubeWires(position, width, height, length, color) }
-    }
-
-    /// Draw a grid (centered at (0, 0, 0))
-    pub fn draw_grid(slices: i32, spacing: f32) {
-        unsafe { raylib_ffi::DrawGrid(slices, spacing) }
-    }
-}
-
+pub mod rtextures;
@@ -0,0 +1,934 @@
+//! Platform backend trait over the rcore input, timing and window-query functions
+//!
+//! Game logic written against `Platform` instead of `RaylibHandle` can run headless:
+//! `RaylibHandle` implements it by calling raylib, `MockPlatform` replays input, frame
+//! times and screen sizes scripted by the caller, or played from recorded automation events.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::automation::AutomationEvent;
+use crate::enums::{AutomationEventType, GamepadAxis, GamepadButton, KeyboardKey, MouseButton};
+use crate::rcore::{self, RaylibHandle};
+use crate::Vector2;
+
+/// Input, timing and window queries of the platform the game runs on
+///
+/// The queue functions (`get_key_pressed()`, `get_char_pressed()`) take `&mut self`, every
+/// other query is read-only.
+pub trait Platform {
+    // Window-related functions
+    /// Check if application should close (KEY_ESCAPE pressed or windows close icon clicked)
+    fn window_should_close(&self) -> bool;
+    /// Check if window is currently fullscreen
+    fn is_window_fullscreen(&self) -> bool;
+    /// Check if window is currently minimized
+    fn is_window_minimized(&self) -> bool;
+    /// Check if window is currently focused
+    fn is_window_focused(&self) -> bool;
+    /// Check if window has been resized last frame
+    fn is_window_resized(&self) -> bool;
+    /// Get current screen width
+    fn get_screen_width(&self) -> i32;
+    /// Get current screen height
+    fn get_screen_height(&self) -> i32;
+    /// Get current render width (it considers HiDPI)
+    fn get_render_width(&self) -> i32;
+    /// Get current render height (it considers HiDPI)
+    fn get_render_height(&self) -> i32;
+    /// Get window scale DPI factor
+    fn get_window_scale_dpi(&self) -> Vector2;
+
+    // Timing-related functions
+    /// Get time in seconds for last frame drawn (delta time)
+    fn get_frame_time(&self) -> f32;
+    /// Get elapsed time in seconds since InitWindow()
+    fn get_time(&self) -> f64;
+    /// Get current FPS
+    fn get_fps(&self) -> i32;
+
+    // Input-related functions: keyboard
+    /// Check if a key has been pressed once
+    fn is_key_pressed(&self, key: KeyboardKey) -> bool;
+    /// Check if a key is being pressed
+    fn is_key_down(&self, key: KeyboardKey) -> bool;
+    /// Check if a key has been released once
+    fn is_key_released(&self, key: KeyboardKey) -> bool;
+    /// Check if a key is NOT being pressed
+    fn is_key_up(&self, key: KeyboardKey) -> bool {
+        !self.is_key_down(key)
+    }
+    /// Get key pressed (keycode), call it multiple times for keys queued, returns None when
+    /// the queue is empty and `Error::UnknownEnumValue` for keycodes without a `KeyboardKey`
+    fn get_key_pressed(&mut self) -> Option<crate::Result<KeyboardKey>>;
+    /// Get char pressed (unicode), call it multiple times for chars queued, returns 0 when
+    /// the queue is empty
+    fn get_char_pressed(&mut self) -> i32;
+
+    // Input-related functions: gamepads
+    /// Check if a gamepad is available
+    fn is_gamepad_available(&self, gamepad: i32) -> bool;
+    /// Check if a gamepad button has been pressed once
+    fn is_gamepad_button_pressed(&self, gamepad: i32, button: GamepadButton) -> bool;
+    /// Check if a gamepad button is being pressed
+    fn is_gamepad_button_down(&self, gamepad: i32, button: GamepadButton) -> bool;
+    /// Check if a gamepad button has been released once
+    fn is_gamepad_button_released(&self, gamepad: i32, button: GamepadButton) -> bool;
+    /// Check if a gamepad button is NOT being pressed
+    fn is_gamepad_button_up(&self, gamepad: i32, button: GamepadButton) -> bool {
+        !self.is_gamepad_button_down(gamepad, button)
+    }
+    /// Get gamepad axis count for a gamepad
+    fn get_gamepad_axis_count(&self, gamepad: i32) -> i32;
+    /// Get axis movement value for a gamepad axis
+    fn get_gamepad_axis_movement(&self, gamepad: i32, axis: GamepadAxis) -> f32;
+
+    // Input-related functions: mouse
+    /// Check if a mouse button has been pressed once
+    fn is_mouse_button_pressed(&self, button: MouseButton) -> bool;
+    /// Check if a mouse button is being pressed
+    fn is_mouse_button_down(&self, button: MouseButton) -> bool;
+    /// Check if a mouse button has been released once
+    fn is_mouse_button_released(&self, button: MouseButton) -> bool;
+    /// Check if a mouse button is NOT being pressed
+    fn is_mouse_button_up(&self, button: MouseButton) -> bool {
+        !self.is_mouse_button_down(button)
+    }
+    /// Get mouse position XY
+    fn get_mouse_position(&self) -> Vector2;
+    /// Get mouse delta between frames
+    fn get_mouse_delta(&self) -> Vector2;
+    /// Get mouse wheel movement for X or Y, whichever is larger
+    fn get_mouse_wheel_move(&self) -> f32 {
+        let wheel = self.get_mouse_wheel_move_v();
+        if wheel.x.abs() > wheel.y.abs() {
+            wheel.x
+        } else {
+            wheel.y
+        }
+    }
+    /// Get mouse wheel movement for both X and Y
+    fn get_mouse_wheel_move_v(&self) -> Vector2;
+
+    // Input-related functions: touch
+    /// Get number of touch points
+    fn get_touch_point_count(&self) -> i32;
+    /// Get touch position XY for a touch point index (relative to screen size)
+    fn get_touch_position(&self, index: i32) -> Vector2;
+    /// Get touch point identifier for given index
+    fn get_touch_point_id(&self, index: i32) -> i32;
+}
+
+impl Platform for RaylibHandle {
+    fn window_should_close(&self) -> bool {
+        rcore::window_should_close(self)
+    }
+
+    fn is_window_fullscreen(&self) -> bool {
+        rcore::is_window_fullscreen(self)
+    }
+
+    fn is_window_minimized(&self) -> bool {
+        rcore::is_window_minimized(self)
+    }
+
+    fn is_window_focused(&self) -> bool {
+        rcore::is_window_focused(self)
+    }
+
+    fn is_window_resized(&self) -> bool {
+        rcore::is_window_resized(self)
+    }
+
+    fn get_screen_width(&self) -> i32 {
+        rcore::get_screen_width(self)
+    }
+
+    fn get_screen_height(&self) -> i32 {
+        rcore::get_screen_height(self)
+    }
+
+    fn get_render_width(&self) -> i32 {
+        rcore::get_render_width(self)
+    }
+
+    fn get_render_height(&self) -> i32 {
+        rcore::get_render_height(self)
+    }
+
+    fn get_window_scale_dpi(&self) -> Vector2 {
+        rcore::get_window_scale_dpi(self)
+    }
+
+    fn get_frame_time(&self) -> f32 {
+        rcore::get_frame_time(self)
+    }
+
+    fn get_time(&self) -> f64 {
+        rcore::get_time(self)
+    }
+
+    fn get_fps(&self) -> i32 {
+        rcore::get_fps(self)
+    }
+
+    fn is_key_pressed(&self, key: KeyboardKey) -> bool {
+        rcore::is_key_pressed(self, key)
+    }
+
+    fn is_key_down(&self, key: KeyboardKey) -> bool {
+        rcore::is_key_down(self, key)
+    }
+
+    fn is_key_released(&self, key: KeyboardKey) -> bool {
+        rcore::is_key_released(self, key)
+    }
+
+    fn is_key_up(&self, key: KeyboardKey) -> bool {
+        rcore::is_key_up(self, key)
+    }
+
+    fn get_key_pressed(&mut self) -> Option<crate::Result<KeyboardKey>> {
+        rcore::get_key_pressed(self)
+    }
+
+    fn get_char_pressed(&mut self) -> i32 {
+        rcore::get_char_pressed(self)
+    }
+
+    fn is_gamepad_available(&self, gamepad: i32) -> bool {
+        rcore::is_gamepad_available(self, gamepad)
+    }
+
+    fn is_gamepad_button_pressed(&self, gamepad: i32, button: GamepadButton) -> bool {
+        rcore::is_gamepad_button_pressed(self, gamepad, button)
+    }
+
+    fn is_gamepad_button_down(&self, gamepad: i32, button: GamepadButton) -> bool {
+        rcore::is_gamepad_button_down(self, gamepad, button)
+    }
+
+    fn is_gamepad_button_released(&self, gamepad: i32, button: GamepadButton) -> bool {
+        rcore::is_gamepad_button_released(self, gamepad, button)
+    }
+
+    fn is_gamepad_button_up(&self, gamepad: i32, button: GamepadButton) -> bool {
+        rcore::is_gamepad_button_up(self, gamepad, button)
+    }
+
+    fn get_gamepad_axis_count(&self, gamepad: i32) -> i32 {
+        rcore::get_gamepad_axis_count(self, gamepad)
+    }
+
+    fn get_gamepad_axis_movement(&self, gamepad: i32, axis: GamepadAxis) -> f32 {
+        rcore::get_gamepad_axis_movement(self, gamepad, axis)
+    }
+
+    fn is_mouse_button_pressed(&self, button: MouseButton) -> bool {
+        rcore::is_mouse_button_pressed(self, button)
+    }
+
+    fn is_mouse_button_down(&self, button: MouseButton) -> bool {
+        rcore::is_mouse_button_down(self, button)
+    }
+
+    fn is_mouse_button_released(&self, button: MouseButton) -> bool {
+        rcore::is_mouse_button_released(self, button)
+    }
+
+    fn is_mouse_button_up(&self, button: MouseButton) -> bool {
+        rcore::is_mouse_button_up(self, button)
+    }
+
+    fn get_mouse_position(&self) -> Vector2 {
+        rcore::get_mouse_position(self)
+    }
+
+    fn get_mouse_delta(&self) -> Vector2 {
+        rcore::get_mouse_delta(self)
+    }
+
+    fn get_mouse_wheel_move(&self) -> f32 {
+        rcore::get_mouse_wheel_move(self)
+    }
+
+    fn get_mouse_wheel_move_v(&self) -> Vector2 {
+        rcore::get_mouse_wheel_move_v(self)
+    }
+
+    fn get_touch_point_count(&self) -> i32 {
+        rcore::get_touch_point_count(self)
+    }
+
+    fn get_touch_position(&self, index: i32) -> Vector2 {
+        rcore::get_touch_position(self, index)
+    }
+
+    fn get_touch_point_id(&self, index: i32) -> i32 {
+        rcore::get_touch_point_id(self, index)
+    }
+}
+
+/// Scripted platform for headless tests
+///
+/// Set the input state for the current frame with the `press_*`/`set_*` functions, run the
+/// game logic, then call `next_frame()`. Like raylib's input polling, a button is "pressed"
+/// during the first frame it is down and "released" during the first frame it is up again.
+#[derive(Debug, Clone)]
+pub struct MockPlatform {
+    should_close: bool,
+    fullscreen: bool,
+    minimized: bool,
+    focused: bool,
+    resized: bool,
+    screen_size: (i32, i32),
+    scale_dpi: Vector2,
+    frame_time: f32,
+    time: f64,
+    keys: HashSet<KeyboardKey>,
+    previous_keys: HashSet<KeyboardKey>,
+    key_queue: VecDeque<i32>,
+    char_queue: VecDeque<char>,
+    gamepads: HashSet<i32>,
+    gamepad_buttons: HashSet<(i32, GamepadButton)>,
+    previous_gamepad_buttons: HashSet<(i32, GamepadButton)>,
+    gamepad_axes: HashMap<(i32, GamepadAxis), f32>,
+    mouse_buttons: HashSet<MouseButton>,
+    previous_mouse_buttons: HashSet<MouseButton>,
+    mouse_position: Vector2,
+    previous_mouse_position: Vector2,
+    mouse_wheel: Vector2,
+    touch_points: Vec<(i32, Vector2)>,
+}
+
+impl Default for MockPlatform {
+    fn default() -> Self {
+        Self::new(800, 450)
+    }
+}
+
+impl MockPlatform {
+    /// Create a focused window of the given screen size, running at 60 FPS
+    pub fn new(width: i32, height: i32) -> Self {
+        Self {
+            should_close: false,
+            fullscreen: false,
+            minimized: false,
+            focused: true,
+            resized: false,
+            screen_size: (width, height),
+            scale_dpi: Vector2 { x: 1.0, y: 1.0 },
+            frame_time: 1.0 / 60.0,
+            time: 0.0,
+            keys: HashSet::new(),
+            previous_keys: HashSet::new(),
+            key_queue: VecDeque::new(),
+            char_queue: VecDeque::new(),
+            gamepads: HashSet::new(),
+            gamepad_buttons: HashSet::new(),
+            previous_gamepad_buttons: HashSet::new(),
+            gamepad_axes: HashMap::new(),
+            mouse_buttons: HashSet::new(),
+            previous_mouse_buttons: HashSet::new(),
+            mouse_position: Vector2 { x: 0.0, y: 0.0 },
+            previous_mouse_position: Vector2 { x: 0.0, y: 0.0 },
+            mouse_wheel: Vector2 { x: 0.0, y: 0.0 },
+            touch_points: Vec::new(),
+        }
+    }
+
+    /// End the current frame: advance the time by the frame time, keep the held
+    /// buttons down and clear the per-frame state (queues, wheel, resize)
+    pub fn next_frame(&mut self) {
+        self.time += self.frame_time as f64;
+        self.previous_keys.clone_from(&self.keys);
+        self.previous_gamepad_buttons
+            .clone_from(&self.gamepad_buttons);
+        self.previous_mouse_buttons.clone_from(&self.mouse_buttons);
+        self.previous_mouse_position = self.mouse_position;
+        self.key_queue.clear();
+        self.char_queue.clear();
+        self.mouse_wheel = Vector2 { x: 0.0, y: 0.0 };
+        self.resized = false;
+    }
+
+    /// Make `window_should_close()` return true
+    pub fn request_close(&mut self) {
+        self.should_close = true;
+    }
+
+    /// Set the screen size, `is_window_resized()` is true until the next frame
+    pub fn set_screen_size(&mut self, width: i32, height: i32) {
+        if self.screen_size != (width, height) {
+            self.screen_size = (width, height);
+            self.resized = true;
+        }
+    }
+
+    /// Set the window scale DPI factor, the render size is the screen size scaled by it
+    pub fn set_window_scale_dpi(&mut self, scale: Vector2) {
+        self.scale_dpi = scale;
+    }
+
+    /// Set the value returned by `is_window_fullscreen()`
+    pub fn set_window_fullscreen(&mut self, fullscreen: bool) {
+        self.fullscreen = fullscreen;
+    }
+
+    /// Set the value returned by `is_window_minimized()`
+    pub fn set_window_minimized(&mut self, minimized: bool) {
+        self.minimized = minimized;
+    }
+
+    /// Set the value returned by `is_window_focused()`, windows start focused
+    pub fn set_window_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+
+    /// Set the time of the frames, used by `next_frame()` to advance the time
+    pub fn set_frame_time(&mut self, frame_time: f32) {
+        self.frame_time = frame_time;
+    }
+
+    /// Press a key and queue it for `get_key_pressed()`
+    pub fn press_key(&mut self, key: KeyboardKey) {
+        if self.keys.insert(key) {
+            self.key_queue.push_back(key as i32);
+        }
+    }
+
+    /// Release a key, `is_key_released()` is true until the next frame
+    pub fn release_key(&mut self, key: KeyboardKey) {
+        self.keys.remove(&key);
+    }
+
+    /// Queue the characters of `text` for `get_char_pressed()`
+    pub fn type_text(&mut self, text: &str) {
+        self.char_queue.extend(text.chars());
+    }
+
+    /// Connect a gamepad, gamepads start disconnected
+    pub fn connect_gamepad(&mut self, gamepad: i32) {
+        self.gamepads.insert(gamepad);
+    }
+
+    /// Disconnect a gamepad, releasing its buttons and centering its axes
+    pub fn disconnect_gamepad(&mut self, gamepad: i32) {
+        self.gamepads.remove(&gamepad);
+        self.gamepad_buttons.retain(|&(g, _)| g != gamepad);
+        self.gamepad_axes.retain(|&(g, _), _| g != gamepad);
+    }
+
+    /// Press a gamepad button, ignored if the gamepad is not connected
+    pub fn press_gamepad_button(&mut self, gamepad: i32, button: GamepadButton) {
+        if self.gamepads.contains(&gamepad) {
+            self.gamepad_buttons.insert((gamepad, button));
+        }
+    }
+
+    /// Release a gamepad button
+    pub fn release_gamepad_button(&mut self, gamepad: i32, button: GamepadButton) {
+        self.gamepad_buttons.remove(&(gamepad, button));
+    }
+
+    /// Move a gamepad axis (-1.0 to 1.0), ignored if the gamepad is not connected
+    pub fn set_gamepad_axis(&mut self, gamepad: i32, axis: GamepadAxis, value: f32) {
+        if self.gamepads.contains(&gamepad) {
+            self.gamepad_axes.insert((gamepad, axis), value);
+        }
+    }
+
+    /// Press a mouse button, `is_mouse_button_pressed()` is true until the next frame
+    pub fn press_mouse_button(&mut self, button: MouseButton) {
+        self.mouse_buttons.insert(button);
+    }
+
+    /// Release a mouse button
+    pub fn release_mouse_button(&mut self, button: MouseButton) {
+        self.mouse_buttons.remove(&button);
+    }
+
+    /// Move the mouse, the delta is measured from the position of the previous frame
+    pub fn set_mouse_position(&mut self, position: Vector2) {
+        self.mouse_position = position;
+    }
+
+    /// Add to the mouse wheel movement of the current frame
+    pub fn scroll(&mut self, wheel: Vector2) {
+        self.mouse_wheel.x += wheel.x;
+        self.mouse_wheel.y += wheel.y;
+    }
+
+    /// Set the touch points, as (id, position) pairs
+    pub fn set_touch_points(&mut self, points: &[(i32, Vector2)]) {
+        self.touch_points = points.to_vec();
+    }
+
+    /// Play a recorded automation event, the way PlayAutomationEvent() applies it to raylib
+    ///
+    /// Events that do not change the mocked state (gestures, screenshots, target FPS) and
+    /// events with unknown values are ignored.
+    pub fn play_automation_event(&mut self, event: &AutomationEvent) {
+        let [p0, p1, p2, _] = event.params();
+        let Ok(event_type) = event.event_type() else {
+            return;
+        };
+        match event_type {
+            AutomationEventType::InputKeyUp => {
+                if let Ok(key) = KeyboardKey::try_from(p0) {
+                    self.release_key(key);
+                }
+            }
+            AutomationEventType::InputKeyDown => match KeyboardKey::try_from(p0) {
+                Ok(key) => self.press_key(key),
+                // raylib queues keycodes without a variant too
+                Err(_) => self.key_queue.push_back(p0),
+            },
+            AutomationEventType::InputMouseButtonUp => {
+                if let Ok(button) = MouseButton::try_from(p0) {
+                    self.release_mouse_button(button);
+                }
+            }
+            AutomationEventType::InputMouseButtonDown => {
+                if let Ok(button) = MouseButton::try_from(p0) {
+                    self.press_mouse_button(button);
+                }
+            }
+            AutomationEventType::InputMousePosition => {
+                self.mouse_position = Vector2 {
+                    x: p0 as f32,
+                    y: p1 as f32,
+                };
+            }
+            // raylib 5.0 only plays the X movement of wheel events
+            AutomationEventType::InputMouseWheelMotion => self.mouse_wheel.x = p0 as f32,
+            AutomationEventType::InputGamepadConnect => self.connect_gamepad(p0),
+            AutomationEventType::InputGamepadDisconnect => self.disconnect_gamepad(p0),
+            AutomationEventType::InputGamepadButtonUp => {
+                if let Ok(button) = GamepadButton::try_from(p1) {
+                    self.release_gamepad_button(p0, button);
+                }
+            }
+            AutomationEventType::InputGamepadButtonDown => {
+                if let Ok(button) = GamepadButton::try_from(p1) {
+                    self.press_gamepad_button(p0, button);
+                }
+            }
+            AutomationEventType::InputGamepadAxisMotion => {
+                if let Ok(axis) = GamepadAxis::try_from(p1) {
+                    self.set_gamepad_axis(p0, axis, p2 as f32 / 32768.0);
+                }
+            }
+            AutomationEventType::InputTouchUp => self.touch_points.retain(|&(id, _)| id != p0),
+            AutomationEventType::InputTouchDown => {
+                if !self.touch_points.iter().any(|&(id, _)| id == p0) {
+                    self.touch_points.push((p0, Vector2 { x: 0.0, y: 0.0 }));
+                }
+            }
+            AutomationEventType::InputTouchPosition => {
+                let position = Vector2 {
+                    x: p1 as f32,
+                    y: p2 as f32,
+                };
+                match self.touch_points.iter_mut().find(|(id, _)| *id == p0) {
+                    Some((_, point)) => *point = position,
+                    None => self.touch_points.push((p0, position)),
+                }
+            }
+            AutomationEventType::WindowClose => self.request_close(),
+            AutomationEventType::WindowMaximize => self.set_window_minimized(false),
+            AutomationEventType::WindowMinimize => self.set_window_minimized(true),
+            AutomationEventType::WindowResize => self.set_screen_size(p0, p1),
+            AutomationEventType::EventNone
+            | AutomationEventType::InputKeyPressed
+            | AutomationEventType::InputKeyReleased
+            | AutomationEventType::InputGesture
+            | AutomationEventType::ActionTakeScreenshot
+            | AutomationEventType::ActionSetTargetFps => {}
+        }
+    }
+}
+
+impl Platform for MockPlatform {
+    fn window_should_close(&self) -> bool {
+        self.should_close
+    }
+
+    fn is_window_fullscreen(&self) -> bool {
+        self.fullscreen
+    }
+
+    fn is_window_minimized(&self) -> bool {
+        self.minimized
+    }
+
+    fn is_window_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn is_window_resized(&self) -> bool {
+        self.resized
+    }
+
+    fn get_screen_width(&self) -> i32 {
+        self.screen_size.0
+    }
+
+    fn get_screen_height(&self) -> i32 {
+        self.screen_size.1
+    }
+
+    fn get_render_width(&self) -> i32 {
+        (self.screen_size.0 as f32 * self.scale_dpi.x) as i32
+    }
+
+    fn get_render_height(&self) -> i32 {
+        (self.screen_size.1 as f32 * self.scale_dpi.y) as i32
+    }
+
+    fn get_window_scale_dpi(&self) -> Vector2 {
+        self.scale_dpi
+    }
+
+    fn get_frame_time(&self) -> f32 {
+        self.frame_time
+    }
+
+    fn get_time(&self) -> f64 {
+        self.time
+    }
+
+    fn get_fps(&self) -> i32 {
+        if self.frame_time > 0.0 {
+            (1.0 / self.frame_time).round() as i32
+        } else {
+            0
+        }
+    }
+
+    fn is_key_pressed(&self, key: KeyboardKey) -> bool {
+        self.keys.contains(&key) && !self.previous_keys.contains(&key)
+    }
+
+    fn is_key_down(&self, key: KeyboardKey) -> bool {
+        self.keys.contains(&key)
+    }
+
+    fn is_key_released(&self, key: KeyboardKey) -> bool {
+        !self.keys.contains(&key) && self.previous_keys.contains(&key)
+    }
+
+    fn get_key_pressed(&mut self) -> Option<crate::Result<KeyboardKey>> {
+        self.key_queue.pop_front().map(KeyboardKey::try_from)
+    }
+
+    fn get_char_pressed(&mut self) -> i32 {
+        self.char_queue.pop_front().map_or(0, |c| c as i32)
+    }
+
+    fn is_gamepad_available(&self, gamepad: i32) -> bool {
+        self.gamepads.contains(&gamepad)
+    }
+
+    fn is_gamepad_button_pressed(&self, gamepad: i32, button: GamepadButton) -> bool {
+        self.gamepad_buttons.contains(&(gamepad, button))
+            && !self.previous_gamepad_buttons.contains(&(gamepad, button))
+    }
+
+    fn is_gamepad_button_down(&self, gamepad: i32, button: GamepadButton) -> bool {
+        self.gamepad_buttons.contains(&(gamepad, button))
+    }
+
+    fn is_gamepad_button_released(&self, gamepad: i32, button: GamepadButton) -> bool {
+        !self.gamepad_buttons.contains(&(gamepad, button))
+            && self.previous_gamepad_buttons.contains(&(gamepad, button))
+    }
+
+    fn get_gamepad_axis_count(&self, gamepad: i32) -> i32 {
+        if self.is_gamepad_available(gamepad) {
+            GamepadAxis::VARIANTS.len() as i32
+        } else {
+            0
+        }
+    }
+
+    fn get_gamepad_axis_movement(&self, gamepad: i32, axis: GamepadAxis) -> f32 {
+        self.gamepad_axes
+            .get(&(gamepad, axis))
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    fn is_mouse_button_pressed(&self, button: MouseButton) -> bool {
+        self.mouse_buttons.contains(&button) && !self.previous_mouse_buttons.contains(&button)
+    }
+
+    fn is_mouse_button_down(&self, button: MouseButton) -> bool {
+        self.mouse_buttons.contains(&button)
+    }
+
+    fn is_mouse_button_released(&self, button: MouseButton) -> bool {
+        !self.mouse_buttons.contains(&button) && self.previous_mouse_buttons.contains(&button)
+    }
+
+    fn get_mouse_position(&self) -> Vector2 {
+        self.mouse_position
+    }
+
+    fn get_mouse_delta(&self) -> Vector2 {
+        Vector2 {
+            x: self.mouse_position.x - self.previous_mouse_position.x,
+            y: self.mouse_position.y - self.previous_mouse_position.y,
+        }
+    }
+
+    fn get_mouse_wheel_move_v(&self) -> Vector2 {
+        self.mouse_wheel
+    }
+
+    fn get_touch_point_count(&self) -> i32 {
+        self.touch_points.len() as i32
+    }
+
+    fn get_touch_position(&self, index: i32) -> Vector2 {
+        usize::try_from(index)
+            .ok()
+            .and_then(|i| self.touch_points.get(i))
+            .map_or(Vector2 { x: 0.0, y: 0.0 }, |&(_, position)| position)
+    }
+
+    fn get_touch_point_id(&self, index: i32) -> i32 {
+        usize::try_from(index)
+            .ok()
+            .and_then(|i| self.touch_points.get(i))
+            .map_or(-1, |&(id, _)| id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+
+    #[test]
+    fn key_edges_across_frames() {
+        let mut platform = MockPlatform::default();
+        platform.press_key(KeyboardKey::A);
+        assert!(platform.is_key_pressed(KeyboardKey::A));
+        assert!(platform.is_key_down(KeyboardKey::A));
+        assert_eq!(platform.get_key_pressed(), Some(Ok(KeyboardKey::A)));
+        assert_eq!(platform.get_key_pressed(), None);
+
+        platform.next_frame();
+        assert!(!platform.is_key_pressed(KeyboardKey::A));
+        assert!(platform.is_key_down(KeyboardKey::A));
+
+        platform.release_key(KeyboardKey::A);
+        assert!(platform.is_key_released(KeyboardKey::A));
+        assert!(platform.is_key_up(KeyboardKey::A));
+
+        platform.next_frame();
+        assert!(!platform.is_key_released(KeyboardKey::A));
+        assert!(platform.is_key_up(KeyboardKey::A));
+    }
+
+    #[test]
+    fn queues_are_cleared_every_frame() {
+        let mut platform = MockPlatform::default();
+        platform.press_key(KeyboardKey::B);
+        platform.press_key(KeyboardKey::C);
+        platform.type_text("hé");
+        assert_eq!(platform.get_key_pressed(), Some(Ok(KeyboardKey::B)));
+        assert_eq!(platform.get_char_pressed(), 'h' as i32);
+        assert_eq!(platform.get_char_pressed(), 'é' as i32);
+        assert_eq!(platform.get_char_pressed(), 0);
+
+        platform.next_frame();
+        assert_eq!(platform.get_key_pressed(), None);
+        // Holding a key does not queue it again
+        platform.press_key(KeyboardKey::B);
+        assert_eq!(platform.get_key_pressed(), None);
+    }
+
+    #[test]
+    fn frame_time_advances_time() {
+        let mut platform = MockPlatform::new(640, 480);
+        assert_eq!(platform.get_fps(), 60);
+        platform.set_frame_time(0.25);
+        assert_eq!(platform.get_frame_time(), 0.25);
+        assert_eq!(platform.get_fps(), 4);
+        assert_eq!(platform.get_time(), 0.0);
+        platform.next_frame();
+        platform.next_frame();
+        assert_eq!(platform.get_time(), 0.5);
+        platform.set_frame_time(0.0);
+        assert_eq!(platform.get_fps(), 0);
+    }
+
+    #[test]
+    fn screen_size_and_resize() {
+        let mut platform = MockPlatform::new(640, 480);
+        assert_eq!(platform.get_screen_width(), 640);
+        assert_eq!(platform.get_screen_height(), 480);
+        assert!(!platform.is_window_resized());
+
+        platform.set_screen_size(1280, 720);
+        assert!(platform.is_window_resized());
+        assert_eq!(platform.get_screen_width(), 1280);
+        platform.set_window_scale_dpi(Vector2 { x: 2.0, y: 1.5 });
+        assert_eq!(platform.get_render_width(), 2560);
+        assert_eq!(platform.get_render_height(), 1080);
+
+        platform.next_frame();
+        assert!(!platform.is_window_resized());
+        platform.set_screen_size(1280, 720);
+        assert!(!platform.is_window_resized());
+    }
+
+    #[test]
+    fn window_state() {
+        let mut platform = MockPlatform::default();
+        assert!(platform.is_window_focused());
+        assert!(!platform.window_should_close());
+        platform.set_window_focused(false);
+        platform.set_window_minimized(true);
+        platform.set_window_fullscreen(true);
+        assert!(!platform.is_window_focused());
+        assert!(platform.is_window_minimized());
+        assert!(platform.is_window_fullscreen());
+        platform.request_close();
+        assert!(platform.window_should_close());
+    }
+
+    #[test]
+    fn mouse_delta_and_wheel() {
+        let mut platform = MockPlatform::default();
+        platform.set_mouse_position(Vector2 { x: 10.0, y: 20.0 });
+        platform.next_frame();
+        platform.set_mouse_position(Vector2 { x: 15.0, y: 10.0 });
+        assert_eq!(platform.get_mouse_delta(), Vector2 { x: 5.0, y: -10.0 });
+        platform.scroll(Vector2 { x: 0.0, y: 1.0 });
+        platform.scroll(Vector2 { x: 0.0, y: 2.0 });
+        assert_eq!(
+            platform.get_mouse_wheel_move_v(),
+            Vector2 { x: 0.0, y: 3.0 }
+        );
+
+        platform.press_mouse_button(MouseButton::Right);
+        assert!(platform.is_mouse_button_pressed(MouseButton::Right));
+        platform.next_frame();
+        assert_eq!(platform.get_mouse_delta(), Vector2 { x: 0.0, y: 0.0 });
+        assert_eq!(
+            platform.get_mouse_wheel_move_v(),
+            Vector2 { x: 0.0, y: 0.0 }
+        );
+        assert!(!platform.is_mouse_button_pressed(MouseButton::Right));
+        platform.release_mouse_button(MouseButton::Right);
+        assert!(platform.is_mouse_button_released(MouseButton::Right));
+    }
+
+    #[test]
+    fn gamepad_buttons_and_axes() {
+        let mut platform = MockPlatform::default();
+        assert!(!platform.is_gamepad_available(0));
+        assert_eq!(platform.get_gamepad_axis_count(0), 0);
+        platform.connect_gamepad(0);
+        assert_eq!(platform.get_gamepad_axis_count(0), 6);
+
+        platform.press_gamepad_button(0, GamepadButton::LeftTrigger1);
+        platform.set_gamepad_axis(0, GamepadAxis::RightY, -0.5);
+        assert!(platform.is_gamepad_button_pressed(0, GamepadButton::LeftTrigger1));
+        assert!(!platform.is_gamepad_button_down(1, GamepadButton::LeftTrigger1));
+        assert_eq!(
+            platform.get_gamepad_axis_movement(0, GamepadAxis::RightY),
+            -0.5
+        );
+
+        platform.next_frame();
+        assert!(!platform.is_gamepad_button_pressed(0, GamepadButton::LeftTrigger1));
+        platform.disconnect_gamepad(0);
+        assert!(platform.is_gamepad_button_released(0, GamepadButton::LeftTrigger1));
+        assert_eq!(
+            platform.get_gamepad_axis_movement(0, GamepadAxis::RightY),
+            0.0
+        );
+    }
+
+    #[test]
+    fn disconnected_gamepads_read_as_idle() {
+        let mut platform = MockPlatform::default();
+        platform.press_gamepad_button(2, GamepadButton::RightFaceDown);
+        platform.set_gamepad_axis(2, GamepadAxis::LeftY, 1.0);
+        assert!(platform.is_gamepad_button_up(2, GamepadButton::RightFaceDown));
+        assert_eq!(
+            platform.get_gamepad_axis_movement(2, GamepadAxis::LeftY),
+            0.0
+        );
+
+        // Connecting the gamepad does not replay what was set while it was disconnected
+        platform.connect_gamepad(2);
+        assert!(!platform.is_gamepad_button_down(2, GamepadButton::RightFaceDown));
+        assert_eq!(
+            platform.get_gamepad_axis_movement(2, GamepadAxis::LeftY),
+            0.0
+        );
+    }
+
+    #[test]
+    fn touch_points() {
+        let mut platform = MockPlatform::default();
+        platform.set_touch_points(&[(3, Vector2 { x: 1.0, y: 2.0 })]);
+        assert_eq!(platform.get_touch_point_count(), 1);
+        assert_eq!(platform.get_touch_point_id(0), 3);
+        assert_eq!(platform.get_touch_position(0), Vector2 { x: 1.0, y: 2.0 });
+        assert_eq!(platform.get_touch_point_id(1), -1);
+        assert_eq!(platform.get_touch_position(-1), Vector2 { x: 0.0, y: 0.0 });
+    }
+
+    #[test]
+    fn automation_events_drive_the_mock() {
+        let mut platform = MockPlatform::default();
+        let events = [
+            AutomationEvent::key_down(0, KeyboardKey::Enter),
+            AutomationEvent::mouse_position(0, 30, 40),
+            AutomationEvent::gamepad_connect(0, 1).unwrap(),
+            AutomationEvent::gamepad_axis_motion(0, 1, GamepadAxis::LeftX, 0.5).unwrap(),
+            AutomationEvent::touch_position(0, 2, 5, 6).unwrap(),
+            AutomationEvent::window_resize(0, 320, 240),
+        ];
+        for event in &events {
+            platform.play_automation_event(event);
+        }
+        assert!(platform.is_key_pressed(KeyboardKey::Enter));
+        assert_eq!(platform.get_key_pressed(), Some(Ok(KeyboardKey::Enter)));
+        assert_eq!(platform.get_mouse_position(), Vector2 { x: 30.0, y: 40.0 });
+        assert!(platform.is_gamepad_available(1));
+        assert_eq!(
+            platform.get_gamepad_axis_movement(1, GamepadAxis::LeftX),
+            0.5
+        );
+        assert_eq!(platform.get_touch_point_id(0), 2);
+        assert_eq!(platform.get_screen_width(), 320);
+        assert!(platform.is_window_resized());
+
+        platform.next_frame();
+        platform.play_automation_event(&AutomationEvent::key_up(1, KeyboardKey::Enter));
+        platform.play_automation_event(&AutomationEvent::window_close(1));
+        assert!(platform.is_key_released(KeyboardKey::Enter));
+        assert!(platform.window_should_close());
+    }
+
+    #[test]
+    fn unknown_keycodes_are_queued() {
+        let mut platform = MockPlatform::default();
+        // KEY_F13, queued by GLFW but without a KeyboardKey variant
+        let f13 = AutomationEvent::new(0, AutomationEventType::InputKeyDown, [302, 0, 0, 0]);
+        platform.play_automation_event(&f13.unwrap());
+        platform.press_key(KeyboardKey::Space);
+        assert!(matches!(
+            platform.get_key_pressed(),
+            Some(Err(Error::UnknownEnumValue { value: 302, .. }))
+        ));
+        assert_eq!(platform.get_key_pressed(), Some(Ok(KeyboardKey::Space)));
+        assert_eq!(platform.get_key_pressed(), None);
+    }
+}
@@ -0,0 +1,847 @@
+use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
+use std::num::NonZeroU32;
+use std::ops::Deref;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::error::{check_file, has_extension};
+use crate::{Error, LoadError};
+
+/// Set while an `AudioDevice` is alive
+static AUDIO_DEVICE_INITIALIZED: AtomicBool = AtomicBool::new(false);
+
+/// Owned audio device and context, the device is closed on drop
+///
+/// Only one device can exist at a time. It is required (by reference) to load
+/// sounds, music and audio streams.
+#[derive(Debug)]
+pub struct AudioDevice {
+    // raylib audio must be driven from the thread that initialized it
+    _marker: PhantomData<*const ()>,
+}
+
+impl Drop for AudioDevice {
+    fn drop(&mut self) {
+        unsafe {
+            raylib_ffi::CloseAudioDevice();
+        }
+        AUDIO_DEVICE_INITIALIZED.store(false, Ordering::SeqCst);
+    }
+}
+
+/// Check if an `AudioDevice` is alive, audio buffers can only be unloaded while it is
+fn is_audio_device_open() -> bool {
+    AUDIO_DEVICE_INITIALIZED.load(Ordering::SeqCst)
+}
+
+/// Wave data stored in CPU memory (RAM), unloaded on drop
+#[derive(Debug)]
+pub struct Wave {
+    raw: raylib_ffi::Wave,
+}
+
+impl Wave {
+    /// Take ownership of a raw wave, it will be unloaded on drop
+    ///
+    /// # Safety
+    /// `raw` must be a valid wave allocated by raylib that is not owned elsewhere
+    pub unsafe fn from_raw(raw: raylib_ffi::Wave) -> Self {
+        Self { raw }
+    }
+
+    /// Release ownership of the raw wave, it will no longer be unloaded on drop
+    pub fn into_raw(self) -> raylib_ffi::Wave {
+        let raw = self.raw;
+        std::mem::forget(self);
+        raw
+    }
+
+    /// Get a copy of the raw wave, only valid while `self` is alive and unmodified
+    pub fn as_raw(&self) -> raylib_ffi::Wave {
+        self.raw
+    }
+
+    /// Total number of frames (considering channels)
+    pub fn frame_count(&self) -> u32 {
+        self.raw.frameCount
+    }
+
+    /// Frequency (samples per second)
+    pub fn sample_rate(&self) -> u32 {
+        self.raw.sampleRate
+    }
+
+    /// Bit depth (bits per sample): 8, 16, 32 (24 not supported)
+    pub fn sample_size(&self) -> u32 {
+        self.raw.sampleSize
+    }
+
+    /// Number of channels (1-mono, 2-stereo, ...)
+    pub fn channels(&self) -> u32 {
+        self.raw.channels
+    }
+}
+
+impl Clone for Wave {
+    fn clone(&self) -> Self {
+        wave_copy(self)
+    }
+}
+
+impl Drop for Wave {
+    fn drop(&mut self) {
+        unsafe { raylib_ffi::UnloadWave(self.raw) }
+    }
+}
+
+/// Bit depth of wave samples, as accepted by `wave_format()`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SampleSize {
+    /// 8 bit unsigned samples
+    Bits8 = 8,
+    /// 16 bit signed samples
+    Bits16 = 16,
+    /// 32 bit float samples
+    Bits32 = 32,
+}
+
+/// Sound loaded in an audio buffer, unloaded on drop
+#[derive(Debug)]
+pub struct Sound {
+    raw: raylib_ffi::Sound,
+}
+
+impl Sound {
+    /// Take ownership of a raw sound, it will be unloaded on drop
+    ///
+    /// # Safety
+    /// `raw` must be a valid sound loaded by raylib that is not owned elsewhere
+    pub unsafe fn from_raw(raw: raylib_ffi::Sound) -> Self {
+        Self { raw }
+    }
+
+    /// Release ownership of the raw sound, it will no longer be unloaded on drop
+    pub fn into_raw(self) -> raylib_ffi::Sound {
+        let raw = self.raw;
+        std::mem::forget(self);
+        raw
+    }
+
+    /// Get a copy of the raw sound, only valid while `self` is alive
+    pub fn as_raw(&self) -> raylib_ffi::Sound {
+        self.raw
+    }
+
+    /// Total number of frames (considering channels)
+    pub fn frame_count(&self) -> u32 {
+        self.raw.frameCount
+    }
+}
+
+impl Drop for Sound {
+    fn drop(&mut self) {
+        // The audio buffers can't be released once the device is closed
+        if is_audio_device_open() {
+            unsafe { raylib_ffi::UnloadSound(self.raw) }
+        }
+    }
+}
+
+/// Sound sharing the sample data of a source sound, unloaded on drop
+///
+/// Derefs to `Sound` so it can be played like any other sound.
+#[derive(Debug)]
+pub struct SoundAlias<'a> {
+    sound: ManuallyDrop<Sound>,
+    _source: PhantomData<&'a Sound>,
+}
+
+impl Deref for SoundAlias<'_> {
+    type Target = Sound;
+
+    fn deref(&self) -> &Self::Target {
+        &self.sound
+    }
+}
+
+impl Drop for SoundAlias<'_> {
+    fn drop(&mut self) {
+        // Releases the alias buffer only, the sample data belongs to the source
+        if is_audio_device_open() {
+            unsafe { raylib_ffi::UnloadSoundAlias(self.sound.raw) }
+        }
+    }
+}
+
+/// Music stream decoded while playing, unloaded on drop
+#[derive(Debug)]
+pub struct Music {
+    raw: raylib_ffi::Music,
+    // File data of streams loaded from memory, read by the decoder while playing
+    _data: Option<Vec<u8>>,
+}
+
+impl Music {
+    /// Get a copy of the raw music, only valid while `self` is alive
+    pub fn as_raw(&self) -> raylib_ffi::Music {
+        self.raw
+    }
+
+    /// Total number of frames (considering channels)
+    pub fn frame_count(&self) -> u32 {
+        self.raw.frameCount
+    }
+
+    /// Music looping enable
+    pub fn looping(&self) -> bool {
+        self.raw.looping
+    }
+
+    /// Enable or disable music looping
+    pub fn set_looping(&mut self, looping: bool) {
+        self.raw.looping = looping;
+    }
+}
+
+impl Drop for Music {
+    fn drop(&mut self) {
+        if is_audio_device_open() {
+            unsafe { raylib_ffi::UnloadMusicStream(self.raw) }
+        }
+    }
+}
+
+/// Audio stream to play raw pcm data, unloaded on drop
+#[derive(Debug)]
+pub struct AudioStream {
+    raw: raylib_ffi::AudioStream,
+}
+
+impl AudioStream {
+    /// Take ownership of a raw audio stream, it will be unloaded on drop
+    ///
+    /// # Safety
+    /// `raw` must be a valid audio stream loaded by raylib that is not owned elsewhere
+    pub unsafe fn from_raw(raw: raylib_ffi::AudioStream) -> Self {
+        Self { raw }
+    }
+
+    /// Release ownership of the raw audio stream, it will no longer be unloaded on drop
+    pub fn into_raw(self) -> raylib_ffi::AudioStream {
+        let raw = self.raw;
+        std::mem::forget(self);
+        raw
+    }
+
+    /// Get a copy of the raw audio stream, only valid while `self` is alive
+    pub fn as_raw(&self) -> raylib_ffi::AudioStream {
+        self.raw
+    }
+
+    /// Frequency (samples per second)
+    pub fn sample_rate(&self) -> u32 {
+        self.raw.sampleRate
+    }
+
+    /// Bit depth (bits per sample): 8, 16, 32 (24 not supported)
+    pub fn sample_size(&self) -> u32 {
+        self.raw.sampleSize
+    }
+
+    /// Number of channels (1-mono, 2-stereo, ...)
+    pub fn channels(&self) -> u32 {
+        self.raw.channels
+    }
+}
+
+impl Drop for AudioStream {
+    fn drop(&mut self) {
+        if is_audio_device_open() {
+            unsafe { raylib_ffi::UnloadAudioStream(self.raw) }
+        }
+    }
+}
+
+/// Sample type of raw pcm data sent to an `AudioStream`
+///
+/// # Safety
+/// `SAMPLE_SIZE` must be the size of the type in bits
+pub unsafe trait AudioSample: Copy {
+    /// Bit depth (bits per sample)
+    const SAMPLE_SIZE: u32;
+}
+
+unsafe impl AudioSample for u8 {
+    const SAMPLE_SIZE: u32 = 8;
+}
+
+unsafe impl AudioSample for i16 {
+    const SAMPLE_SIZE: u32 = 16;
+}
+
+unsafe impl AudioSample for f32 {
+    const SAMPLE_SIZE: u32 = 32;
+}
+
+/// Wave file types accepted by LoadWave()
+const WAVE_FILE_EXTENSIONS: &[&str] = &[".wav", ".ogg", ".mp3", ".qoa"];
+
+/// Music file types accepted by LoadMusicStream(), modules are streamed only
+const MUSIC_FILE_EXTENSIONS: &[&str] = &[".wav", ".ogg", ".mp3", ".qoa", ".xm", ".mod"];
+
+// Audio device management functions
+/// Initialize audio device and context
+pub fn init_audio_device() -> crate::Result<AudioDevice> {
+    if AUDIO_DEVICE_INITIALIZED.swap(true, Ordering::SeqCst) {
+        return Err(Error::AudioDeviceAlreadyInitialized);
+    }
+    #[cfg(feature = "log")]
+    crate::rcore::install_log_forwarding();
+    unsafe {
+        raylib_ffi::InitAudioDevice();
+        if !raylib_ffi::IsAudioDeviceReady() {
+            AUDIO_DEVICE_INITIALIZED.store(false, Ordering::SeqCst);
+            return Err(Error::AudioDeviceInitFailed);
+        }
+    }
+    Ok(AudioDevice {
+        _marker: PhantomData,
+    })
+}
+
+/// Set master volume (listener)
+pub fn set_master_volume(_audio: &AudioDevice, volume: f32) {
+    unsafe { raylib_ffi::SetMasterVolume(volume) }
+}
+
+/// Get master volume (listener)
+pub fn get_master_volume(_audio: &AudioDevice) -> f32 {
+    unsafe { raylib_ffi::GetMasterVolume() }
+}
+
+// Wave/Sound loading/unloading functions
+/// Turn a wave without data into an error
+fn check_wave(path: &str, wave: raylib_ffi::Wave) -> crate::Result<Wave> {
+    if wave.data.is_null() {
+        Err(Error::load(path, LoadError::InvalidData))
+    } else {
+        Ok(Wave { raw: wave })
+    }
+}
+
+/// Turn a sound without audio buffer into an error
+fn check_sound(path: &str, sound: raylib_ffi::Sound) -> crate::Result<Sound> {
+    if sound.stream.buffer.is_null() {
+        Err(Error::load(path, LoadError::AudioBufferFailed))
+    } else {
+        Ok(Sound { raw: sound })
+    }
+}
+
+/// Load wave data from file
+pub fn load_wave(file_name: &str) -> crate::Result<Wave> {
+    let c_file_name = check_file(file_name, WAVE_FILE_EXTENSIONS)?;
+    let wave = unsafe { raylib_ffi::LoadWave(c_file_name.as_ptr()) };
+    check_wave(file_name, wave)
+}
+
+/// Load wave from memory buffer, fileType refers to extension: i.e. '.wav'
+pub fn load_wave_from_memory(file_type: &str, file_data: &[u8]) -> crate::Result<Wave> {
+    if !has_extension(file_type, WAVE_FILE_EXTENSIONS) {
+        return Err(Error::load(file_type, LoadError::UnsupportedFormat));
+    }
+    let wave = unsafe {
+        raylib_ffi::LoadWaveFromMemory(
+            raylib_ffi::rl_str!(file_type),
+            file_data.as_ptr(),
+            file_data.len() as i32,
+        )
+    };
+    check_wave(file_type, wave)
+}
+
+/// Checks if wave data is ready
+pub fn is_wave_ready(wave: &Wave) -> bool {
+    unsafe { raylib_ffi::IsWaveReady(wave.raw) }
+}
+
+/// Load sound from file
+pub fn load_sound(_audio: &AudioDevice, file_name: &str) -> crate::Result<Sound> {
+    // Loaded through a Wave to tell decoding and buffer errors apart
+    let wave = load_wave(file_name)?;
+    let sound = unsafe { raylib_ffi::LoadSoundFromWave(wave.raw) };
+    check_sound(file_name, sound)
+}
+
+/// Load sound from wave data
+pub fn load_sound_from_wave(_audio: &AudioDevice, wave: &Wave) -> crate::Result<Sound> {
+    if !is_wave_ready(wave) {
+        return Err(Error::load("<wave>", LoadError::InvalidData));
+    }
+    let sound = unsafe { raylib_ffi::LoadSoundFromWave(wave.raw) };
+    check_sound("<wave>", sound)
+}
+
+/// Create a new sound that shares the same sample data as the source sound, does not own the sound data
+pub fn load_sound_alias(source: &Sound) -> crate::Result<SoundAlias<'_>> {
+    let alias = unsafe { raylib_ffi::LoadSoundAlias(source.raw) };
+    if alias.stream.buffer.is_null() {
+        return Err(Error::load("<sound alias>", LoadError::AudioBufferFailed));
+    }
+    Ok(SoundAlias {
+        sound: ManuallyDrop::new(Sound { raw: alias }),
+        _source: PhantomData,
+    })
+}
+
+/// Checks if a sound is ready
+pub fn is_sound_ready(sound: &Sound) -> bool {
+    unsafe { raylib_ffi::IsSoundReady(sound.raw) }
+}
+
+/// Export wave data to file, returns true on success
+pub fn export_wave(wave: &Wave, file_name: &str) -> bool {
+    unsafe { raylib_ffi::ExportWave(wave.raw, raylib_ffi::rl_str!(file_name)) }
+}
+
+/// Export wave sample data to code (.h), returns true on success
+pub fn export_wave_as_code(wave: &Wave, file_name: &str) -> bool {
+    unsafe { raylib_ffi::ExportWaveAsCode(wave.raw, raylib_ffi::rl_str!(file_name)) }
+}
+
+// Wave/Sound management functions
+/// Play a sound
+pub fn play_sound(sound: &Sound) {
+    unsafe { raylib_ffi::PlaySound(sound.raw) }
+}
+
+/// Stop playing a sound
+pub fn stop_sound(sound: &Sound) {
+    unsafe { raylib_ffi::StopSound(sound.raw) }
+}
+
+/// Pause a sound
+pub fn pause_sound(sound: &Sound) {
+    unsafe { raylib_ffi::PauseSound(sound.raw) }
+}
+
+/// Resume a paused sound
+pub fn resume_sound(sound: &Sound) {
+    unsafe { raylib_ffi::ResumeSound(sound.raw) }
+}
+
+/// Check if a sound is currently playing
+pub fn is_sound_playing(sound: &Sound) -> bool {
+    unsafe { raylib_ffi::IsSoundPlaying(sound.raw) }
+}
+
+/// Set volume for a sound (1.0 is max level)
+pub fn set_sound_volume(sound: &Sound, volume: f32) {
+    unsafe { raylib_ffi::SetSoundVolume(sound.raw, volume) }
+}
+
+/// Set pitch for a sound (1.0 is base level)
+pub fn set_sound_pitch(sound: &Sound, pitch: f32) {
+    unsafe { raylib_ffi::SetSoundPitch(sound.raw, pitch) }
+}
+
+/// Set pan for a sound (0.5 is center)
+pub fn set_sound_pan(sound: &Sound, pan: f32) {
+    unsafe { raylib_ffi::SetSoundPan(sound.raw, pan) }
+}
+
+/// Copy a wave to a new wave
+pub fn wave_copy(wave: &Wave) -> Wave {
+    Wave {
+        raw: unsafe { raylib_ffi::WaveCopy(wave.raw) },
+    }
+}
+
+/// Crop a wave to defined samples range
+pub fn wave_crop(wave: &mut Wave, init_sample: i32, final_sample: i32) {
+    unsafe { raylib_ffi::WaveCrop(&mut wave.raw, init_sample, final_sample) }
+}
+
+/// Convert wave data to desired format
+///
+/// Returns false and leaves the wave unchanged if the conversion fails or the converted
+/// data would be too large.
+pub fn wave_format(
+    wave: &mut Wave,
+    sample_rate: NonZeroU32,
+    sample_size: SampleSize,
+    channels: NonZeroU32,
+) -> bool {
+    // WaveFormat() allocates frameCount*channels*(sampleSize/8) bytes with int arithmetic,
+    // estimate the converted size (resampling adds at most a frame) to catch overflows
+    let frame_count = u64::from(wave.raw.frameCount) * u64::from(sample_rate.get())
+        / u64::from(wave.raw.sampleRate.max(1))
+        + 1;
+    let size = frame_count * u64::from(channels.get()) * (sample_size as u64 / 8);
+    if wave.raw.data.is_null() || size > i32::MAX as u64 {
+        return false;
+    }
+    unsafe {
+        raylib_ffi::WaveFormat(
+            &mut wave.raw,
+            sample_rate.get() as i32,
+            sample_size as i32,
+            channels.get() as i32,
+        )
+    }
+    wave.raw.sampleRate == sample_rate.get()
+        && wave.raw.sampleSize == sample_size as u32
+        && wave.raw.channels == channels.get()
+}
+
+/// Load samples data from wave as a 32bit float data array
+pub fn load_wave_samples(wave: &Wave) -> Vec<f32> {
+    unsafe {
+        let samples_ptr = raylib_ffi::LoadWaveSamples(wave.raw);
+        if samples_ptr.is_null() {
+            return Vec::new();
+        }
+        let count = (wave.raw.frameCount * wave.raw.channels) as usize;
+        let samples = std::slice::from_raw_parts(samples_ptr, count).to_vec();
+        raylib_ffi::UnloadWaveSamples(samples_ptr);
+        samples
+    }
+}
+
+// Music management functions
+/// Turn a music stream without decoder context into an error
+fn check_music(
+    path: &str,
+    music: raylib_ffi::Music,
+    data: Option<Vec<u8>>,
+) -> crate::Result<Music> {
+    if music.ctxData.is_null() {
+        Err(Error::load(path, LoadError::InvalidData))
+    } else if music.stream.buffer.is_null() {
+        unsafe { raylib_ffi::UnloadMusicStream(music) };
+        Err(Error::load(path, LoadError::AudioBufferFailed))
+    } else {
+        Ok(Music {
+            raw: music,
+            _data: data,
+        })
+    }
+}
+
+/// Load music stream from file
+pub fn load_music_stream(_audio: &AudioDevice, file_name: &str) -> crate::Result<Music> {
+    let c_file_name = check_file(file_name, MUSIC_FILE_EXTENSIONS)?;
+    let music = unsafe { raylib_ffi::LoadMusicStream(c_file_name.as_ptr()) };
+    check_music(file_name, music, None)
+}
+
+/// Load music stream from data, the data is copied and kept alive with the stream
+pub fn load_music_stream_from_memory(
+    _audio: &AudioDevice,
+    file_type: &str,
+    data: &[u8],
+) -> crate::Result<Music> {
+    if !has_extension(file_type, MUSIC_FILE_EXTENSIONS) {
+        return Err(Error::load(file_type, LoadError::UnsupportedFormat));
+    }
+    let data = data.to_vec();
+    let music = unsafe {
+        raylib_ffi::LoadMusicStreamFromMemory(
+            raylib_ffi::rl_str!(file_type),
+            data.as_ptr(),
+            data.len() as i32,
+        )
+    };
+    check_music(file_type, music, Some(data))
+}
+
+/// Checks if a music stream is ready
+pub fn is_music_ready(music: &Music) -> bool {
+    unsafe { raylib_ffi::IsMusicReady(music.raw) }
+}
+
+/// Start music playing
+pub fn play_music_stream(music: &Music) {
+    unsafe { raylib_ffi::PlayMusicStream(music.raw) }
+}
+
+/// Check if music is playing
+pub fn is_music_stream_playing(music: &Music) -> bool {
+    unsafe { raylib_ffi::IsMusicStreamPlaying(music.raw) }
+}
+
+/// Updates buffers for music streaming, call it every frame while playing
+pub fn update_music_stream(music: &mut Music) {
+    unsafe { raylib_ffi::UpdateMusicStream(music.raw) }
+}
+
+/// Stop music playing
+pub fn stop_music_stream(music: &Music) {
+    unsafe { raylib_ffi::StopMusicStream(music.raw) }
+}
+
+/// Pause music playing
+pub fn pause_music_stream(music: &Music) {
+    unsafe { raylib_ffi::PauseMusicStream(music.raw) }
+}
+
+/// Resume playing paused music
+pub fn resume_music_stream(music: &Music) {
+    unsafe { raylib_ffi::ResumeMusicStream(music.raw) }
+}
+
+/// Seek music to a position (in seconds)
+pub fn seek_music_stream(music: &mut Music, position: f32) {
+    unsafe { raylib_ffi::SeekMusicStream(music.raw, position) }
+}
+
+/// Set volume for music (1.0 is max level)
+pub fn set_music_volume(music: &Music, volume: f32) {
+    unsafe { raylib_ffi::SetMusicVolume(music.raw, volume) }
+}
+
+/// Set pitch for a music (1.0 is base level)
+pub fn set_music_pitch(music: &Music, pitch: f32) {
+    unsafe { raylib_ffi::SetMusicPitch(music.raw, pitch) }
+}
+
+/// Set pan for a music (0.5 is center)
+pub fn set_music_pan(music: &Music, pan: f32) {
+    unsafe { raylib_ffi::SetMusicPan(music.raw, pan) }
+}
+
+/// Get music time length (in seconds)
+pub fn get_music_time_length(music: &Music) -> f32 {
+    unsafe { raylib_ffi::GetMusicTimeLength(music.raw) }
+}
+
+/// Get current music time played (in seconds)
+pub fn get_music_time_played(music: &Music) -> f32 {
+    unsafe { raylib_ffi::GetMusicTimePlayed(music.raw) }
+}
+
+// AudioStream management functions
+/// Load audio stream (to stream raw audio pcm data)
+///
+/// `sample_size` must match the `AudioSample` type later given to `update_audio_stream()`.
+pub fn load_audio_stream(
+    _audio: &AudioDevice,
+    sample_rate: NonZeroU32,
+    sample_size: SampleSize,
+    channels: NonZeroU32,
+) -> crate::Result<AudioStream> {
+    let stream = unsafe {
+        raylib_ffi::LoadAudioStream(sample_rate.get(), sample_size as u32, channels.get())
+    };
+    if stream.buffer.is_null() {
+        return Err(Error::load("<audio stream>", LoadError::AudioBufferFailed));
+    }
+    Ok(AudioStream { raw: stream })
+}
+
+/// Checks if an audio stream is ready
+pub fn is_audio_stream_ready(stream: &AudioStream) -> bool {
+    unsafe { raylib_ffi::IsAudioStreamReady(stream.raw) }
+}
+
+/// Update audio stream buffers with data, `samples` are interleaved frames in the stream format
+///
+/// # Panics
+/// If the sample type does not match the stream sample size
+pub fn update_audio_stream<T: AudioSample>(stream: &mut AudioStream, samples: &[T]) {
+    assert_eq!(
+        T::SAMPLE_SIZE,
+        stream.raw.sampleSize,
+        "update_audio_stream: sample type does not match the stream sample size"
+    );
+    let frame_count = samples.len() / stream.raw.channels.max(1) as usize;
+    unsafe {
+        raylib_ffi::UpdateAudioStream(
+            stream.raw,
+            samples.as_ptr() as *const std::ffi::c_void,
+            frame_count as i32,
+        )
+    }
+}
+
+/// Check if any audio stream buffers requires refill
+pub fn is_audio_stream_processed(stream: &AudioStream) -> bool {
+    unsafe { raylib_ffi::IsAudioStreamProcessed(stream.raw) }
+}
+
+/// Play audio stream
+pub fn play_audio_stream(stream: &AudioStream) {
+    unsafe { raylib_ffi::PlayAudioStream(stream.raw) }
+}
+
+/// Pause audio stream
+pub fn pause_audio_stream(stream: &AudioStream) {
+    unsafe { raylib_ffi::PauseAudioStream(stream.raw) }
+}
+
+/// Resume audio stream
+pub fn resume_audio_stream(stream: &AudioStream) {
+    unsafe { raylib_ffi::ResumeAudioStream(stream.raw) }
+}
+
+/// Check if audio stream is playing
+pub fn is_audio_stream_playing(stream: &AudioStream) -> bool {
+    unsafe { raylib_ffi::IsAudioStreamPlaying(stream.raw) }
+}
+
+/// Stop audio stream
+pub fn stop_audio_stream(stream: &AudioStream) {
+    unsafe { raylib_ffi::StopAudioStream(stream.raw) }
+}
+
+/// Set volume for audio stream (1.0 is max level)
+pub fn set_audio_stream_volume(stream: &AudioStream, volume: f32) {
+    unsafe { raylib_ffi::SetAudioStreamVolume(stream.raw, volume) }
+}
+
+/// Set pitch for audio stream (1.0 is base level)
+pub fn set_audio_stream_pitch(stream: &AudioStream, pitch: f32) {
+    unsafe { raylib_ffi::SetAudioStreamPitch(stream.raw, pitch) }
+}
+
+/// Set pan for audio stream (0.5 is centered)
+pub fn set_audio_stream_pan(stream: &AudioStream, pan: f32) {
+    unsafe { raylib_ffi::SetAudioStreamPan(stream.raw, pan) }
+}
+
+/// Default size for new audio streams
+pub fn set_audio_stream_buffer_size_default(_audio: &AudioDevice, size: i32) {
+    unsafe { raylib_ffi::SetAudioStreamBufferSizeDefault(size) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 16 bit mono WAV file data with the given samples
+    fn wav_data(sample_rate: u32, samples: &[i16]) -> Vec<u8> {
+        let data_size = (samples.len() * 2) as u32;
+        let mut data = Vec::new();
+        data.extend_from_slice(b"RIFF");
+        data.extend_from_slice(&(36 + data_size).to_le_bytes());
+        data.extend_from_slice(b"WAVEfmt ");
+        data.extend_from_slice(&16u32.to_le_bytes());
+        data.extend_from_slice(&1u16.to_le_bytes()); // PCM
+        data.extend_from_slice(&1u16.to_le_bytes()); // Mono
+        data.extend_from_slice(&sample_rate.to_le_bytes());
+        data.extend_from_slice(&(sample_rate * 2).to_le_bytes());
+        data.extend_from_slice(&2u16.to_le_bytes());
+        data.extend_from_slice(&16u16.to_le_bytes());
+        data.extend_from_slice(b"data");
+        data.extend_from_slice(&data_size.to_le_bytes());
+        for sample in samples {
+            data.extend_from_slice(&sample.to_le_bytes());
+        }
+        data
+    }
+
+    fn test_wave() -> Wave {
+        let samples: Vec<i16> = (0..100)
+            .map(|i| if i % 2 == 0 { 16384 } else { -16384 })
+            .collect();
+        load_wave_from_memory(".wav", &wav_data(44100, &samples)).unwrap()
+    }
+
+    fn assert_samples_near(samples: &[f32], expected: &[f32]) {
+        assert_eq!(samples.len(), expected.len());
+        for (sample, expected) in samples.iter().zip(expected) {
+            assert!(
+                (sample - expected).abs() < 0.01,
+                "{} != {}",
+                sample,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn load_wave_from_wav_data() {
+        let wave = test_wave();
+        assert!(is_wave_ready(&wave));
+        assert_eq!(wave.frame_count(), 100);
+        assert_eq!(wave.sample_rate(), 44100);
+        assert_eq!(wave.sample_size(), 16);
+        assert_eq!(wave.channels(), 1);
+        let samples = load_wave_samples(&wave);
+        assert_samples_near(&samples[..2], &[0.5, -0.5]);
+    }
+
+    #[test]
+    fn load_wave_rejects_bad_data() {
+        assert!(matches!(
+            load_wave_from_memory(".wav", b"not a wav file"),
+            Err(Error::Load {
+                reason: LoadError::InvalidData,
+                ..
+            })
+        ));
+        assert!(matches!(
+            load_wave_from_memory(".txt", &wav_data(44100, &[0; 4])),
+            Err(Error::Load {
+                reason: LoadError::UnsupportedFormat,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn wave_format_converts() {
+        let mut wave = test_wave();
+        let two = NonZeroU32::new(2).unwrap();
+        assert!(wave_format(
+            &mut wave,
+            NonZeroU32::new(44100).unwrap(),
+            SampleSize::Bits32,
+            two
+        ));
+        assert_eq!(wave.sample_size(), 32);
+        assert_eq!(wave.channels(), 2);
+        assert_eq!(wave.frame_count(), 100);
+        // Mono is copied to both channels
+        assert_samples_near(&load_wave_samples(&wave)[..4], &[0.5, 0.5, -0.5, -0.5]);
+
+        assert!(wave_format(
+            &mut wave,
+            NonZeroU32::new(22050).unwrap(),
+            SampleSize::Bits8,
+            two
+        ));
+        assert_eq!(wave.sample_rate(), 22050);
+        assert_eq!(wave.sample_size(), 8);
+        // The resampler may hold back a few frames
+        assert!((45..=51).contains(&wave.frame_count()));
+    }
+
+    #[test]
+    fn wave_format_rejects_oversized_conversion() {
+        let mut wave = test_wave();
+        assert!(!wave_format(
+            &mut wave,
+            NonZeroU32::new(44100).unwrap(),
+            SampleSize::Bits32,
+            NonZeroU32::MAX,
+        ));
+        assert_eq!(wave.channels(), 1);
+        assert_eq!(wave.sample_size(), 16);
+        assert_eq!(wave.frame_count(), 100);
+    }
+
+    #[test]
+    fn export_wave_round_trips() {
+        let wave = test_wave();
+        let path = std::env::temp_dir().join(format!("raylib-rs-wave-{}.wav", std::process::id()));
+        let path = path.to_str().unwrap();
+        assert!(export_wave(&wave, path));
+        let loaded = load_wave(path);
+        std::fs::remove_file(path).unwrap();
+        let loaded = loaded.unwrap();
+        assert_eq!(loaded.frame_count(), wave.frame_count());
+        assert_eq!(loaded.sample_rate(), wave.sample_rate());
+        assert_eq!(load_wave_samples(&loaded), load_wave_samples(&wave));
+    }
+}
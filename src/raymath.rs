@@ -0,0 +1,2281 @@
+//! Pure-Rust port of raymath.h
+//!
+//! The types in this module mirror the `raylib_ffi` math structs (and the cameras, rays and
+//! boxes built from them) field for field and convert to and from them with `From`/`Into`.
+//! They are re-exported at the crate root and used by all the safe wrappers, so the
+//! operators and methods below apply to the values passed to and returned by raylib.
+//! Every function follows the order of operations of its raymath.h counterpart so results
+//! match the C implementation.
+
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+const EPSILON: f32 = 0.000001;
+
+//------------------------------------------------------------------------------------
+// Utils math
+//------------------------------------------------------------------------------------
+
+/// Clamp float value
+pub fn clamp(value: f32, min: f32, max: f32) -> f32 {
+    let result = if value < min { min } else { value };
+    if result > max {
+        max
+    } else {
+        result
+    }
+}
+
+/// Calculate linear interpolation between two floats
+pub fn lerp(start: f32, end: f32, amount: f32) -> f32 {
+    start + amount * (end - start)
+}
+
+/// Normalize input value within input range
+pub fn normalize(value: f32, start: f32, end: f32) -> f32 {
+    (value - start) / (end - start)
+}
+
+/// Remap input value within input range to output range
+pub fn remap(
+    value: f32,
+    input_start: f32,
+    input_end: f32,
+    output_start: f32,
+    output_end: f32,
+) -> f32 {
+    (value - input_start) / (input_end - input_start) * (output_end - output_start) + output_start
+}
+
+/// Wrap input value from min to max
+pub fn wrap(value: f32, min: f32, max: f32) -> f32 {
+    value - (max - min) * ((value - min) / (max - min)).floor()
+}
+
+/// Check whether two given floats are almost equal
+pub fn float_equals(x: f32, y: f32) -> bool {
+    (x - y).abs() <= EPSILON * 1.0f32.max(x.abs().max(y.abs()))
+}
+
+//------------------------------------------------------------------------------------
+// Types
+//------------------------------------------------------------------------------------
+
+/// Vector2, 2 components
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Vector2 {
+    pub x: f32,
+    pub y: f32,
+}
+
+/// Vector3, 3 components
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Vector3 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+/// Vector4, 4 components
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Vector4 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+/// Quaternion, 4 components (Vector4 alias)
+pub type Quaternion = Vector4;
+
+/// Matrix, 4x4 components, column major, OpenGL style, right-handed
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Matrix {
+    pub m0: f32,
+    pub m4: f32,
+    pub m8: f32,
+    pub m12: f32,
+    pub m1: f32,
+    pub m5: f32,
+    pub m9: f32,
+    pub m13: f32,
+    pub m2: f32,
+    pub m6: f32,
+    pub m10: f32,
+    pub m14: f32,
+    pub m3: f32,
+    pub m7: f32,
+    pub m11: f32,
+    pub m15: f32,
+}
+
+/// Camera, defines position/orientation in 3d space
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Camera3D {
+    /// Camera position
+    pub position: Vector3,
+    /// Camera target it looks-at
+    pub target: Vector3,
+    /// Camera up vector (rotation over its axis)
+    pub up: Vector3,
+    /// Camera field-of-view aperture in Y (degrees) in perspective, used as near plane width in orthographic
+    pub fovy: f32,
+    /// Camera projection: CAMERA_PERSPECTIVE or CAMERA_ORTHOGRAPHIC
+    pub projection: i32,
+}
+
+/// Camera type fallback, defaults to Camera3D
+pub type Camera = Camera3D;
+
+/// Camera2D, defines position/orientation in 2d space
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Camera2D {
+    /// Camera offset (displacement from target)
+    pub offset: Vector2,
+    /// Camera target (rotation and zoom origin)
+    pub target: Vector2,
+    /// Camera rotation in degrees
+    pub rotation: f32,
+    /// Camera zoom (scaling), should be 1.0f by default
+    pub zoom: f32,
+}
+
+/// Ray, ray for raycasting
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Ray {
+    /// Ray position (origin)
+    pub position: Vector3,
+    /// Ray direction
+    pub direction: Vector3,
+}
+
+/// BoundingBox
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct BoundingBox {
+    /// Minimum vertex box-corner
+    pub min: Vector3,
+    /// Maximum vertex box-corner
+    pub max: Vector3,
+}
+
+/// Transform, vertex transformation data
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Transform {
+    /// Translation
+    pub translation: Vector3,
+    /// Rotation
+    pub rotation: Quaternion,
+    /// Scale
+    pub scale: Vector3,
+}
+
+macro_rules! impl_ffi_conversions {
+    ($ty:ident { $($field:ident),+ }) => {
+        // Same layout as the raw type, so slices and pointers can be cast too
+        const _: () = assert!(
+            std::mem::size_of::<$ty>() == std::mem::size_of::<raylib_ffi::$ty>()
+                && std::mem::align_of::<$ty>() == std::mem::align_of::<raylib_ffi::$ty>()
+        );
+
+        impl From<raylib_ffi::$ty> for $ty {
+            #[allow(clippy::useless_conversion)]
+            fn from(v: raylib_ffi::$ty) -> Self {
+                Self { $($field: v.$field.into()),+ }
+            }
+        }
+
+        impl From<$ty> for raylib_ffi::$ty {
+            #[allow(clippy::useless_conversion)]
+            fn from(v: $ty) -> Self {
+                Self { $($field: v.$field.into()),+ }
+            }
+        }
+    };
+}
+
+impl_ffi_conversions!(Vector2 { x, y });
+impl_ffi_conversions!(Vector3 { x, y, z });
+impl_ffi_conversions!(Vector4 { x, y, z, w });
+impl_ffi_conversions!(Matrix {
+    m0,
+    m4,
+    m8,
+    m12,
+    m1,
+    m5,
+    m9,
+    m13,
+    m2,
+    m6,
+    m10,
+    m14,
+    m3,
+    m7,
+    m11,
+    m15
+});
+impl_ffi_conversions!(Camera3D {
+    position,
+    target,
+    up,
+    fovy,
+    projection
+});
+impl_ffi_conversions!(Camera2D {
+    offset,
+    target,
+    rotation,
+    zoom
+});
+impl_ffi_conversions!(Ray {
+    position,
+    direction
+});
+impl_ffi_conversions!(BoundingBox { min, max });
+impl_ffi_conversions!(Transform {
+    translation,
+    rotation,
+    scale
+});
+
+//----------------------------------------------------------------------------------
+// Vector2 math
+//----------------------------------------------------------------------------------
+
+impl Vector2 {
+    pub const fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+
+    /// Vector with components value 0.0f
+    pub const fn zero() -> Self {
+        Self::new(0.0, 0.0)
+    }
+
+    /// Vector with components value 1.0f
+    pub const fn one() -> Self {
+        Self::new(1.0, 1.0)
+    }
+
+    /// Add vector and float value
+    pub fn add_value(self, add: f32) -> Self {
+        Self::new(self.x + add, self.y + add)
+    }
+
+    /// Subtract vector by float value
+    pub fn sub_value(self, sub: f32) -> Self {
+        Self::new(self.x - sub, self.y - sub)
+    }
+
+    /// Calculate vector length
+    pub fn length(self) -> f32 {
+        ((self.x * self.x) + (self.y * self.y)).sqrt()
+    }
+
+    /// Calculate vector square length
+    pub fn length_sqr(self) -> f32 {
+        (self.x * self.x) + (self.y * self.y)
+    }
+
+    /// Calculate two vectors dot product
+    pub fn dot(self, v: Self) -> f32 {
+        self.x * v.x + self.y * v.y
+    }
+
+    /// Calculate distance between two vectors
+    pub fn distance(self, v: Self) -> f32 {
+        ((self.x - v.x) * (self.x - v.x) + (self.y - v.y) * (self.y - v.y)).sqrt()
+    }
+
+    /// Calculate square distance between two vectors
+    pub fn distance_sqr(self, v: Self) -> f32 {
+        (self.x - v.x) * (self.x - v.x) + (self.y - v.y) * (self.y - v.y)
+    }
+
+    /// Calculate angle between two vectors
+    /// NOTE: Angle is calculated from origin point (0, 0)
+    pub fn angle(self, v: Self) -> f32 {
+        let dot = self.x * v.x + self.y * v.y;
+        let det = self.x * v.y - self.y * v.x;
+        det.atan2(dot)
+    }
+
+    /// Calculate angle defined by a two vectors line
+    /// NOTE: Parameters need to be normalized
+    /// Current implementation should be aligned with glm::angle
+    pub fn line_angle(start: Self, end: Self) -> f32 {
+        -(end.y - start.y).atan2(end.x - start.x)
+    }
+
+    /// Scale vector (multiply by value)
+    pub fn scale(self, scale: f32) -> Self {
+        Self::new(self.x * scale, self.y * scale)
+    }
+
+    /// Normalize provided vector
+    pub fn normalize(self) -> Self {
+        let mut result = Self::zero();
+        let length = ((self.x * self.x) + (self.y * self.y)).sqrt();
+        if length > 0.0 {
+            let ilength = 1.0 / length;
+            result.x = self.x * ilength;
+            result.y = self.y * ilength;
+        }
+        result
+    }
+
+    /// Transforms a Vector2 by a given Matrix
+    pub fn transform(self, mat: Matrix) -> Self {
+        let x = self.x;
+        let y = self.y;
+        let z = 0.0;
+        Self::new(
+            mat.m0 * x + mat.m4 * y + mat.m8 * z + mat.m12,
+            mat.m1 * x + mat.m5 * y + mat.m9 * z + mat.m13,
+        )
+    }
+
+    /// Calculate linear interpolation between two vectors
+    pub fn lerp(self, v: Self, amount: f32) -> Self {
+        Self::new(
+            self.x + amount * (v.x - self.x),
+            self.y + amount * (v.y - self.y),
+        )
+    }
+
+    /// Calculate reflected vector to normal
+    pub fn reflect(self, normal: Self) -> Self {
+        let dot_product = self.x * normal.x + self.y * normal.y;
+        Self::new(
+            self.x - (2.0 * normal.x) * dot_product,
+            self.y - (2.0 * normal.y) * dot_product,
+        )
+    }
+
+    /// Rotate vector by angle
+    pub fn rotate(self, angle: f32) -> Self {
+        let cosres = angle.cos();
+        let sinres = angle.sin();
+        Self::new(
+            self.x * cosres - self.y * sinres,
+            self.x * sinres + self.y * cosres,
+        )
+    }
+
+    /// Move Vector towards target
+    pub fn move_towards(self, target: Self, max_distance: f32) -> Self {
+        let dx = target.x - self.x;
+        let dy = target.y - self.y;
+        let value = (dx * dx) + (dy * dy);
+        if value == 0.0 || (max_distance >= 0.0 && value <= max_distance * max_distance) {
+            return target;
+        }
+        let dist = value.sqrt();
+        Self::new(
+            self.x + dx / dist * max_distance,
+            self.y + dy / dist * max_distance,
+        )
+    }
+
+    /// Invert the given vector
+    pub fn invert(self) -> Self {
+        Self::new(1.0 / self.x, 1.0 / self.y)
+    }
+
+    /// Clamp the components of the vector between
+    /// min and max values specified by the given vectors
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        Self::new(max.x.min(min.x.max(self.x)), max.y.min(min.y.max(self.y)))
+    }
+
+    /// Clamp the magnitude of the vector between two min and max values
+    pub fn clamp_value(self, min: f32, max: f32) -> Self {
+        let mut result = self;
+        let length = (self.x * self.x) + (self.y * self.y);
+        if length > 0.0 {
+            let length = length.sqrt();
+            if length < min {
+                let scale = min / length;
+                result.x = self.x * scale;
+                result.y = self.y * scale;
+            } else if length > max {
+                let scale = max / length;
+                result.x = self.x * scale;
+                result.y = self.y * scale;
+            }
+        }
+        result
+    }
+
+    /// Check whether two given vectors are almost equal
+    pub fn equals(self, q: Self) -> bool {
+        float_equals(self.x, q.x) && float_equals(self.y, q.y)
+    }
+}
+
+//----------------------------------------------------------------------------------
+// Vector3 math
+//----------------------------------------------------------------------------------
+
+impl Vector3 {
+    pub const fn new(x: f32, y: f32, z: f32) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Vector with components value 0.0f
+    pub const fn zero() -> Self {
+        Self::new(0.0, 0.0, 0.0)
+    }
+
+    /// Vector with components value 1.0f
+    pub const fn one() -> Self {
+        Self::new(1.0, 1.0, 1.0)
+    }
+
+    /// Add vector and float value
+    pub fn add_value(self, add: f32) -> Self {
+        Self::new(self.x + add, self.y + add, self.z + add)
+    }
+
+    /// Subtract vector by float value
+    pub fn sub_value(self, sub: f32) -> Self {
+        Self::new(self.x - sub, self.y - sub, self.z - sub)
+    }
+
+    /// Multiply vector by scalar
+    pub fn scale(self, scalar: f32) -> Self {
+        Self::new(self.x * scalar, self.y * scalar, self.z * scalar)
+    }
+
+    /// Calculate two vectors cross product
+    pub fn cross(self, v: Self) -> Self {
+        Self::new(
+            self.y * v.z - self.z * v.y,
+            self.z * v.x - self.x * v.z,
+            self.x * v.y - self.y * v.x,
+        )
+    }
+
+    /// Calculate one vector perpendicular vector
+    pub fn perpendicular(self) -> Self {
+        let mut min = self.x.abs();
+        let mut cardinal_axis = Self::new(1.0, 0.0, 0.0);
+
+        if self.y.abs() < min {
+            min = self.y.abs();
+            cardinal_axis = Self::new(0.0, 1.0, 0.0);
+        }
+
+        if self.z.abs() < min {
+            cardinal_axis = Self::new(0.0, 0.0, 1.0);
+        }
+
+        // Cross product between vectors
+        self.cross(cardinal_axis)
+    }
+
+    /// Calculate vector length
+    pub fn length(self) -> f32 {
+        (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    }
+
+    /// Calculate vector square length
+    pub fn length_sqr(self) -> f32 {
+        self.x * self.x + self.y * self.y + self.z * self.z
+    }
+
+    /// Calculate two vectors dot product
+    pub fn dot(self, v: Self) -> f32 {
+        self.x * v.x + self.y * v.y + self.z * v.z
+    }
+
+    /// Calculate distance between two vectors
+    pub fn distance(self, v: Self) -> f32 {
+        let dx = v.x - self.x;
+        let dy = v.y - self.y;
+        let dz = v.z - self.z;
+        (dx * dx + dy * dy + dz * dz).sqrt()
+    }
+
+    /// Calculate square distance between two vectors
+    pub fn distance_sqr(self, v: Self) -> f32 {
+        let dx = v.x - self.x;
+        let dy = v.y - self.y;
+        let dz = v.z - self.z;
+        dx * dx + dy * dy + dz * dz
+    }
+
+    /// Calculate angle between two vectors
+    pub fn angle(self, v: Self) -> f32 {
+        let cross = self.cross(v);
+        let len = (cross.x * cross.x + cross.y * cross.y + cross.z * cross.z).sqrt();
+        let dot = self.x * v.x + self.y * v.y + self.z * v.z;
+        len.atan2(dot)
+    }
+
+    /// Normalize provided vector
+    pub fn normalize(self) -> Self {
+        let mut result = self;
+        let length = (self.x * self.x + self.y * self.y + self.z * self.z).sqrt();
+        if length != 0.0 {
+            let ilength = 1.0 / length;
+            result.x *= ilength;
+            result.y *= ilength;
+            result.z *= ilength;
+        }
+        result
+    }
+
+    /// Calculate the projection of the vector v1 on to v2
+    pub fn project(self, v: Self) -> Self {
+        let v1dv2 = self.x * v.x + self.y * v.y + self.z * v.z;
+        let v2dv2 = v.x * v.x + v.y * v.y + v.z * v.z;
+        let mag = v1dv2 / v2dv2;
+        Self::new(v.x * mag, v.y * mag, v.z * mag)
+    }
+
+    /// Calculate the rejection of the vector v1 on to v2
+    pub fn reject(self, v: Self) -> Self {
+        let v1dv2 = self.x * v.x + self.y * v.y + self.z * v.z;
+        let v2dv2 = v.x * v.x + v.y * v.y + v.z * v.z;
+        let mag = v1dv2 / v2dv2;
+        Self::new(
+            self.x - (v.x * mag),
+            self.y - (v.y * mag),
+            self.z - (v.z * mag),
+        )
+    }
+
+    /// Orthonormalize provided vectors
+    /// Makes vectors normalized and orthogonal to each other
+    /// Gram-Schmidt function implementation
+    pub fn ortho_normalize(v1: &mut Self, v2: &mut Self) {
+        // Vector3Normalize(*v1);
+        let mut length = v1.length();
+        if length == 0.0 {
+            length = 1.0;
+        }
+        let mut ilength = 1.0 / length;
+        v1.x *= ilength;
+        v1.y *= ilength;
+        v1.z *= ilength;
+
+        // Vector3CrossProduct(*v1, *v2)
+        let mut vn1 = v1.cross(*v2);
+
+        // Vector3Normalize(vn1);
+        length = vn1.length();
+        if length == 0.0 {
+            length = 1.0;
+        }
+        ilength = 1.0 / length;
+        vn1.x *= ilength;
+        vn1.y *= ilength;
+        vn1.z *= ilength;
+
+        // Vector3CrossProduct(vn1, *v1)
+        *v2 = vn1.cross(*v1);
+    }
+
+    /// Transforms a Vector3 by a given Matrix
+    pub fn transform(self, mat: Matrix) -> Self {
+        let x = self.x;
+        let y = self.y;
+        let z = self.z;
+        Self::new(
+            mat.m0 * x + mat.m4 * y + mat.m8 * z + mat.m12,
+            mat.m1 * x + mat.m5 * y + mat.m9 * z + mat.m13,
+            mat.m2 * x + mat.m6 * y + mat.m10 * z + mat.m14,
+        )
+    }
+
+    /// Transform a vector by quaternion rotation
+    pub fn rotate_by_quaternion(self, q: Quaternion) -> Self {
+        let v = self;
+        Self::new(
+            v.x * (q.x * q.x + q.w * q.w - q.y * q.y - q.z * q.z)
+                + v.y * (2.0 * q.x * q.y - 2.0 * q.w * q.z)
+                + v.z * (2.0 * q.x * q.z + 2.0 * q.w * q.y),
+            v.x * (2.0 * q.w * q.z + 2.0 * q.x * q.y)
+                + v.y * (q.w * q.w - q.x * q.x + q.y * q.y - q.z * q.z)
+                + v.z * (-2.0 * q.w * q.x + 2.0 * q.y * q.z),
+            v.x * (-2.0 * q.w * q.y + 2.0 * q.x * q.z)
+                + v.y * (2.0 * q.w * q.x + 2.0 * q.y * q.z)
+                + v.z * (q.w * q.w - q.x * q.x - q.y * q.y + q.z * q.z),
+        )
+    }
+
+    /// Rotates a vector around an axis
+    pub fn rotate_by_axis_angle(self, axis: Self, angle: f32) -> Self {
+        // Using Euler-Rodrigues Formula
+        // Ref.: https://en.wikipedia.org/w/index.php?title=Euler%E2%80%93Rodrigues_formula
+
+        let mut result = self;
+
+        // Vector3Normalize(axis);
+        let mut length = axis.length();
+        if length == 0.0 {
+            length = 1.0;
+        }
+        let ilength = 1.0 / length;
+        let axis = Self::new(axis.x * ilength, axis.y * ilength, axis.z * ilength);
+
+        let angle = angle / 2.0;
+        let mut a = angle.sin();
+        let b = axis.x * a;
+        let c = axis.y * a;
+        let d = axis.z * a;
+        a = angle.cos();
+        let w = Self::new(b, c, d);
+
+        // Vector3CrossProduct(w, v)
+        let mut wv = w.cross(self);
+
+        // Vector3CrossProduct(w, wv)
+        let mut wwv = w.cross(wv);
+
+        // Vector3Scale(wv, 2*a)
+        a *= 2.0;
+        wv.x *= a;
+        wv.y *= a;
+        wv.z *= a;
+
+        // Vector3Scale(wwv, 2)
+        wwv.x *= 2.0;
+        wwv.y *= 2.0;
+        wwv.z *= 2.0;
+
+        result.x += wv.x;
+        result.y += wv.y;
+        result.z += wv.z;
+
+        result.x += wwv.x;
+        result.y += wwv.y;
+        result.z += wwv.z;
+
+        result
+    }
+
+    /// Calculate linear interpolation between two vectors
+    pub fn lerp(self, v: Self, amount: f32) -> Self {
+        Self::new(
+            self.x + amount * (v.x - self.x),
+            self.y + amount * (v.y - self.y),
+            self.z + amount * (v.z - self.z),
+        )
+    }
+
+    /// Calculate reflected vector to normal
+    pub fn reflect(self, normal: Self) -> Self {
+        // I is the original vector
+        // N is the normal of the incident plane
+        // R = I - (2*N*(DotProduct[I, N]))
+
+        let dot_product = self.x * normal.x + self.y * normal.y + self.z * normal.z;
+        Self::new(
+            self.x - (2.0 * normal.x) * dot_product,
+            self.y - (2.0 * normal.y) * dot_product,
+            self.z - (2.0 * normal.z) * dot_product,
+        )
+    }
+
+    /// Get min value for each pair of components
+    pub fn min(self, v: Self) -> Self {
+        Self::new(self.x.min(v.x), self.y.min(v.y), self.z.min(v.z))
+    }
+
+    /// Get max value for each pair of components
+    pub fn max(self, v: Self) -> Self {
+        Self::new(self.x.max(v.x), self.y.max(v.y), self.z.max(v.z))
+    }
+
+    /// Compute barycenter coordinates (u, v, w) for point p with respect to triangle (a, b, c)
+    /// NOTE: Assumes P is on the plane of the triangle
+    pub fn barycenter(p: Self, a: Self, b: Self, c: Self) -> Self {
+        let v0 = Self::new(b.x - a.x, b.y - a.y, b.z - a.z);
+        let v1 = Self::new(c.x - a.x, c.y - a.y, c.z - a.z);
+        let v2 = Self::new(p.x - a.x, p.y - a.y, p.z - a.z);
+        let d00 = v0.dot(v0);
+        let d01 = v0.dot(v1);
+        let d11 = v1.dot(v1);
+        let d20 = v2.dot(v0);
+        let d21 = v2.dot(v1);
+
+        let denom = d00 * d11 - d01 * d01;
+
+        let y = (d11 * d20 - d01 * d21) / denom;
+        let z = (d00 * d21 - d01 * d20) / denom;
+        let x = 1.0 - (z + y);
+        Self::new(x, y, z)
+    }
+
+    /// Projects a Vector3 from screen space into object space
+    /// NOTE: We are avoiding calling other raymath functions despite available
+    pub fn unproject(source: Self, projection: Matrix, view: Matrix) -> Self {
+        // Calculate unprojected matrix (multiply view matrix by projection matrix)
+        // NOTE: Built with the same positional initializer as raymath.h
+        let mat_view_proj = Matrix::from_positional([
+            view.m0 * projection.m0
+                + view.m1 * projection.m4
+                + view.m2 * projection.m8
+                + view.m3 * projection.m12,
+            view.m0 * projection.m1
+                + view.m1 * projection.m5
+                + view.m2 * projection.m9
+                + view.m3 * projection.m13,
+            view.m0 * projection.m2
+                + view.m1 * projection.m6
+                + view.m2 * projection.m10
+                + view.m3 * projection.m14,
+            view.m0 * projection.m3
+                + view.m1 * projection.m7
+                + view.m2 * projection.m11
+                + view.m3 * projection.m15,
+            view.m4 * projection.m0
+                + view.m5 * projection.m4
+                + view.m6 * projection.m8
+                + view.m7 * projection.m12,
+            view.m4 * projection.m1
+                + view.m5 * projection.m5
+                + view.m6 * projection.m9
+                + view.m7 * projection.m13,
+            view.m4 * projection.m2
+                + view.m5 * projection.m6
+                + view.m6 * projection.m10
+                + view.m7 * projection.m14,
+            view.m4 * projection.m3
+                + view.m5 * projection.m7
+                + view.m6 * projection.m11
+                + view.m7 * projection.m15,
+            view.m8 * projection.m0
+                + view.m9 * projection.m4
+                + view.m10 * projection.m8
+                + view.m11 * projection.m12,
+            view.m8 * projection.m1
+                + view.m9 * projection.m5
+                + view.m10 * projection.m9
+                + view.m11 * projection.m13,
+            view.m8 * projection.m2
+                + view.m9 * projection.m6
+                + view.m10 * projection.m10
+                + view.m11 * projection.m14,
+            view.m8 * projection.m3
+                + view.m9 * projection.m7
+                + view.m10 * projection.m11
+                + view.m11 * projection.m15,
+            view.m12 * projection.m0
+                + view.m13 * projection.m4
+                + view.m14 * projection.m8
+                + view.m15 * projection.m12,
+            view.m12 * projection.m1
+                + view.m13 * projection.m5
+                + view.m14 * projection.m9
+                + view.m15 * projection.m13,
+            view.m12 * projection.m2
+                + view.m13 * projection.m6
+                + view.m14 * projection.m10
+                + view.m15 * projection.m14,
+            view.m12 * projection.m3
+                + view.m13 * projection.m7
+                + view.m14 * projection.m11
+                + view.m15 * projection.m15,
+        ]);
+
+        // Calculate inverted matrix -> MatrixInvert(matViewProj);
+        let m = mat_view_proj;
+        let (a00, a01, a02, a03) = (m.m0, m.m1, m.m2, m.m3);
+        let (a10, a11, a12, a13) = (m.m4, m.m5, m.m6, m.m7);
+        let (a20, a21, a22, a23) = (m.m8, m.m9, m.m10, m.m11);
+        let (a30, a31, a32, a33) = (m.m12, m.m13, m.m14, m.m15);
+
+        let b00 = a00 * a11 - a01 * a10;
+        let b01 = a00 * a12 - a02 * a10;
+        let b02 = a00 * a13 - a03 * a10;
+        let b03 = a01 * a12 - a02 * a11;
+        let b04 = a01 * a13 - a03 * a11;
+        let b05 = a02 * a13 - a03 * a12;
+        let b06 = a20 * a31 - a21 * a30;
+        let b07 = a20 * a32 - a22 * a30;
+        let b08 = a20 * a33 - a23 * a30;
+        let b09 = a21 * a32 - a22 * a31;
+        let b10 = a21 * a33 - a23 * a31;
+        let b11 = a22 * a33 - a23 * a32;
+
+        // Calculate the invert determinant (inlined to avoid double-caching)
+        let inv_det = 1.0 / (b00 * b11 - b01 * b10 + b02 * b09 + b03 * b08 - b04 * b07 + b05 * b06);
+
+        let mat_view_proj_inv = Matrix::from_positional([
+            (a11 * b11 - a12 * b10 + a13 * b09) * inv_det,
+            (-a01 * b11 + a02 * b10 - a03 * b09) * inv_det,
+            (a31 * b05 - a32 * b04 + a33 * b03) * inv_det,
+            (-a21 * b05 + a22 * b04 - a23 * b03) * inv_det,
+            (-a10 * b11 + a12 * b08 - a13 * b07) * inv_det,
+            (a00 * b11 - a02 * b08 + a03 * b07) * inv_det,
+            (-a30 * b05 + a32 * b02 - a33 * b01) * inv_det,
+            (a20 * b05 - a22 * b02 + a23 * b01) * inv_det,
+            (a10 * b10 - a11 * b08 + a13 * b06) * inv_det,
+            (-a00 * b10 + a01 * b08 - a03 * b06) * inv_det,
+            (a30 * b04 - a31 * b02 + a33 * b00) * inv_det,
+            (-a20 * b04 + a21 * b02 - a23 * b00) * inv_det,
+            (-a10 * b09 + a11 * b07 - a12 * b06) * inv_det,
+            (a00 * b09 - a01 * b07 + a02 * b06) * inv_det,
+            (-a30 * b03 + a31 * b01 - a32 * b00) * inv_det,
+            (a20 * b03 - a21 * b01 + a22 * b00) * inv_det,
+        ]);
+
+        // Create quaternion from source point
+        let quat = Quaternion::new(source.x, source.y, source.z, 1.0);
+
+        // Multiply quat point by unprojecte matrix
+        let qtransformed = quat.transform(mat_view_proj_inv);
+
+        // Normalized world points in vectors
+        Self::new(
+            qtransformed.x / qtransformed.w,
+            qtransformed.y / qtransformed.w,
+            qtransformed.z / qtransformed.w,
+        )
+    }
+
+    /// Get Vector3 as float array
+    pub fn to_float_v(self) -> [f32; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    /// Invert the given vector
+    pub fn invert(self) -> Self {
+        Self::new(1.0 / self.x, 1.0 / self.y, 1.0 / self.z)
+    }
+
+    /// Clamp the components of the vector between
+    /// min and max values specified by the given vectors
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        Self::new(
+            max.x.min(min.x.max(self.x)),
+            max.y.min(min.y.max(self.y)),
+            max.z.min(min.z.max(self.z)),
+        )
+    }
+
+    /// Clamp the magnitude of the vector between two values
+    pub fn clamp_value(self, min: f32, max: f32) -> Self {
+        let mut result = self;
+        let length = (self.x * self.x) + (self.y * self.y) + (self.z * self.z);
+        if length > 0.0 {
+            let length = length.sqrt();
+            if length < min {
+                let scale = min / length;
+                result.x = self.x * scale;
+                result.y = self.y * scale;
+                result.z = self.z * scale;
+            } else if length > max {
+                let scale = max / length;
+                result.x = self.x * scale;
+                result.y = self.y * scale;
+                result.z = self.z * scale;
+            }
+        }
+        result
+    }
+
+    /// Check whether two given vectors are almost equal
+    pub fn equals(self, q: Self) -> bool {
+        float_equals(self.x, q.x) && float_equals(self.y, q.y) && float_equals(self.z, q.z)
+    }
+
+    /// Compute the direction of a refracted ray
+    /// v: normalized direction of the incoming ray
+    /// n: normalized normal vector of the interface of two optical media
+    /// r: ratio of the refractive index of the medium from where the ray comes
+    ///    to the refractive index of the medium on the other side of the surface
+    pub fn refract(self, n: Self, r: f32) -> Self {
+        let mut result = Self::zero();
+        let v = self;
+        let dot = v.x * n.x + v.y * n.y + v.z * n.z;
+        let mut d = 1.0 - r * r * (1.0 - dot * dot);
+        if d >= 0.0 {
+            d = d.sqrt();
+            result.x = r * v.x - (r * dot + d) * n.x;
+            result.y = r * v.y - (r * dot + d) * n.y;
+            result.z = r * v.z - (r * dot + d) * n.z;
+        }
+        result
+    }
+}
+
+//----------------------------------------------------------------------------------
+// Matrix math
+//----------------------------------------------------------------------------------
+
+impl Matrix {
+    /// Build a matrix the way a C positional initializer does, i.e. values are given row by row
+    const fn from_positional(v: [f32; 16]) -> Self {
+        Self {
+            m0: v[0],
+            m4: v[1],
+            m8: v[2],
+            m12: v[3],
+            m1: v[4],
+            m5: v[5],
+            m9: v[6],
+            m13: v[7],
+            m2: v[8],
+            m6: v[9],
+            m10: v[10],
+            m14: v[11],
+            m3: v[12],
+            m7: v[13],
+            m11: v[14],
+            m15: v[15],
+        }
+    }
+
+    /// Compute matrix determinant
+    pub fn determinant(self) -> f32 {
+        // Cache the matrix values (speed optimization)
+        let (a00, a01, a02, a03) = (self.m0, self.m1, self.m2, self.m3);
+        let (a10, a11, a12, a13) = (self.m4, self.m5, self.m6, self.m7);
+        let (a20, a21, a22, a23) = (self.m8, self.m9, self.m10, self.m11);
+        let (a30, a31, a32, a33) = (self.m12, self.m13, self.m14, self.m15);
+
+        a30 * a21 * a12 * a03 - a20 * a31 * a12 * a03 - a30 * a11 * a22 * a03
+            + a10 * a31 * a22 * a03
+            + a20 * a11 * a32 * a03
+            - a10 * a21 * a32 * a03
+            - a30 * a21 * a02 * a13
+            + a20 * a31 * a02 * a13
+            + a30 * a01 * a22 * a13
+            - a00 * a31 * a22 * a13
+            - a20 * a01 * a32 * a13
+            + a00 * a21 * a32 * a13
+            + a30 * a11 * a02 * a23
+            - a10 * a31 * a02 * a23
+            - a30 * a01 * a12 * a23
+            + a00 * a31 * a12 * a23
+            + a10 * a01 * a32 * a23
+            - a00 * a11 * a32 * a23
+            - a20 * a11 * a02 * a33
+            + a10 * a21 * a02 * a33
+            + a20 * a01 * a12 * a33
+            - a00 * a21 * a12 * a33
+            - a10 * a01 * a22 * a33
+            + a00 * a11 * a22 * a33
+    }
+
+    /// Get the trace of the matrix (sum of the values along the diagonal)
+    pub fn trace(self) -> f32 {
+        self.m0 + self.m5 + self.m10 + self.m15
+    }
+
+    /// Transposes provided matrix
+    pub fn transpose(self) -> Self {
+        Self {
+            m0: self.m0,
+            m1: self.m4,
+            m2: self.m8,
+            m3: self.m12,
+            m4: self.m1,
+            m5: self.m5,
+            m6: self.m9,
+            m7: self.m13,
+            m8: self.m2,
+            m9: self.m6,
+            m10: self.m10,
+            m11: self.m14,
+            m12: self.m3,
+            m13: self.m7,
+            m14: self.m11,
+            m15: self.m15,
+        }
+    }
+
+    /// Invert provided matrix
+    pub fn invert(self) -> Self {
+        // Cache the matrix values (speed optimization)
+        let (a00, a01, a02, a03) = (self.m0, self.m1, self.m2, self.m3);
+        let (a10, a11, a12, a13) = (self.m4, self.m5, self.m6, self.m7);
+        let (a20, a21, a22, a23) = (self.m8, self.m9, self.m10, self.m11);
+        let (a30, a31, a32, a33) = (self.m12, self.m13, self.m14, self.m15);
+
+        let b00 = a00 * a11 - a01 * a10;
+        let b01 = a00 * a12 - a02 * a10;
+        let b02 = a00 * a13 - a03 * a10;
+        let b03 = a01 * a12 - a02 * a11;
+        let b04 = a01 * a13 - a03 * a11;
+        let b05 = a02 * a13 - a03 * a12;
+        let b06 = a20 * a31 - a21 * a30;
+        let b07 = a20 * a32 - a22 * a30;
+        let b08 = a20 * a33 - a23 * a30;
+        let b09 = a21 * a32 - a22 * a31;
+        let b10 = a21 * a33 - a23 * a31;
+        let b11 = a22 * a33 - a23 * a32;
+
+        // Calculate the invert determinant (inlined to avoid double-caching)
+        let inv_det = 1.0 / (b00 * b11 - b01 * b10 + b02 * b09 + b03 * b08 - b04 * b07 + b05 * b06);
+
+        Self {
+            m0: (a11 * b11 - a12 * b10 + a13 * b09) * inv_det,
+            m1: (-a01 * b11 + a02 * b10 - a03 * b09) * inv_det,
+            m2: (a31 * b05 - a32 * b04 + a33 * b03) * inv_det,
+            m3: (-a21 * b05 + a22 * b04 - a23 * b03) * inv_det,
+            m4: (-a10 * b11 + a12 * b08 - a13 * b07) * inv_det,
+            m5: (a00 * b11 - a02 * b08 + a03 * b07) * inv_det,
+            m6: (-a30 * b05 + a32 * b02 - a33 * b01) * inv_det,
+            m7: (a20 * b05 - a22 * b02 + a23 * b01) * inv_det,
+            m8: (a10 * b10 - a11 * b08 + a13 * b06) * inv_det,
+            m9: (-a00 * b10 + a01 * b08 - a03 * b06) * inv_det,
+            m10: (a30 * b04 - a31 * b02 + a33 * b00) * inv_det,
+            m11: (-a20 * b04 + a21 * b02 - a23 * b00) * inv_det,
+            m12: (-a10 * b09 + a11 * b07 - a12 * b06) * inv_det,
+            m13: (a00 * b09 - a01 * b07 + a02 * b06) * inv_det,
+            m14: (-a30 * b03 + a31 * b01 - a32 * b00) * inv_det,
+            m15: (a20 * b03 - a21 * b01 + a22 * b00) * inv_det,
+        }
+    }
+
+    /// Get identity matrix
+    pub const fn identity() -> Self {
+        Self::from_positional([
+            1.0, 0.0, 0.0, 0.0, //
+            0.0, 1.0, 0.0, 0.0, //
+            0.0, 0.0, 1.0, 0.0, //
+            0.0, 0.0, 0.0, 1.0,
+        ])
+    }
+
+    /// Get translation matrix
+    pub const fn translate(x: f32, y: f32, z: f32) -> Self {
+        Self::from_positional([
+            1.0, 0.0, 0.0, x, //
+            0.0, 1.0, 0.0, y, //
+            0.0, 0.0, 1.0, z, //
+            0.0, 0.0, 0.0, 1.0,
+        ])
+    }
+
+    /// Create rotation matrix from axis and angle
+    /// NOTE: Angle should be provided in radians
+    pub fn rotate(axis: Vector3, angle: f32) -> Self {
+        let mut result = Self::default();
+
+        let (mut x, mut y, mut z) = (axis.x, axis.y, axis.z);
+
+        let length_squared = x * x + y * y + z * z;
+
+        if length_squared != 1.0 && length_squared != 0.0 {
+            let ilength = 1.0 / length_squared.sqrt();
+            x *= ilength;
+            y *= ilength;
+            z *= ilength;
+        }
+
+        let sinres = angle.sin();
+        let cosres = angle.cos();
+        let t = 1.0 - cosres;
+
+        result.m0 = x * x * t + cosres;
+        result.m1 = y * x * t + z * sinres;
+        result.m2 = z * x * t - y * sinres;
+        result.m3 = 0.0;
+
+        result.m4 = x * y * t - z * sinres;
+        result.m5 = y * y * t + cosres;
+        result.m6 = z * y * t + x * sinres;
+        result.m7 = 0.0;
+
+        result.m8 = x * z * t + y * sinres;
+        result.m9 = y * z * t - x * sinres;
+        result.m10 = z * z * t + cosres;
+        result.m11 = 0.0;
+
+        result.m12 = 0.0;
+        result.m13 = 0.0;
+        result.m14 = 0.0;
+        result.m15 = 1.0;
+
+        result
+    }
+
+    /// Get x-rotation matrix
+    /// NOTE: Angle must be provided in radians
+    pub fn rotate_x(angle: f32) -> Self {
+        let mut result = Self::identity();
+
+        let cosres = angle.cos();
+        let sinres = angle.sin();
+
+        result.m5 = cosres;
+        result.m6 = sinres;
+        result.m9 = -sinres;
+        result.m10 = cosres;
+
+        result
+    }
+
+    /// Get y-rotation matrix
+    /// NOTE: Angle must be provided in radians
+    pub fn rotate_y(angle: f32) -> Self {
+        let mut result = Self::identity();
+
+        let cosres = angle.cos();
+        let sinres = angle.sin();
+
+        result.m0 = cosres;
+        result.m2 = -sinres;
+        result.m8 = sinres;
+        result.m10 = cosres;
+
+        result
+    }
+
+    /// Get z-rotation matrix
+    /// NOTE: Angle must be provided in radians
+    pub fn rotate_z(angle: f32) -> Self {
+        let mut result = Self::identity();
+
+        let cosres = angle.cos();
+        let sinres = angle.sin();
+
+        result.m0 = cosres;
+        result.m1 = sinres;
+        result.m4 = -sinres;
+        result.m5 = cosres;
+
+        result
+    }
+
+    /// Get xyz-rotation matrix
+    /// NOTE: Angle must be provided in radians
+    pub fn rotate_xyz(angle: Vector3) -> Self {
+        let mut result = Self::identity();
+
+        let cosz = (-angle.z).cos();
+        let sinz = (-angle.z).sin();
+        let cosy = (-angle.y).cos();
+        let siny = (-angle.y).sin();
+        let cosx = (-angle.x).cos();
+        let sinx = (-angle.x).sin();
+
+        result.m0 = cosz * cosy;
+        result.m1 = (cosz * siny * sinx) - (sinz * cosx);
+        result.m2 = (cosz * siny * cosx) + (sinz * sinx);
+
+        result.m4 = sinz * cosy;
+        result.m5 = (sinz * siny * sinx) + (cosz * cosx);
+        result.m6 = (sinz * siny * cosx) - (cosz * sinx);
+
+        result.m8 = -siny;
+        result.m9 = cosy * sinx;
+        result.m10 = cosy * cosx;
+
+        result
+    }
+
+    /// Get zyx-rotation matrix
+    /// NOTE: Angle must be provided in radians
+    pub fn rotate_zyx(angle: Vector3) -> Self {
+        let cz = angle.z.cos();
+        let sz = angle.z.sin();
+        let cy = angle.y.cos();
+        let sy = angle.y.sin();
+        let cx = angle.x.cos();
+        let sx = angle.x.sin();
+
+        Self {
+            m0: cz * cy,
+            m4: cz * sy * sx - cx * sz,
+            m8: sz * sx + cz * cx * sy,
+            m12: 0.0,
+
+            m1: cy * sz,
+            m5: cz * cx + sz * sy * sx,
+            m9: cx * sz * sy - cz * sx,
+            m13: 0.0,
+
+            m2: -sy,
+            m6: cy * sx,
+            m10: cy * cx,
+            m14: 0.0,
+
+            m3: 0.0,
+            m7: 0.0,
+            m11: 0.0,
+            m15: 1.0,
+        }
+    }
+
+    /// Get scaling matrix
+    pub const fn scale(x: f32, y: f32, z: f32) -> Self {
+        Self::from_positional([
+            x, 0.0, 0.0, 0.0, //
+            0.0, y, 0.0, 0.0, //
+            0.0, 0.0, z, 0.0, //
+            0.0, 0.0, 0.0, 1.0,
+        ])
+    }
+
+    /// Get perspective projection matrix
+    pub fn frustum(left: f64, right: f64, bottom: f64, top: f64, near: f64, far: f64) -> Self {
+        let rl = (right - left) as f32;
+        let tb = (top - bottom) as f32;
+        let fn_ = (far - near) as f32;
+
+        Self {
+            m0: (near as f32 * 2.0) / rl,
+            m1: 0.0,
+            m2: 0.0,
+            m3: 0.0,
+
+            m4: 0.0,
+            m5: (near as f32 * 2.0) / tb,
+            m6: 0.0,
+            m7: 0.0,
+
+            m8: (right as f32 + left as f32) / rl,
+            m9: (top as f32 + bottom as f32) / tb,
+            m10: -(far as f32 + near as f32) / fn_,
+            m11: -1.0,
+
+            m12: 0.0,
+            m13: 0.0,
+            m14: -(far as f32 * near as f32 * 2.0) / fn_,
+            m15: 0.0,
+        }
+    }
+
+    /// Get perspective projection matrix
+    /// NOTE: Fovy angle must be provided in radians
+    pub fn perspective(fov_y: f64, aspect: f64, near_plane: f64, far_plane: f64) -> Self {
+        let mut result = Self::default();
+
+        let top = near_plane * (fov_y * 0.5).tan();
+        let bottom = -top;
+        let right = top * aspect;
+        let left = -right;
+
+        // MatrixFrustum(-right, right, -top, top, near, far);
+        let rl = (right - left) as f32;
+        let tb = (top - bottom) as f32;
+        let fn_ = (far_plane - near_plane) as f32;
+
+        result.m0 = (near_plane as f32 * 2.0) / rl;
+        result.m5 = (near_plane as f32 * 2.0) / tb;
+        result.m8 = (right as f32 + left as f32) / rl;
+        result.m9 = (top as f32 + bottom as f32) / tb;
+        result.m10 = -(far_plane as f32 + near_plane as f32) / fn_;
+        result.m11 = -1.0;
+        result.m14 = -(far_plane as f32 * near_plane as f32 * 2.0) / fn_;
+
+        result
+    }
+
+    /// Get orthographic projection matrix
+    pub fn ortho(
+        left: f64,
+        right: f64,
+        bottom: f64,
+        top: f64,
+        near_plane: f64,
+        far_plane: f64,
+    ) -> Self {
+        let rl = (right - left) as f32;
+        let tb = (top - bottom) as f32;
+        let fn_ = (far_plane - near_plane) as f32;
+
+        Self {
+            m0: 2.0 / rl,
+            m1: 0.0,
+            m2: 0.0,
+            m3: 0.0,
+            m4: 0.0,
+            m5: 2.0 / tb,
+            m6: 0.0,
+            m7: 0.0,
+            m8: 0.0,
+            m9: 0.0,
+            m10: -2.0 / fn_,
+            m11: 0.0,
+            m12: -(left as f32 + right as f32) / rl,
+            m13: -(top as f32 + bottom as f32) / tb,
+            m14: -(far_plane as f32 + near_plane as f32) / fn_,
+            m15: 1.0,
+        }
+    }
+
+    /// Get camera look-at matrix (view matrix)
+    pub fn look_at(eye: Vector3, target: Vector3, up: Vector3) -> Self {
+        // Vector3Subtract(eye, target)
+        let mut vz = Vector3::new(eye.x - target.x, eye.y - target.y, eye.z - target.z);
+
+        // Vector3Normalize(vz)
+        let mut length = vz.length();
+        if length == 0.0 {
+            length = 1.0;
+        }
+        let mut ilength = 1.0 / length;
+        vz.x *= ilength;
+        vz.y *= ilength;
+        vz.z *= ilength;
+
+        // Vector3CrossProduct(up, vz)
+        let mut vx = up.cross(vz);
+
+        // Vector3Normalize(x)
+        length = vx.length();
+        if length == 0.0 {
+            length = 1.0;
+        }
+        ilength = 1.0 / length;
+        vx.x *= ilength;
+        vx.y *= ilength;
+        vx.z *= ilength;
+
+        // Vector3CrossProduct(vz, vx)
+        let vy = vz.cross(vx);
+
+        Self {
+            m0: vx.x,
+            m1: vy.x,
+            m2: vz.x,
+            m3: 0.0,
+            m4: vx.y,
+            m5: vy.y,
+            m6: vz.y,
+            m7: 0.0,
+            m8: vx.z,
+            m9: vy.z,
+            m10: vz.z,
+            m11: 0.0,
+            m12: -(vx.x * eye.x + vx.y * eye.y + vx.z * eye.z), // Vector3DotProduct(vx, eye)
+            m13: -(vy.x * eye.x + vy.y * eye.y + vy.z * eye.z), // Vector3DotProduct(vy, eye)
+            m14: -(vz.x * eye.x + vz.y * eye.y + vz.z * eye.z), // Vector3DotProduct(vz, eye)
+            m15: 1.0,
+        }
+    }
+
+    /// Get float array of matrix data
+    pub fn to_float_v(self) -> [f32; 16] {
+        [
+            self.m0, self.m1, self.m2, self.m3, self.m4, self.m5, self.m6, self.m7, self.m8,
+            self.m9, self.m10, self.m11, self.m12, self.m13, self.m14, self.m15,
+        ]
+    }
+}
+
+//----------------------------------------------------------------------------------
+// Quaternion math
+//----------------------------------------------------------------------------------
+
+impl Vector4 {
+    pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Vector with components value 0.0f
+    pub const fn zero() -> Self {
+        Self::new(0.0, 0.0, 0.0, 0.0)
+    }
+
+    /// Vector with components value 1.0f
+    pub const fn one() -> Self {
+        Self::new(1.0, 1.0, 1.0, 1.0)
+    }
+
+    /// Add quaternion and float value
+    pub fn add_value(self, add: f32) -> Self {
+        Self::new(self.x + add, self.y + add, self.z + add, self.w + add)
+    }
+
+    /// Subtract quaternion and float value
+    pub fn sub_value(self, sub: f32) -> Self {
+        Self::new(self.x - sub, self.y - sub, self.z - sub, self.w - sub)
+    }
+
+    /// Get identity quaternion
+    pub const fn identity() -> Self {
+        Self::new(0.0, 0.0, 0.0, 1.0)
+    }
+
+    /// Computes the length of a quaternion
+    pub fn length(self) -> f32 {
+        (self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w).sqrt()
+    }
+
+    /// Normalize provided quaternion
+    pub fn normalize(self) -> Self {
+        let mut length = self.length();
+        if length == 0.0 {
+            length = 1.0;
+        }
+        let ilength = 1.0 / length;
+        Self::new(
+            self.x * ilength,
+            self.y * ilength,
+            self.z * ilength,
+            self.w * ilength,
+        )
+    }
+
+    /// Invert provided quaternion
+    pub fn invert(self) -> Self {
+        let mut result = self;
+
+        let length_sq = self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w;
+
+        if length_sq != 0.0 {
+            let inv_length = 1.0 / length_sq;
+
+            result.x *= -inv_length;
+            result.y *= -inv_length;
+            result.z *= -inv_length;
+            result.w *= inv_length;
+        }
+
+        result
+    }
+
+    /// Scale quaternion by float value
+    pub fn scale(self, mul: f32) -> Self {
+        Self::new(self.x * mul, self.y * mul, self.z * mul, self.w * mul)
+    }
+
+    /// Calculate linear interpolation between two quaternions
+    pub fn lerp(self, q: Self, amount: f32) -> Self {
+        Self::new(
+            self.x + amount * (q.x - self.x),
+            self.y + amount * (q.y - self.y),
+            self.z + amount * (q.z - self.z),
+            self.w + amount * (q.w - self.w),
+        )
+    }
+
+    /// Calculate slerp-optimized interpolation between two quaternions
+    pub fn nlerp(self, q: Self, amount: f32) -> Self {
+        self.lerp(q, amount).normalize()
+    }
+
+    /// Calculates spherical linear interpolation between two quaternions
+    pub fn slerp(self, q: Self, amount: f32) -> Self {
+        let q1 = self;
+        let mut q2 = q;
+
+        let mut cos_half_theta = q1.x * q2.x + q1.y * q2.y + q1.z * q2.z + q1.w * q2.w;
+
+        if cos_half_theta < 0.0 {
+            q2 = -q2;
+            cos_half_theta = -cos_half_theta;
+        }
+
+        if cos_half_theta.abs() >= 1.0 {
+            q1
+        } else if cos_half_theta > 0.95 {
+            q1.nlerp(q2, amount)
+        } else {
+            let half_theta = cos_half_theta.acos();
+            let sin_half_theta = (1.0 - cos_half_theta * cos_half_theta).sqrt();
+
+            if sin_half_theta.abs() < EPSILON {
+                Self::new(
+                    q1.x * 0.5 + q2.x * 0.5,
+                    q1.y * 0.5 + q2.y * 0.5,
+                    q1.z * 0.5 + q2.z * 0.5,
+                    q1.w * 0.5 + q2.w * 0.5,
+                )
+            } else {
+                let ratio_a = ((1.0 - amount) * half_theta).sin() / sin_half_theta;
+                let ratio_b = (amount * half_theta).sin() / sin_half_theta;
+
+                Self::new(
+                    q1.x * ratio_a + q2.x * ratio_b,
+                    q1.y * ratio_a + q2.y * ratio_b,
+                    q1.z * ratio_a + q2.z * ratio_b,
+                    q1.w * ratio_a + q2.w * ratio_b,
+                )
+            }
+        }
+    }
+
+    /// Calculate quaternion based on the rotation from one vector to another
+    pub fn from_vector3_to_vector3(from: Vector3, to: Vector3) -> Self {
+        let cos2_theta = from.dot(to);
+        let cross = from.cross(to);
+
+        // NOTE: Normalize to essentially nlerp the original and identity to 0.5
+        Self::new(cross.x, cross.y, cross.z, 1.0 + cos2_theta).normalize()
+    }
+
+    /// Get a quaternion for a given rotation matrix
+    pub fn from_matrix(mat: Matrix) -> Self {
+        let mut result = Self::zero();
+
+        let four_w_squared_minus1 = mat.m0 + mat.m5 + mat.m10;
+        let four_x_squared_minus1 = mat.m0 - mat.m5 - mat.m10;
+        let four_y_squared_minus1 = mat.m5 - mat.m0 - mat.m10;
+        let four_z_squared_minus1 = mat.m10 - mat.m0 - mat.m5;
+
+        let mut biggest_index = 0;
+        let mut four_biggest_squared_minus1 = four_w_squared_minus1;
+        if four_x_squared_minus1 > four_biggest_squared_minus1 {
+            four_biggest_squared_minus1 = four_x_squared_minus1;
+            biggest_index = 1;
+        }
+
+        if four_y_squared_minus1 > four_biggest_squared_minus1 {
+            four_biggest_squared_minus1 = four_y_squared_minus1;
+            biggest_index = 2;
+        }
+
+        if four_z_squared_minus1 > four_biggest_squared_minus1 {
+            four_biggest_squared_minus1 = four_z_squared_minus1;
+            biggest_index = 3;
+        }
+
+        let biggest_val = (four_biggest_squared_minus1 + 1.0).sqrt() * 0.5;
+        let mult = 0.25 / biggest_val;
+
+        match biggest_index {
+            0 => {
+                result.w = biggest_val;
+                result.x = (mat.m6 - mat.m9) * mult;
+                result.y = (mat.m8 - mat.m2) * mult;
+                result.z = (mat.m1 - mat.m4) * mult;
+            }
+            1 => {
+                result.x = biggest_val;
+                result.w = (mat.m6 - mat.m9) * mult;
+                result.y = (mat.m1 + mat.m4) * mult;
+                result.z = (mat.m8 + mat.m2) * mult;
+            }
+            2 => {
+                result.y = biggest_val;
+                result.w = (mat.m8 - mat.m2) * mult;
+                result.x = (mat.m1 + mat.m4) * mult;
+                result.z = (mat.m6 + mat.m9) * mult;
+            }
+            _ => {
+                result.z = biggest_val;
+                result.w = (mat.m1 - mat.m4) * mult;
+                result.x = (mat.m8 + mat.m2) * mult;
+                result.y = (mat.m6 + mat.m9) * mult;
+            }
+        }
+
+        result
+    }
+
+    /// Get a matrix for a given quaternion
+    pub fn to_matrix(self) -> Matrix {
+        let mut result = Matrix::identity();
+
+        let a2 = self.x * self.x;
+        let b2 = self.y * self.y;
+        let c2 = self.z * self.z;
+        let ac = self.x * self.z;
+        let ab = self.x * self.y;
+        let bc = self.y * self.z;
+        let ad = self.w * self.x;
+        let bd = self.w * self.y;
+        let cd = self.w * self.z;
+
+        result.m0 = 1.0 - 2.0 * (b2 + c2);
+        result.m1 = 2.0 * (ab + cd);
+        result.m2 = 2.0 * (ac - bd);
+
+        result.m4 = 2.0 * (ab - cd);
+        result.m5 = 1.0 - 2.0 * (a2 + c2);
+        result.m6 = 2.0 * (bc + ad);
+
+        result.m8 = 2.0 * (ac + bd);
+        result.m9 = 2.0 * (bc - ad);
+        result.m10 = 1.0 - 2.0 * (a2 + b2);
+
+        result
+    }
+
+    /// Get rotation quaternion for an angle and axis
+    /// NOTE: Angle must be provided in radians
+    pub fn from_axis_angle(axis: Vector3, angle: f32) -> Self {
+        let axis_length = axis.length();
+
+        if axis_length != 0.0 {
+            let angle = angle * 0.5;
+
+            // Vector3Normalize(axis)
+            let mut length = axis.length();
+            if length == 0.0 {
+                length = 1.0;
+            }
+            let ilength = 1.0 / length;
+            let axis = Vector3::new(axis.x * ilength, axis.y * ilength, axis.z * ilength);
+
+            let sinres = angle.sin();
+            let cosres = angle.cos();
+
+            Self::new(axis.x * sinres, axis.y * sinres, axis.z * sinres, cosres).normalize()
+        } else {
+            Self::identity()
+        }
+    }
+
+    /// Get the rotation angle and axis for a given quaternion
+    pub fn to_axis_angle(self) -> (Vector3, f32) {
+        let q = if self.w.abs() > 1.0 {
+            self.normalize()
+        } else {
+            self
+        };
+
+        let mut res_axis = Vector3::zero();
+        let res_angle = 2.0 * q.w.acos();
+        let den = (1.0 - q.w * q.w).sqrt();
+
+        if den > EPSILON {
+            res_axis.x = q.x / den;
+            res_axis.y = q.y / den;
+            res_axis.z = q.z / den;
+        } else {
+            // This occurs when the angle is zero.
+            // Not a problem: just set an arbitrary normalized axis.
+            res_axis.x = 1.0;
+        }
+
+        (res_axis, res_angle)
+    }
+
+    /// Get the quaternion equivalent to Euler angles
+    /// NOTE: Rotation order is ZYX
+    pub fn from_euler(pitch: f32, yaw: f32, roll: f32) -> Self {
+        let x0 = (pitch * 0.5).cos();
+        let x1 = (pitch * 0.5).sin();
+        let y0 = (yaw * 0.5).cos();
+        let y1 = (yaw * 0.5).sin();
+        let z0 = (roll * 0.5).cos();
+        let z1 = (roll * 0.5).sin();
+
+        Self::new(
+            x1 * y0 * z0 - x0 * y1 * z1,
+            x0 * y1 * z0 + x1 * y0 * z1,
+            x0 * y0 * z1 - x1 * y1 * z0,
+            x0 * y0 * z0 + x1 * y1 * z1,
+        )
+    }
+
+    /// Get the Euler angles equivalent to quaternion (roll, pitch, yaw)
+    /// NOTE: Angles are returned in a Vector3 struct in radians
+    pub fn to_euler(self) -> Vector3 {
+        let q = self;
+
+        // Roll (x-axis rotation)
+        let x0 = 2.0 * (q.w * q.x + q.y * q.z);
+        let x1 = 1.0 - 2.0 * (q.x * q.x + q.y * q.y);
+
+        // Pitch (y-axis rotation)
+        let mut y0 = 2.0 * (q.w * q.y - q.z * q.x);
+        y0 = if y0 > 1.0 { 1.0 } else { y0 };
+        y0 = if y0 < -1.0 { -1.0 } else { y0 };
+
+        // Yaw (z-axis rotation)
+        let z0 = 2.0 * (q.w * q.z + q.x * q.y);
+        let z1 = 1.0 - 2.0 * (q.y * q.y + q.z * q.z);
+
+        Vector3::new(x0.atan2(x1), y0.asin(), z0.atan2(z1))
+    }
+
+    /// Transform a quaternion given a transformation matrix
+    pub fn transform(self, mat: Matrix) -> Self {
+        let q = self;
+        Self::new(
+            mat.m0 * q.x + mat.m4 * q.y + mat.m8 * q.z + mat.m12 * q.w,
+            mat.m1 * q.x + mat.m5 * q.y + mat.m9 * q.z + mat.m13 * q.w,
+            mat.m2 * q.x + mat.m6 * q.y + mat.m10 * q.z + mat.m14 * q.w,
+            mat.m3 * q.x + mat.m7 * q.y + mat.m11 * q.z + mat.m15 * q.w,
+        )
+    }
+
+    /// Check whether two given quaternions are almost equal
+    pub fn equals(self, q: Self) -> bool {
+        let p = self;
+        (float_equals(p.x, q.x)
+            && float_equals(p.y, q.y)
+            && float_equals(p.z, q.z)
+            && float_equals(p.w, q.w))
+            || (float_equals(p.x, -q.x)
+                && float_equals(p.y, -q.y)
+                && float_equals(p.z, -q.z)
+                && float_equals(p.w, -q.w))
+    }
+}
+
+//----------------------------------------------------------------------------------
+// Operators
+//----------------------------------------------------------------------------------
+
+macro_rules! impl_vector_ops {
+    ($ty:ident { $($field:ident),+ }) => {
+        impl Add for $ty {
+            type Output = Self;
+
+            fn add(self, v: Self) -> Self {
+                Self { $($field: self.$field + v.$field),+ }
+            }
+        }
+
+        impl Sub for $ty {
+            type Output = Self;
+
+            fn sub(self, v: Self) -> Self {
+                Self { $($field: self.$field - v.$field),+ }
+            }
+        }
+
+        impl Mul<f32> for $ty {
+            type Output = Self;
+
+            fn mul(self, scale: f32) -> Self {
+                Self { $($field: self.$field * scale),+ }
+            }
+        }
+
+        impl Mul<$ty> for f32 {
+            type Output = $ty;
+
+            fn mul(self, v: $ty) -> $ty {
+                v * self
+            }
+        }
+
+        impl Div<f32> for $ty {
+            type Output = Self;
+
+            fn div(self, div: f32) -> Self {
+                Self { $($field: self.$field / div),+ }
+            }
+        }
+
+        impl Div for $ty {
+            type Output = Self;
+
+            fn div(self, v: Self) -> Self {
+                Self { $($field: self.$field / v.$field),+ }
+            }
+        }
+
+        impl Neg for $ty {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),+ }
+            }
+        }
+
+        impl AddAssign for $ty {
+            fn add_assign(&mut self, v: Self) {
+                *self = *self + v;
+            }
+        }
+
+        impl SubAssign for $ty {
+            fn sub_assign(&mut self, v: Self) {
+                *self = *self - v;
+            }
+        }
+
+        impl MulAssign<f32> for $ty {
+            fn mul_assign(&mut self, scale: f32) {
+                *self = *self * scale;
+            }
+        }
+
+        impl DivAssign<f32> for $ty {
+            fn div_assign(&mut self, div: f32) {
+                *self = *self / div;
+            }
+        }
+    };
+}
+
+impl_vector_ops!(Vector2 { x, y });
+impl_vector_ops!(Vector3 { x, y, z });
+impl_vector_ops!(Vector4 { x, y, z, w });
+
+/// Multiply vector by vector, component-wise
+impl Mul for Vector2 {
+    type Output = Self;
+
+    fn mul(self, v: Self) -> Self {
+        Self::new(self.x * v.x, self.y * v.y)
+    }
+}
+
+/// Multiply vector by vector, component-wise
+impl Mul for Vector3 {
+    type Output = Self;
+
+    fn mul(self, v: Self) -> Self {
+        Self::new(self.x * v.x, self.y * v.y, self.z * v.z)
+    }
+}
+
+/// Calculate two quaternion multiplication
+impl Mul for Quaternion {
+    type Output = Self;
+
+    fn mul(self, q2: Self) -> Self {
+        let (qax, qay, qaz, qaw) = (self.x, self.y, self.z, self.w);
+        let (qbx, qby, qbz, qbw) = (q2.x, q2.y, q2.z, q2.w);
+
+        Self::new(
+            qax * qbw + qaw * qbx + qay * qbz - qaz * qby,
+            qay * qbw + qaw * qby + qaz * qbx - qax * qbz,
+            qaz * qbw + qaw * qbz + qax * qby - qay * qbx,
+            qaw * qbw - qax * qbx - qay * qby - qaz * qbz,
+        )
+    }
+}
+
+impl MulAssign for Quaternion {
+    fn mul_assign(&mut self, q: Self) {
+        *self = *self * q;
+    }
+}
+
+/// Add two matrices
+impl Add for Matrix {
+    type Output = Self;
+
+    fn add(self, right: Self) -> Self {
+        let left = self;
+        Self {
+            m0: left.m0 + right.m0,
+            m1: left.m1 + right.m1,
+            m2: left.m2 + right.m2,
+            m3: left.m3 + right.m3,
+            m4: left.m4 + right.m4,
+            m5: left.m5 + right.m5,
+            m6: left.m6 + right.m6,
+            m7: left.m7 + right.m7,
+            m8: left.m8 + right.m8,
+            m9: left.m9 + right.m9,
+            m10: left.m10 + right.m10,
+            m11: left.m11 + right.m11,
+            m12: left.m12 + right.m12,
+            m13: left.m13 + right.m13,
+            m14: left.m14 + right.m14,
+            m15: left.m15 + right.m15,
+        }
+    }
+}
+
+/// Subtract two matrices (left - right)
+impl Sub for Matrix {
+    type Output = Self;
+
+    fn sub(self, right: Self) -> Self {
+        let left = self;
+        Self {
+            m0: left.m0 - right.m0,
+            m1: left.m1 - right.m1,
+            m2: left.m2 - right.m2,
+            m3: left.m3 - right.m3,
+            m4: left.m4 - right.m4,
+            m5: left.m5 - right.m5,
+            m6: left.m6 - right.m6,
+            m7: left.m7 - right.m7,
+            m8: left.m8 - right.m8,
+            m9: left.m9 - right.m9,
+            m10: left.m10 - right.m10,
+            m11: left.m11 - right.m11,
+            m12: left.m12 - right.m12,
+            m13: left.m13 - right.m13,
+            m14: left.m14 - right.m14,
+            m15: left.m15 - right.m15,
+        }
+    }
+}
+
+/// Get two matrix multiplication
+/// NOTE: When multiplying matrices... the order matters!
+impl Mul for Matrix {
+    type Output = Self;
+
+    fn mul(self, right: Self) -> Self {
+        let left = self;
+        Self {
+            m0: left.m0 * right.m0 + left.m1 * right.m4 + left.m2 * right.m8 + left.m3 * right.m12,
+            m1: left.m0 * right.m1 + left.m1 * right.m5 + left.m2 * right.m9 + left.m3 * right.m13,
+            m2: left.m0 * right.m2 + left.m1 * right.m6 + left.m2 * right.m10 + left.m3 * right.m14,
+            m3: left.m0 * right.m3 + left.m1 * right.m7 + left.m2 * right.m11 + left.m3 * right.m15,
+            m4: left.m4 * right.m0 + left.m5 * right.m4 + left.m6 * right.m8 + left.m7 * right.m12,
+            m5: left.m4 * right.m1 + left.m5 * right.m5 + left.m6 * right.m9 + left.m7 * right.m13,
+            m6: left.m4 * right.m2 + left.m5 * right.m6 + left.m6 * right.m10 + left.m7 * right.m14,
+            m7: left.m4 * right.m3 + left.m5 * right.m7 + left.m6 * right.m11 + left.m7 * right.m15,
+            m8: left.m8 * right.m0
+                + left.m9 * right.m4
+                + left.m10 * right.m8
+                + left.m11 * right.m12,
+            m9: left.m8 * right.m1
+                + left.m9 * right.m5
+                + left.m10 * right.m9
+                + left.m11 * right.m13,
+            m10: left.m8 * right.m2
+                + left.m9 * right.m6
+                + left.m10 * right.m10
+                + left.m11 * right.m14,
+            m11: left.m8 * right.m3
+                + left.m9 * right.m7
+                + left.m10 * right.m11
+                + left.m11 * right.m15,
+            m12: left.m12 * right.m0
+                + left.m13 * right.m4
+                + left.m14 * right.m8
+                + left.m15 * right.m12,
+            m13: left.m12 * right.m1
+                + left.m13 * right.m5
+                + left.m14 * right.m9
+                + left.m15 * right.m13,
+            m14: left.m12 * right.m2
+                + left.m13 * right.m6
+                + left.m14 * right.m10
+                + left.m15 * right.m14,
+            m15: left.m12 * right.m3
+                + left.m13 * right.m7
+                + left.m14 * right.m11
+                + left.m15 * right.m15,
+        }
+    }
+}
+
+impl AddAssign for Matrix {
+    fn add_assign(&mut self, m: Self) {
+        *self = *self + m;
+    }
+}
+
+impl SubAssign for Matrix {
+    fn sub_assign(&mut self, m: Self) {
+        *self = *self - m;
+    }
+}
+
+impl MulAssign for Matrix {
+    fn mul_assign(&mut self, m: Self) {
+        *self = *self * m;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Expected values are the results of the raymath.h functions with the same arguments,
+    // compared exactly where the Rust port does the same float operations in the same order
+
+    fn assert_near(actual: &[f32], expected: &[f32]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!(
+                (a - e).abs() <= 1e-5 * 1.0f32.max(e.abs()),
+                "{:?} != {:?}",
+                actual,
+                expected
+            );
+        }
+    }
+
+    /// Matrix values in row order (m0, m4, m8, m12, m1...)
+    fn rows(m: Matrix) -> [f32; 16] {
+        [
+            m.m0, m.m4, m.m8, m.m12, m.m1, m.m5, m.m9, m.m13, m.m2, m.m6, m.m10, m.m14, m.m3, m.m7,
+            m.m11, m.m15,
+        ]
+    }
+
+    #[test]
+    fn matrix_perspective() {
+        let m = Matrix::perspective(45f64.to_radians(), 16.0 / 9.0, 0.1, 100.0);
+        #[rustfmt::skip]
+        assert_near(&rows(m), &[
+            1.357_995_3, 0.0, 0.0, 0.0,
+            0.0, 2.414_213_4, 0.0, 0.0,
+            0.0, 0.0, -1.002_002, -0.200_200_2,
+            0.0, 0.0, -1.0, 0.0,
+        ]);
+    }
+
+    #[test]
+    fn matrix_look_at() {
+        let m = Matrix::look_at(
+            Vector3::new(10.0, 10.0, 10.0),
+            Vector3::zero(),
+            Vector3::new(0.0, 1.0, 0.0),
+        );
+        #[rustfmt::skip]
+        assert_near(&rows(m), &[
+            0.707_106_77, 0.0, -0.707_106_77, 0.0,
+            -0.408_248_25, 0.816_496_5, -0.408_248_25, 0.0,
+            0.577_350_2, 0.577_350_2, 0.577_350_2, -17.320_505,
+            0.0, 0.0, 0.0, 1.0,
+        ]);
+    }
+
+    #[test]
+    fn matrix_invert() {
+        let mut m =
+            Matrix::rotate_xyz(Vector3::new(0.3, -0.5, 1.2)) * Matrix::translate(1.0, 2.0, 3.0);
+        m.m0 *= 2.0;
+        #[rustfmt::skip]
+        assert_near(&rows(m.invert()), &[
+            0.288_794_97, 0.762_014_8, 0.400_864_18, -3.015_417_3,
+            -0.742_824_5, 0.676_428_14, -0.215_535_22, 0.036_573_753,
+            -0.435_396_85, -0.143_169_1, 0.899_501_1, -1.976_768_3,
+            0.0, 0.0, 0.0, 1.0,
+        ]);
+        assert_near(&rows(m * m.invert()), &rows(Matrix::identity()));
+    }
+
+    #[test]
+    fn quaternion_slerp() {
+        let up = Vector3::new(0.0, 1.0, 0.0);
+        let a = Quaternion::from_axis_angle(up, 0.0);
+        let b = Quaternion::from_axis_angle(up, std::f32::consts::FRAC_PI_2);
+        let q = a.slerp(b, 0.5);
+        assert_near(
+            &[q.x, q.y, q.z, q.w],
+            &[0.0, 0.382_683_52, 0.0, 0.923_879_7],
+        );
+        let q = a.slerp(b, 0.0);
+        assert_near(&[q.x, q.y, q.z, q.w], &[a.x, a.y, a.z, a.w]);
+    }
+
+    #[test]
+    fn quaternion_euler() {
+        let q = Quaternion::from_euler(0.1, 0.2, 0.3);
+        assert_near(
+            &[q.x, q.y, q.z, q.w],
+            &[0.034_270_793, 0.106_020_52, 0.143_572_18, 0.983_347_5],
+        );
+        let euler = q.to_euler();
+        assert_near(&[euler.x, euler.y, euler.z], &[0.1, 0.2, 0.3]);
+    }
+
+    #[test]
+    fn vector2_angle() {
+        let angle = Vector2::new(1.0, 0.0).angle(Vector2::new(0.0, 1.0));
+        assert_near(&[angle], &[std::f32::consts::FRAC_PI_2]);
+        let angle = Vector2::new(1.0, 1.0).angle(Vector2::new(-1.0, 0.0));
+        assert_near(&[angle], &[2.356_194_5]);
+    }
+
+    #[test]
+    fn ffi_conversions_round_trip() {
+        let camera = Camera3D {
+            position: Vector3::new(1.0, 2.0, 3.0),
+            target: Vector3::new(4.0, 5.0, 6.0),
+            up: Vector3::new(0.0, 1.0, 0.0),
+            fovy: 45.0,
+            projection: 1,
+        };
+        let raw: raylib_ffi::Camera3D = camera.into();
+        assert_eq!(raw.target.y, 5.0);
+        assert_eq!(Camera3D::from(raw), camera);
+
+        let m = Matrix::translate(1.0, 2.0, 3.0);
+        assert_eq!(Matrix::from(raylib_ffi::Matrix::from(m)), m);
+    }
+
+    #[test]
+    fn vector2_operators() {
+        let a = Vector2::new(1.5, -2.25);
+        let b = Vector2::new(0.3, 0.7);
+        assert_eq!(a + b, Vector2::new(1.8, -1.55));
+        assert_eq!(a - b, Vector2::new(1.2, -2.95));
+        assert_eq!(a * 0.7, Vector2::new(1.05, -1.574_999_9));
+        assert_eq!(0.7 * a, a.scale(0.7));
+        assert_eq!(a * b, Vector2::new(0.450_000_02, -1.574_999_9));
+        assert_eq!(a / b, Vector2::new(5.0, -3.214_285_9));
+        assert_eq!(-a, Vector2::new(-1.5, 2.25));
+        let mut c = a;
+        c += b;
+        c -= b * 2.0;
+        c *= 4.0;
+        c /= 2.0;
+        assert_eq!(c, (a + b - b * 2.0) * 4.0 / 2.0);
+    }
+
+    #[test]
+    fn vector2_functions() {
+        let a = Vector2::new(1.5, -2.25);
+        let b = Vector2::new(0.3, 0.7);
+        assert_eq!(a.dot(b), -1.124_999_9);
+        assert_eq!(a.length(), 2.704_163_6);
+        assert_eq!(a.distance(b), 3.184_729);
+        assert_eq!(a.normalize(), Vector2::new(0.554_700_2, -0.832_050_26));
+        assert_eq!(Vector2::zero().normalize(), Vector2::zero());
+        assert_eq!(a.lerp(b, 0.3), Vector2::new(1.14, -1.365));
+        assert_eq!(
+            a.reflect(b.normalize()),
+            Vector2::new(2.663_793, 0.465_517_28)
+        );
+        assert_eq!(a.rotate(0.6), Vector2::new(2.508_449, -1.010_041_4));
+        assert_eq!(
+            a.transform(Matrix::rotate_z(0.6)),
+            Vector2::new(2.508_449, -1.010_041_4)
+        );
+    }
+
+    #[test]
+    fn vector3_operators() {
+        let a = Vector3::new(1.0, 2.0, 3.0);
+        let b = Vector3::new(-0.4, 0.9, 2.5);
+        assert_eq!(a + b, Vector3::new(0.6, 2.9, 5.5));
+        assert_eq!(a - b, Vector3::new(1.4, 1.1, 0.5));
+        assert_eq!(a * 0.7, Vector3::new(0.7, 1.4, 2.1));
+        assert_eq!(a * b, Vector3::new(-0.4, 1.8, 7.5));
+        assert_eq!(a / b, Vector3::new(-2.5, 2.222_222_3, 1.2));
+        assert_eq!(-a, Vector3::new(-1.0, -2.0, -3.0));
+    }
+
+    #[test]
+    fn vector3_functions() {
+        let a = Vector3::new(1.0, 2.0, 3.0);
+        let b = Vector3::new(-0.4, 0.9, 2.5);
+        assert_eq!(a.dot(b), 8.9);
+        assert_eq!(a.cross(b), Vector3::new(2.300_000_2, -3.7, 1.7));
+        assert_eq!(a.length(), 3.741_657_5);
+        assert_eq!(a.distance(b), 1.849_324_2);
+        assert_eq!(
+            a.normalize(),
+            Vector3::new(0.267_261_24, 0.534_522_5, 0.801_783_7)
+        );
+        assert_eq!(a.lerp(b, 0.3), Vector3::new(0.58, 1.67, 2.85));
+        assert_eq!(
+            a.reflect(b.normalize()),
+            Vector3::new(1.986_149_8, -0.218_836_78, -3.163_435_5)
+        );
+        assert_eq!(
+            a.rotate_by_axis_angle(b, 0.8),
+            Vector3::new(-0.066_875_905, 2.717_69, 2.570_931_4)
+        );
+        assert_eq!(
+            a.rotate_by_quaternion(Quaternion::from_axis_angle(b, 0.8)),
+            Vector3::new(-0.066_875_935, 2.717_69, 2.570_931_4)
+        );
+        let transform = Matrix::rotate_y(0.4) * Matrix::translate(1.0, -2.0, 0.5);
+        assert_eq!(
+            a.transform(transform),
+            Vector3::new(3.089_316, 0.0, 2.873_764_5)
+        );
+    }
+
+    #[test]
+    fn vector4_and_quaternion() {
+        let a = Vector4::new(0.1, 0.2, 0.3, 0.9);
+        let b = Vector4::new(1.0, -1.0, 0.5, 2.0);
+        assert_eq!(a + b, Vector4::new(1.1, -0.8, 0.8, 2.9));
+        assert_eq!(a.scale(0.7), Vector4::new(0.07, 0.14, 0.210_000_01, 0.63));
+        assert_eq!(
+            a.normalize(),
+            Vector4::new(0.102_597_83, 0.205_195_67, 0.307_793_5, 0.923_380_5)
+        );
+        assert_eq!(
+            a.normalize() * b.normalize(),
+            Vector4::new(0.615_587, -0.102_597_82, 0.307_793_47, 0.718_184_8)
+        );
+        assert_eq!(
+            a.lerp(b, 0.3),
+            Vector4::new(0.37, -0.160_000_01, 0.36, 1.23)
+        );
+    }
+
+    #[test]
+    fn matrix_transforms() {
+        #[rustfmt::skip]
+        assert_eq!(rows(Matrix::translate(1.0, -2.0, 0.5)), [
+            1.0, 0.0, 0.0, 1.0,
+            0.0, 1.0, 0.0, -2.0,
+            0.0, 0.0, 1.0, 0.5,
+            0.0, 0.0, 0.0, 1.0,
+        ]);
+        #[rustfmt::skip]
+        assert_eq!(rows(Matrix::scale(2.0, 0.5, -1.0)), [
+            2.0, 0.0, 0.0, 0.0,
+            0.0, 0.5, 0.0, 0.0,
+            0.0, 0.0, -1.0, 0.0,
+            0.0, 0.0, 0.0, 1.0,
+        ]);
+        #[rustfmt::skip]
+        assert_eq!(rows(Matrix::rotate(Vector3::new(-0.4, 0.9, 2.5), 0.8)), [
+            0.703_427_9, -0.682_553_4, 0.198_267_68, 0.0,
+            0.652_308_1, 0.730_732_7, 0.201_305_54, 0.0,
+            -0.282_282_47, -0.012_272_313, 0.959_252_83, 0.0,
+            0.0, 0.0, 0.0, 1.0,
+        ]);
+        #[rustfmt::skip]
+        assert_eq!(rows(Matrix::rotate_x(0.4)), [
+            1.0, 0.0, 0.0, 0.0,
+            0.0, 0.921_061, -0.389_418_33, 0.0,
+            0.0, 0.389_418_33, 0.921_061, 0.0,
+            0.0, 0.0, 0.0, 1.0,
+        ]);
+        #[rustfmt::skip]
+        assert_eq!(rows(Matrix::rotate_y(0.4)), [
+            0.921_061, 0.0, 0.389_418_33, 0.0,
+            0.0, 1.0, 0.0, 0.0,
+            -0.389_418_33, 0.0, 0.921_061, 0.0,
+            0.0, 0.0, 0.0, 1.0,
+        ]);
+        #[rustfmt::skip]
+        assert_eq!(rows(Matrix::rotate_z(0.4)), [
+            0.921_061, -0.389_418_33, 0.0, 0.0,
+            0.389_418_33, 0.921_061, 0.0, 0.0,
+            0.0, 0.0, 1.0, 0.0,
+            0.0, 0.0, 0.0, 1.0,
+        ]);
+        #[rustfmt::skip]
+        assert_eq!(rows(Matrix::ortho(-4.0, 6.0, -2.5, 3.5, 0.1, 100.0)), [
+            0.2, 0.0, 0.0, -0.2,
+            0.0, 0.333_333_34, 0.0, -0.166_666_67,
+            0.0, 0.0, -0.020_020_02, -1.002_002,
+            0.0, 0.0, 0.0, 1.0,
+        ]);
+    }
+
+    #[test]
+    fn matrix_operators() {
+        let rotate = Matrix::rotate(Vector3::new(-0.4, 0.9, 2.5), 0.8);
+        #[rustfmt::skip]
+        assert_eq!(rows(rotate * Matrix::translate(1.0, -2.0, 0.5)), [
+            0.703_427_9, -0.682_553_4, 0.198_267_68, 1.0,
+            0.652_308_1, 0.730_732_7, 0.201_305_54, -2.0,
+            -0.282_282_47, -0.012_272_313, 0.959_252_83, 0.5,
+            0.0, 0.0, 0.0, 1.0,
+        ]);
+        let (a, b) = (Matrix::rotate_x(0.4), Matrix::scale(2.0, 0.5, -1.0));
+        #[rustfmt::skip]
+        assert_eq!(rows(a + b), [
+            3.0, 0.0, 0.0, 0.0,
+            0.0, 1.421_061, -0.389_418_33, 0.0,
+            0.0, 0.389_418_33, -0.078_939_02, 0.0,
+            0.0, 0.0, 0.0, 2.0,
+        ]);
+        #[rustfmt::skip]
+        assert_eq!(rows(a - b), [
+            -1.0, 0.0, 0.0, 0.0,
+            0.0, 0.421_060_98, -0.389_418_33, 0.0,
+            0.0, 0.389_418_33, 1.921_061, 0.0,
+            0.0, 0.0, 0.0, 0.0,
+        ]);
+        let mut m = a;
+        m *= b;
+        assert_eq!(m, a * b);
+        assert_eq!(rotate * Matrix::identity(), rotate);
+    }
+}
@@ -1,269 +1,631 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::ffi::CString;
-
-use crate::enums::MouseButton;
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+
+use crate::automation::{AutomationEvent, AutomationEvents};
+use crate::enums::{
+    BlendMode, KeyboardKey, MouseButton, MouseCursor, ShaderUniformDataType, TraceLogLevel,
+};
+use crate::error::{c_string, check_file};
 use crate::Color;
-use crate::Shader;
-use crate::Texture2D;
+use crate::Image;
 use crate::{Camera, Camera2D, Camera3D};
+use crate::{Error, LoadError};
 use crate::{Matrix, Ray};
-use crate::{Vector2, Vector3};
+use crate::{RenderTexture, Texture};
+use crate::{Vector2, Vector3, Vector4};
 use crate::{VrDeviceInfo, VrStereoConfig};
-// Window-related functions
-/// Initialize window and OpenGL context
-pub fn init_window(width: i32, height: i32, title: &str) {
-    unsafe {
-        raylib_ffi::InitWindow(width, height, raylib_ffi::rl_str!(title));
+
+/// Set while a `RaylibHandle` is alive, raylib only supports a single window
+static WINDOW_INITIALIZED: AtomicBool = AtomicBool::new(false);
+
+/// Owned handle to the raylib window and OpenGL context, the window is closed on drop
+///
+/// Only one handle can exist at a time. It is required (by reference) to call
+/// the rcore functions that need an open window.
+#[derive(Debug)]
+pub struct RaylibHandle {
+    // raylib must be driven from the thread that created the window
+    _marker: PhantomData<*const ()>,
+}
+
+impl Drop for RaylibHandle {
+    fn drop(&mut self) {
+        unsafe {
+            raylib_ffi::CloseWindow();
+        }
+        WINDOW_INITIALIZED.store(false, Ordering::SeqCst);
     }
 }
 
-/// Close window and unload OpenGL context
-pub fn close_window() {
-    unsafe {
-        raylib_ffi::CloseWindow();
+bitflags::bitflags! {
+    /// System/Window config flags
+    ///
+    /// By default all flags are set to 0
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ConfigFlags: u32 {
+        /// Set to try enabling V-Sync on GPU
+        const VSYNC_HINT = 0x00000040;
+        /// Set to run program in fullscreen
+        const FULLSCREEN_MODE = 0x00000002;
+        /// Set to allow resizable window
+        const WINDOW_RESIZABLE = 0x00000004;
+        /// Set to disable window decoration (frame and buttons)
+        const WINDOW_UNDECORATED = 0x00000008;
+        /// Set to hide window
+        const WINDOW_HIDDEN = 0x00000080;
+        /// Set to minimize window (iconify)
+        const WINDOW_MINIMIZED = 0x00000200;
+        /// Set to maximize window (expanded to monitor)
+        const WINDOW_MAXIMIZED = 0x00000400;
+        /// Set to window non focused
+        const WINDOW_UNFOCUSED = 0x00000800;
+        /// Set to window always on top
+        const WINDOW_TOPMOST = 0x00001000;
+        /// Set to allow windows running while minimized
+        const WINDOW_ALWAYS_RUN = 0x00000100;
+        /// Set to allow transparent framebuffer
+        const WINDOW_TRANSPARENT = 0x00000010;
+        /// Set to support HighDPI
+        const WINDOW_HIGHDPI = 0x00002000;
+        /// Set to support mouse passthrough, only supported when WINDOW_UNDECORATED
+        const WINDOW_MOUSE_PASSTHROUGH = 0x00004000;
+        /// Set to run program in borderless windowed mode
+        const BORDERLESS_WINDOWED_MODE = 0x00008000;
+        /// Set to try enabling MSAA 4X
+        const MSAA_4X_HINT = 0x00000020;
+        /// Set to try enabling interlaced video format (for V3D)
+        const INTERLACED_HINT = 0x00010000;
+    }
+}
+
+/// Builder for the window and OpenGL context, wraps SetConfigFlags() and InitWindow()
+///
+/// Config flags are applied before InitWindow(), the other window settings right after it.
+#[derive(Debug, Clone)]
+pub struct WindowBuilder {
+    width: i32,
+    height: i32,
+    title: String,
+    flags: ConfigFlags,
+    min_size: Option<(i32, i32)>,
+    max_size: Option<(i32, i32)>,
+    monitor: Option<i32>,
+    icons: Vec<Image>,
+    target_fps: Option<i32>,
+    exit_key: Option<KeyboardKey>,
+}
+
+impl Default for WindowBuilder {
+    fn default() -> Self {
+        Self {
+            width: 800,
+            height: 450,
+            title: String::from("raylib"),
+            flags: ConfigFlags::empty(),
+            min_size: None,
+            max_size: None,
+            monitor: None,
+            icons: Vec::new(),
+            target_fps: None,
+            exit_key: None,
+        }
     }
 }
 
+impl WindowBuilder {
+    /// Create a builder for a 800x450 window titled "raylib"
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the window size
+    pub fn size(mut self, width: i32, height: i32) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    /// Set the window title
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = title.to_string();
+        self
+    }
+
+    /// Set init configuration flags (view FLAGS), applied before the window is created
+    ///
+    /// Adds to the flags set so far, including the ones set by the flag options below.
+    pub fn config_flags(mut self, flags: ConfigFlags) -> Self {
+        self.flags |= flags;
+        self
+    }
+
+    /// Allow the window to be resized
+    pub fn resizable(mut self, enabled: bool) -> Self {
+        self.flags.set(ConfigFlags::WINDOW_RESIZABLE, enabled);
+        self
+    }
+
+    /// Try enabling V-Sync on GPU
+    pub fn vsync(mut self, enabled: bool) -> Self {
+        self.flags.set(ConfigFlags::VSYNC_HINT, enabled);
+        self
+    }
+
+    /// Try enabling MSAA 4X
+    pub fn msaa_4x(mut self, enabled: bool) -> Self {
+        self.flags.set(ConfigFlags::MSAA_4X_HINT, enabled);
+        self
+    }
+
+    /// Support HighDPI
+    pub fn highdpi(mut self, enabled: bool) -> Self {
+        self.flags.set(ConfigFlags::WINDOW_HIGHDPI, enabled);
+        self
+    }
+
+    /// Allow a transparent framebuffer
+    pub fn transparent(mut self, enabled: bool) -> Self {
+        self.flags.set(ConfigFlags::WINDOW_TRANSPARENT, enabled);
+        self
+    }
+
+    /// Disable the window decoration (frame and buttons)
+    pub fn undecorated(mut self, enabled: bool) -> Self {
+        self.flags.set(ConfigFlags::WINDOW_UNDECORATED, enabled);
+        self
+    }
+
+    /// Keep running while the window is minimized
+    pub fn always_run(mut self, enabled: bool) -> Self {
+        self.flags.set(ConfigFlags::WINDOW_ALWAYS_RUN, enabled);
+        self
+    }
+
+    /// Set the window minimum dimensions (for FLAG_WINDOW_RESIZABLE)
+    pub fn min_size(mut self, width: i32, height: i32) -> Self {
+        self.min_size = Some((width, height));
+        self
+    }
+
+    /// Set the window maximum dimensions (for FLAG_WINDOW_RESIZABLE)
+    pub fn max_size(mut self, width: i32, height: i32) -> Self {
+        self.max_size = Some((width, height));
+        self
+    }
+
+    /// Start on the specified monitor, centred on it
+    pub fn monitor(mut self, monitor: i32) -> Self {
+        self.monitor = Some(monitor);
+        self
+    }
+
+    /// Set the window icon (RGBA 32bit, only PLATFORM_DESKTOP)
+    pub fn icon(mut self, image: Image) -> Self {
+        self.icons = vec![image];
+        self
+    }
+
+    /// Set the window icon from several sizes (RGBA 32bit, only PLATFORM_DESKTOP)
+    pub fn icons(mut self, images: Vec<Image>) -> Self {
+        self.icons = images;
+        self
+    }
+
+    /// Set target FPS (maximum)
+    pub fn target_fps(mut self, fps: i32) -> Self {
+        self.target_fps = Some(fps);
+        self
+    }
+
+    /// Set a custom key to exit program (default is ESC)
+    pub fn exit_key(mut self, key: KeyboardKey) -> Self {
+        self.exit_key = Some(key);
+        self
+    }
+
+    /// Initialize window and OpenGL context
+    ///
+    /// Fails with `Error::MonitorNotFound` (and closes the window) if the starting
+    /// monitor is not connected.
+    pub fn build(self) -> crate::Result<RaylibHandle> {
+        if WINDOW_INITIALIZED.swap(true, Ordering::SeqCst) {
+            return Err(crate::Error::AlreadyInitialized);
+        }
+        #[cfg(feature = "log")]
+        install_log_forwarding();
+        unsafe {
+            raylib_ffi::SetConfigFlags(self.flags.bits());
+            raylib_ffi::InitWindow(self.width, self.height, raylib_ffi::rl_str!(self.title));
+            if !raylib_ffi::IsWindowReady() {
+                WINDOW_INITIALIZED.store(false, Ordering::SeqCst);
+                return Err(crate::Error::WindowInitFailed);
+            }
+        }
+        let rl = RaylibHandle {
+            _marker: PhantomData,
+        };
+
+        if let Some((width, height)) = self.min_size {
+            set_window_min_size(&rl, width, height);
+        }
+        if let Some((width, height)) = self.max_size {
+            set_window_max_size(&rl, width, height);
+        }
+        if let Some(monitor) = self.monitor {
+            move_window_to_monitor(&rl, monitor)?;
+        }
+        if !self.icons.is_empty() {
+            set_window_icons(&rl, &self.icons);
+        }
+        if let Some(fps) = self.target_fps {
+            set_target_fps(&rl, fps);
+        }
+        if let Some(key) = self.exit_key {
+            set_exit_key(&rl, key);
+        }
+        Ok(rl)
+    }
+}
+
+/// Check if a `RaylibHandle` is alive, GPU resources can only be unloaded while it is
+pub(crate) fn is_window_open() -> bool {
+    WINDOW_INITIALIZED.load(Ordering::SeqCst)
+}
+
+/// Create a window builder, shorthand for `WindowBuilder::new()`
+pub fn init() -> WindowBuilder {
+    WindowBuilder::new()
+}
+
+// Window-related functions
 /// Check if application should close (KEY_ESCAPE pressed or windows close icon clicked)
-pub fn window_should_close() -> bool {
+pub fn window_should_close(_rl: &RaylibHandle) -> bool {
     unsafe { raylib_ffi::WindowShouldClose() }
 }
 
 /// Check if window has been initialized successfully
-pub fn is_window_ready() -> bool {
+pub fn is_window_ready(_rl: &RaylibHandle) -> bool {
     unsafe { raylib_ffi::IsWindowReady() }
 }
 
 /// Check if window is currently fullscreen
-pub fn is_window_fullscreen() -> bool {
+pub fn is_window_fullscreen(_rl: &RaylibHandle) -> bool {
     unsafe { raylib_ffi::IsWindowFullscreen() }
 }
 
 /// Check if window is currently hidden (only PLATFORM_DESKTOP)
-pub fn is_window_hidden() -> bool {
+pub fn is_window_hidden(_rl: &RaylibHandle) -> bool {
     unsafe { raylib_ffi::IsWindowHidden() }
 }
 
 /// Check if window is currently minimized (only PLATFORM_DESKTOP)
-pub fn is_window_minimized() -> bool {
+pub fn is_window_minimized(_rl: &RaylibHandle) -> bool {
     unsafe { raylib_ffi::IsWindowMinimized() }
 }
 
 /// Check if window is currently maximized (only PLATFORM_DESKTOP)
-pub fn is_window_maximized() -> bool {
+pub fn is_window_maximized(_rl: &RaylibHandle) -> bool {
     unsafe { raylib_ffi::IsWindowMaximized() }
 }
 
 /// Check if window is currently focused (only PLATFORM_DESKTOP)
-pub fn is_window_focused() -> bool {
+pub fn is_window_focused(_rl: &RaylibHandle) -> bool {
     unsafe { raylib_ffi::IsWindowFocused() }
 }
 
 /// Check if window has been resized last frame
-pub fn is_window_resized() -> bool {
+pub fn is_window_resized(_rl: &RaylibHandle) -> bool {
     unsafe { raylib_ffi::IsWindowResized() }
 }
 
 /// Check if one specific window flag is enabled
-pub fn is_window_state(flag: u32) -> bool {
-    unsafe { raylib_ffi::IsWindowState(flag) }
+pub fn is_window_state(_rl: &RaylibHandle, flag: ConfigFlags) -> bool {
+    unsafe { raylib_ffi::IsWindowState(flag.bits()) }
 }
 
 /// Set window configuration state using flags (only PLATFORM_DESKTOP)
-pub fn set_window_state(flags: u32) {
-    unsafe { raylib_ffi::SetWindowState(flags) }
+pub fn set_window_state(_rl: &RaylibHandle, flags: ConfigFlags) {
+    unsafe { raylib_ffi::SetWindowState(flags.bits()) }
 }
 
 /// Clear window configuration state flags
-pub fn clear_window_state(flags: u32) {
-    unsafe { raylib_ffi::ClearWindowState(flags) }
+pub fn clear_window_state(_rl: &RaylibHandle, flags: ConfigFlags) {
+    unsafe { raylib_ffi::ClearWindowState(flags.bits()) }
 }
 
 /// Toggle window state: fullscreen/windowed (only PLATFORM_DESKTOP)
-pub fn toggle_fullscreen() {
+pub fn toggle_fullscreen(_rl: &RaylibHandle) {
     unsafe { raylib_ffi::ToggleFullscreen() }
 }
 
 /// Toggle window state: borderless windowed (only PLATFORM_DESKTOP)
-pub fn toggle_borderless_windowed() {
+pub fn toggle_borderless_windowed(_rl: &RaylibHandle) {
     unsafe { raylib_ffi::ToggleBorderlessWindowed() }
 }
 
 /// Set window state: maximized, if resizable (only PLATFORM_DESKTOP)
-pub fn maximize_window() {
+pub fn maximize_window(_rl: &RaylibHandle) {
     unsafe { raylib_ffi::MaximizeWindow() }
 }
 
 /// Set window state: minimized, if resizable (only PLATFORM_DESKTOP)
-pub fn minimize_window() {
+pub fn minimize_window(_rl: &RaylibHandle) {
     unsafe { raylib_ffi::MinimizeWindow() }
 }
 
 /// Set window state: not minimized/maximized (only PLATFORM_DESKTOP)
-pub fn restore_window() {
+pub fn restore_window(_rl: &RaylibHandle) {
     unsafe { raylib_ffi::RestoreWindow() }
 }
 
 /// Set icon for window (single image, RGBA 32bit, only PLATFORM_DESKTOP)
-pub fn set_window_icon(image: raylib_ffi::Image) {
+pub fn set_window_icon(_rl: &RaylibHandle, image: &Image) {
     unsafe {
-        raylib_ffi::SetWindowIcon(image);
+        raylib_ffi::SetWindowIcon(image.as_raw());
     }
 }
 
 /// Set icon for window (multiple images, RGBA 32bit, only PLATFORM_DESKTOP)
-pub fn set_window_icons(images: &mut [crate::Image]) {
+pub fn set_window_icons(_rl: &RaylibHandle, images: &[Image]) {
+    let mut raw_images: Vec<raylib_ffi::Image> = images.iter().map(Image::as_raw).collect();
     unsafe {
-        raylib_ffi::SetWindowIcons(images.as_mut_ptr(), images.len() as i32);
+        raylib_ffi::SetWindowIcons(raw_images.as_mut_ptr(), raw_images.len() as i32);
     }
 }
 
 /// Set title for window (only PLATFORM_DESKTOP and PLATFORM_WEB)
-pub fn set_window_title(title: &str) {
+pub fn set_window_title(_rl: &RaylibHandle, title: &str) {
     unsafe {
         raylib_ffi::SetWindowTitle(raylib_ffi::rl_str!(title));
     }
 }
 
 /// Set window position on screen (only PLATFORM_DESKTOP)
-pub fn set_window_position(x: i32, y: i32) {
+pub fn set_window_position(_rl: &RaylibHandle, x: i32, y: i32) {
     unsafe {
         raylib_ffi::SetWindowPosition(x, y);
     }
 }
 
 /// Set monitor for the current window
-pub fn set_window_monitor(monitor: i32) {
+pub fn set_window_monitor(_rl: &RaylibHandle, monitor: i32) {
     unsafe {
         raylib_ffi::SetWindowMonitor(monitor);
     }
 }
 
 /// Set window minimum dimensions (for FLAG_WINDOW_RESIZABLE)
-pub fn set_window_min_size(width: i32, height: i32) {
+pub fn set_window_min_size(_rl: &RaylibHandle, width: i32, height: i32) {
     unsafe {
         raylib_ffi::SetWindowMinSize(width, height);
     }
 }
 
 /// Set window maximum dimensions (for FLAG_WINDOW_RESIZABLE)
-pub fn set_window_max_size(width: i32, height: i32) {
+pub fn set_window_max_size(_rl: &RaylibHandle, width: i32, height: i32) {
     unsafe {
         raylib_ffi::SetWindowMaxSize(width, height);
     }
 }
 
 /// Set window dimensions
-pub fn set_window_size(width: i32, height: i32) {
+pub fn set_window_size(_rl: &RaylibHandle, width: i32, height: i32) {
     unsafe {
         raylib_ffi::SetWindowSize(width, height);
     }
 }
 
 /// Set window opacity [0.0f..1.0f] (only PLATFORM_DESKTOP)
-pub fn set_window_opacity(opacity: f32) {
+pub fn set_window_opacity(_rl: &RaylibHandle, opacity: f32) {
     unsafe {
         raylib_ffi::SetWindowOpacity(opacity);
     }
 }
 
 /// Set window focused (only PLATFORM_DESKTOP)
-pub fn set_window_focused() {
+pub fn set_window_focused(_rl: &RaylibHandle) {
     unsafe {
         raylib_ffi::SetWindowFocused();
     }
 }
 
 /// Get native window handle
-pub fn get_window_handle() -> *mut std::ffi::c_void {
+pub fn get_window_handle(_rl: &RaylibHandle) -> *mut std::ffi::c_void {
     unsafe { raylib_ffi::GetWindowHandle() }
 }
 
 /// Get current screen width
-pub fn get_screen_width() -> i32 {
+pub fn get_screen_width(_rl: &RaylibHandle) -> i32 {
     unsafe { raylib_ffi::GetScreenWidth() }
 }
 
 /// Get current screen height
-pub fn get_screen_height() -> i32 {
+pub fn get_screen_height(_rl: &RaylibHandle) -> i32 {
     unsafe { raylib_ffi::GetScreenHeight() }
 }
 
 /// Get current render width (it considers HiDPI)
-pub fn get_render_width() -> i32 {
+pub fn get_render_width(_rl: &RaylibHandle) -> i32 {
     unsafe { raylib_ffi::GetRenderWidth() }
 }
 
 /// Get current render height (it considers HiDPI)
-pub fn get_render_height() -> i32 {
+pub fn get_render_height(_rl: &RaylibHandle) -> i32 {
     unsafe { raylib_ffi::GetRenderHeight() }
 }
 
 /// Get number of connected monitors
-pub fn get_monitor_count() -> i32 {
+pub fn get_monitor_count(_rl: &RaylibHandle) -> i32 {
     unsafe { raylib_ffi::GetMonitorCount() }
 }
 
 /// Get current connected monitor
-pub fn get_current_monitor() -> i32 {
+pub fn get_current_monitor(_rl: &RaylibHandle) -> i32 {
     unsafe { raylib_ffi::GetCurrentMonitor() }
 }
 
 /// Get specified monitor position
-pub fn get_monitor_position(monitor: i32) -> Vector2 {
-    unsafe { raylib_ffi::GetMonitorPosition(monitor) }
+pub fn get_monitor_position(_rl: &RaylibHandle, monitor: i32) -> Vector2 {
+    unsafe { raylib_ffi::GetMonitorPosition(monitor).into() }
 }
 
 /// Get specified monitor width (current video mode used by monitor)
-pub fn get_monitor_width(monitor: i32) -> i32 {
+pub fn get_monitor_width(_rl: &RaylibHandle, monitor: i32) -> i32 {
     unsafe { raylib_ffi::GetMonitorWidth(monitor) }
 }
 
 /// Get specified monitor height (current video mode used by monitor)
-pub fn get_monitor_height(monitor: i32) -> i32 {
+pub fn get_monitor_height(_rl: &RaylibHandle, monitor: i32) -> i32 {
     unsafe { raylib_ffi::GetMonitorHeight(monitor) }
 }
 
 /// Get specified monitor physical width in millimetres
-pub fn get_monitor_physical_width(monitor: i32) -> i32 {
+pub fn get_monitor_physical_width(_rl: &RaylibHandle, monitor: i32) -> i32 {
     unsafe { raylib_ffi::GetMonitorPhysicalWidth(monitor) }
 }
 
 /// Get specified monitor physical height in millimetres
-pub fn get_monitor_physical_height(monitor: i32) -> i32 {
+pub fn get_monitor_physical_height(_rl: &RaylibHandle, monitor: i32) -> i32 {
     unsafe { raylib_ffi::GetMonitorPhysicalHeight(monitor) }
 }
 
 /// Get specified monitor refresh rate
-pub fn get_monitor_refresh_rate(monitor: i32) -> i32 {
+pub fn get_monitor_refresh_rate(_rl: &RaylibHandle, monitor: i32) -> i32 {
     unsafe { raylib_ffi::GetMonitorRefreshRate(monitor) }
 }
 
 /// Get window position XY on monitor
-pub fn get_window_position() -> Vector2 {
-    unsafe { raylib_ffi::GetWindowPosition() }
+pub fn get_window_position(_rl: &RaylibHandle) -> Vector2 {
+    unsafe { raylib_ffi::GetWindowPosition().into() }
 }
 
 /// Get window scale DPI factor
-pub fn get_window_scale_dpi() -> Vector2 {
-    unsafe { raylib_ffi::GetWindowScaleDPI() }
+pub fn get_window_scale_dpi(_rl: &RaylibHandle) -> Vector2 {
+    unsafe { raylib_ffi::GetWindowScaleDPI().into() }
 }
 
 /// Get the human-readable, UTF-8 encoded name of the specified monitor
-pub fn get_monitor_name(monitor: i32) -> &'static str {
+pub fn get_monitor_name(_rl: &RaylibHandle, monitor: i32) -> String {
     unsafe {
-        std::ffi::CStr::from_ptr(raylib_ffi::GetMonitorName(monitor))
-            .to_str()
-            .unwrap()
+        let ptr = raylib_ffi::GetMonitorName(monitor);
+        if ptr.is_null() {
+            return String::new();
+        }
+        std::ffi::CStr::from_ptr(ptr).to_string_lossy().into_owned()
+    }
+}
+
+/// Properties of a connected monitor, read when the `Monitor` is queried
+#[derive(Debug, Clone)]
+pub struct Monitor {
+    /// Index of the monitor, as passed to the `get_monitor_*` functions
+    pub index: i32,
+    /// Human-readable name
+    pub name: String,
+    /// Position of the monitor on the virtual desktop
+    pub position: Vector2,
+    /// Width of the current video mode
+    pub width: i32,
+    /// Height of the current video mode
+    pub height: i32,
+    /// Physical width in millimetres
+    pub physical_width: i32,
+    /// Physical height in millimetres
+    pub physical_height: i32,
+    /// Refresh rate of the current video mode
+    pub refresh_rate: i32,
+    /// Window scale DPI factor, raylib only reports it for the monitor of the window
+    pub scale_dpi: Option<Vector2>,
+}
+
+impl Monitor {
+    /// Dots per inch computed from the video mode and physical size, if the size is known
+    pub fn dpi(&self) -> Option<Vector2> {
+        if self.physical_width <= 0 || self.physical_height <= 0 {
+            return None;
+        }
+        Some(Vector2 {
+            x: self.width as f32 * 25.4 / self.physical_width as f32,
+            y: self.height as f32 * 25.4 / self.physical_height as f32,
+        })
+    }
+
+    /// Check if a point of the virtual desktop is on the monitor
+    pub fn contains(&self, point: Vector2) -> bool {
+        point.x >= self.position.x
+            && point.y >= self.position.y
+            && point.x < self.position.x + self.width as f32
+            && point.y < self.position.y + self.height as f32
     }
+
+    /// Position of a window of the given size centred on the monitor
+    pub fn centered_position(&self, width: i32, height: i32) -> (i32, i32) {
+        (
+            self.position.x as i32 + (self.width - width) / 2,
+            self.position.y as i32 + (self.height - height) / 2,
+        )
+    }
+}
+
+/// Get the properties of the specified monitor, `None` if it is not connected
+pub fn get_monitor(rl: &RaylibHandle, monitor: i32) -> Option<Monitor> {
+    if monitor < 0 || monitor >= get_monitor_count(rl) {
+        return None;
+    }
+    let scale_dpi = if monitor == get_current_monitor(rl) {
+        Some(get_window_scale_dpi(rl))
+    } else {
+        None
+    };
+    Some(Monitor {
+        index: monitor,
+        name: get_monitor_name(rl, monitor),
+        position: get_monitor_position(rl, monitor),
+        width: get_monitor_width(rl, monitor),
+        height: get_monitor_height(rl, monitor),
+        physical_width: get_monitor_physical_width(rl, monitor),
+        physical_height: get_monitor_physical_height(rl, monitor),
+        refresh_rate: get_monitor_refresh_rate(rl, monitor),
+        scale_dpi,
+    })
+}
+
+/// Get the properties of every connected monitor
+pub fn monitors(rl: &RaylibHandle) -> impl Iterator<Item = Monitor> + '_ {
+    (0..get_monitor_count(rl)).filter_map(move |monitor| get_monitor(rl, monitor))
+}
+
+/// Get the monitor containing the window
+pub fn get_window_monitor(rl: &RaylibHandle) -> Option<Monitor> {
+    get_monitor(rl, get_current_monitor(rl))
+}
+
+/// Move the window to the specified monitor, centred on it
+///
+/// A fullscreen window is moved with SetWindowMonitor(), a windowed one keeps its size.
+pub fn move_window_to_monitor(rl: &RaylibHandle, monitor: i32) -> crate::Result<()> {
+    let target = get_monitor(rl, monitor).ok_or(Error::MonitorNotFound(monitor))?;
+    if is_window_fullscreen(rl) {
+        set_window_monitor(rl, monitor);
+    } else {
+        let (x, y) = target.centered_position(get_screen_width(rl), get_screen_height(rl));
+        set_window_position(rl, x, y);
+    }
+    Ok(())
 }
 
 /// Set clipboard text content
-pub fn set_clipboard_text(text: &str) {
+pub fn set_clipboard_text(_rl: &RaylibHandle, text: &str) {
     unsafe { raylib_ffi::SetClipboardText(raylib_ffi::rl_str!(text)) }
 }
 
 /// Get clipboard text content
-pub fn get_clipboard_text() -> String {
+pub fn get_clipboard_text(_rl: &RaylibHandle) -> String {
     unsafe {
         let ptr = raylib_ffi::GetClipboardText();
         std::ffi::CStr::from_ptr(ptr).to_string_lossy().into_owned()
@@ -271,271 +633,599 @@ pub fn get_clipboard_text() -> String {
 }
 
 /// Enable waiting for events on EndDrawing(), no automatic event polling
-pub fn enable_event_waiting() {
+pub fn enable_event_waiting(_rl: &RaylibHandle) {
     unsafe { raylib_ffi::EnableEventWaiting() }
 }
 
 /// Disable waiting for events on EndDrawing(), automatic events polling
-pub fn disable_event_waiting() {
+pub fn disable_event_waiting(_rl: &RaylibHandle) {
     unsafe { raylib_ffi::DisableEventWaiting() }
 }
 
 // Cursor-related functions
 /// Shows cursor
-pub fn show_cursor() {
+pub fn show_cursor(_rl: &RaylibHandle) {
     unsafe { raylib_ffi::ShowCursor() }
 }
 
 /// Hides cursor
-pub fn hide_cursor() {
+pub fn hide_cursor(_rl: &RaylibHandle) {
     unsafe { raylib_ffi::HideCursor() }
 }
 
 /// Check if cursor is not visible
-pub fn is_cursor_hidden() -> bool {
+pub fn is_cursor_hidden(_rl: &RaylibHandle) -> bool {
     unsafe { raylib_ffi::IsCursorHidden() }
 }
 
 /// Enables cursor (unlock cursor)
-pub fn enable_cursor() {
+pub fn enable_cursor(_rl: &RaylibHandle) {
     unsafe { raylib_ffi::EnableCursor() }
 }
 
 /// Disables cursor (lock cursor)
-pub fn disable_cursor() {
+pub fn disable_cursor(_rl: &RaylibHandle) {
     unsafe { raylib_ffi::DisableCursor() }
 }
 
 /// Check if cursor is on the screen
-pub fn is_cursor_on_screen() -> bool {
+pub fn is_cursor_on_screen(_rl: &RaylibHandle) -> bool {
     unsafe { raylib_ffi::IsCursorOnScreen() }
 }
 
-/// Drawing-related functions
+// Drawing-related functions
+mod private {
+    /// Keeps `RaylibContext` and `RaylibDraw` from being implemented outside the crate
+    pub trait Sealed {}
+}
 
-/// Set background color (framebuffer clear color)
-pub fn clear_background(color: Color) {
-    unsafe {
-        raylib_ffi::ClearBackground(color);
+/// Types that prove the window is open: the `RaylibHandle` and every drawing scope
+///
+/// This trait is sealed, it can not be implemented outside this crate.
+pub trait RaylibContext: private::Sealed {}
+
+/// Drawing scopes, drawing functions can only be called through one of these
+///
+/// This trait is sealed, it can not be implemented outside this crate.
+pub trait RaylibDraw: RaylibContext {}
+
+impl private::Sealed for RaylibHandle {}
+impl RaylibContext for RaylibHandle {}
+
+/// Canvas (framebuffer) drawing scope, calls EndDrawing() on drop
+#[derive(Debug)]
+pub struct DrawHandle<'a> {
+    rl: &'a mut RaylibHandle,
+}
+
+impl Deref for DrawHandle<'_> {
+    type Target = RaylibHandle;
+
+    fn deref(&self) -> &Self::Target {
+        self.rl
     }
 }
 
-/// Setup canvas (framebuffer) to start drawing
-pub fn begin_drawing() {
-    unsafe {
-        raylib_ffi::BeginDrawing();
+impl Drop for DrawHandle<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            raylib_ffi::EndDrawing();
+        }
     }
 }
 
-/// End canvas drawing and swap buffers (double buffering)
-pub fn end_drawing() {
+impl private::Sealed for DrawHandle<'_> {}
+impl RaylibContext for DrawHandle<'_> {}
+impl RaylibDraw for DrawHandle<'_> {}
+
+/// Setup canvas (framebuffer) to start drawing, ends drawing and swaps buffers when the handle is dropped
+pub fn begin_drawing(rl: &mut RaylibHandle) -> DrawHandle<'_> {
     unsafe {
-        raylib_ffi::EndDrawing();
+        raylib_ffi::BeginDrawing();
     }
+    DrawHandle { rl }
 }
 
-/// Begin 2D mode with custom camera (2D)
-pub fn begin_mode_2d(camera: Camera2D) {
+/// Defines a drawing scope nested in a parent scope, the end function is called on drop
+macro_rules! draw_mode {
+    ($(#[$meta:meta])* $name:ident, $end:ident) => {
+        $(#[$meta])*
+        #[derive(Debug)]
+        pub struct $name<'a, T> {
+            parent: &'a mut T,
+        }
+
+        impl<T> Deref for $name<'_, T> {
+            type Target = T;
+
+            fn deref(&self) -> &Self::Target {
+                self.parent
+            }
+        }
+
+        impl<T> Drop for $name<'_, T> {
+            fn drop(&mut self) {
+                unsafe {
+                    raylib_ffi::$end();
+                }
+            }
+        }
+
+        impl<T: RaylibContext> private::Sealed for $name<'_, T> {}
+        impl<T: RaylibContext> RaylibContext for $name<'_, T> {}
+        impl<T: RaylibContext> RaylibDraw for $name<'_, T> {}
+    };
+}
+
+draw_mode!(
+    /// 2D mode with custom camera, calls EndMode2D() on drop
+    Mode2D,
+    EndMode2D
+);
+draw_mode!(
+    /// 3D mode with custom camera, returns to default 2D orthographic mode on drop
+    Mode3D,
+    EndMode3D
+);
+draw_mode!(
+    /// Drawing to render texture, calls EndTextureMode() on drop
+    TextureMode,
+    EndTextureMode
+);
+draw_mode!(
+    /// Custom shader drawing, returns to the default shader on drop
+    ShaderMode,
+    EndShaderMode
+);
+draw_mode!(
+    /// Blending mode, resets to default alpha blending on drop
+    BlendingMode,
+    EndBlendMode
+);
+draw_mode!(
+    /// Scissor mode, calls EndScissorMode() on drop
+    ScissorMode,
+    EndScissorMode
+);
+draw_mode!(
+    /// Stereo rendering, calls EndVrStereoMode() on drop
+    VrStereoMode,
+    EndVrStereoMode
+);
+
+/// Set background color (framebuffer clear color)
+pub fn clear_background(_d: &mut impl RaylibDraw, color: Color) {
     unsafe {
-        raylib_ffi::BeginMode2D(camera);
+        raylib_ffi::ClearBackground(color);
     }
 }
 
-/// Ends 2D mode with custom camera
-pub fn end_mode_2d() {
+/// Begin 2D mode with custom camera (2D)
+pub fn begin_mode_2d<T: RaylibDraw>(d: &mut T, camera: Camera2D) -> Mode2D<'_, T> {
     unsafe {
-        raylib_ffi::EndMode2D();
+        raylib_ffi::BeginMode2D(camera.into());
     }
+    Mode2D { parent: d }
 }
 
 /// Begin 3D mode with custom camera (3D)
-pub fn begin_mode_3d(camera: Camera3D) {
+pub fn begin_mode_3d<T: RaylibDraw>(d: &mut T, camera: Camera3D) -> Mode3D<'_, T> {
     unsafe {
-        raylib_ffi::BeginMode3D(camera);
+        raylib_ffi::BeginMode3D(camera.into());
     }
+    Mode3D { parent: d }
 }
 
-/// Ends 3D mode and returns to default 2D orthographic mode
-pub fn end_mode_3d() {
+/// Begin drawing to render texture, can be started inside or outside of a drawing scope
+///
+/// `target` stays mutably borrowed until the mode ends, so it can't be used as a source meanwhile.
+pub fn begin_texture_mode<'a, T: RaylibContext>(
+    parent: &'a mut T,
+    target: &'a mut RenderTexture,
+) -> TextureMode<'a, T> {
     unsafe {
-        raylib_ffi::EndMode3D();
+        raylib_ffi::BeginTextureMode(target.as_raw());
     }
+    TextureMode { parent }
 }
 
-/// Begin drawing to render texture
-pub fn begin_texture_mode(target: crate::RenderTexture2D) {
+/// Begin custom shader drawing
+pub fn begin_shader_mode<'a, T: RaylibDraw>(d: &'a mut T, shader: &'a Shader) -> ShaderMode<'a, T> {
     unsafe {
-        raylib_ffi::BeginTextureMode(target);
+        raylib_ffi::BeginShaderMode(shader.raw);
     }
+    ShaderMode { parent: d }
 }
 
-/// Ends drawing to render texture
-pub fn end_texture_mode() {
+/// Begin blending mode (alpha, additive, multiplied, subtract, custom)
+pub fn begin_blend_mode<T: RaylibDraw>(d: &mut T, mode: BlendMode) -> BlendingMode<'_, T> {
     unsafe {
-        raylib_ffi::EndTextureMode();
+        raylib_ffi::BeginBlendMode(mode as i32);
     }
+    BlendingMode { parent: d }
 }
 
-/// Begin custom shader drawing
-pub fn begin_shader_mode(shader: crate::Shader) {
+/// Begin scissor mode (define screen area for following drawing)
+pub fn begin_scissor_mode<T: RaylibDraw>(
+    d: &mut T,
+    x: i32,
+    y: i32,
+    width: i32,
+    height: i32,
+) -> ScissorMode<'_, T> {
     unsafe {
-        raylib_ffi::BeginShaderMode(shader);
+        raylib_ffi::BeginScissorMode(x, y, width, height);
     }
+    ScissorMode { parent: d }
 }
 
-/// End custom shader drawing (use default shader)
-pub fn end_shader_mode() {
+/// Begin stereo rendering (requires VR simulator)
+pub fn begin_vr_stereo_mode<T: RaylibDraw>(
+    d: &mut T,
+    config: crate::VrStereoConfig,
+) -> VrStereoMode<'_, T> {
     unsafe {
-        raylib_ffi::EndShaderMode();
+        raylib_ffi::BeginVrStereoMode(config);
     }
+    VrStereoMode { parent: d }
 }
 
-/// Begin blending mode (alpha, additive, multiplied, subtract, custom)
-pub fn begin_blend_mode(mode: i32) {
-    unsafe {
-        raylib_ffi::BeginBlendMode(mode);
+// VR stereo config functions for VR simulator
+/// Load VR stereo config for VR simulator device parameters
+pub fn load_vr_stereo_config(_rl: &RaylibHandle, device: VrDeviceInfo) -> VrStereoConfig {
+    unsafe { raylib_ffi::LoadVrStereoConfig(device) }
+}
+
+/// Unload VR stereo config
+pub fn unload_vr_stereo_config(_rl: &RaylibHandle, config: VrStereoConfig) {
+    unsafe { raylib_ffi::UnloadVrStereoConfig(config) }
+}
+
+// Shader management functions
+/// Maximum number of shader locations supported (RL_MAX_SHADER_LOCATIONS)
+const MAX_SHADER_LOCATIONS: usize = 32;
+
+/// Shader program loaded in GPU memory (VRAM), unloaded on drop
+#[derive(Debug)]
+pub struct Shader {
+    raw: raylib_ffi::Shader,
+    // Uniform locations by name, including missing uniforms (-1)
+    uniform_locs: RefCell<HashMap<String, i32>>,
+}
+
+impl Shader {
+    /// Take ownership of a raw shader, it will be unloaded on drop
+    ///
+    /// # Safety
+    /// `raw` must be a valid shader loaded by raylib that is not owned elsewhere
+    pub unsafe fn from_raw(raw: raylib_ffi::Shader) -> Self {
+        Self {
+            raw,
+            uniform_locs: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Release ownership of the raw shader, it will no longer be unloaded on drop
+    pub fn into_raw(self) -> raylib_ffi::Shader {
+        let raw = self.raw;
+        std::mem::forget(self);
+        raw
+    }
+
+    /// Get a copy of the raw shader, only valid while `self` is alive
+    pub fn as_raw(&self) -> raylib_ffi::Shader {
+        self.raw
+    }
+
+    /// Shader program id
+    pub fn id(&self) -> u32 {
+        self.raw.id
+    }
+
+    /// Shader locations array, indexed by `ShaderLocationIndex`
+    pub fn locs(&self) -> &[i32] {
+        if self.raw.locs.is_null() {
+            return &[];
+        }
+        unsafe { std::slice::from_raw_parts(self.raw.locs, MAX_SHADER_LOCATIONS) }
+    }
+
+    /// Mutable shader locations array, used to bind custom uniforms to default locations
+    pub fn locs_mut(&mut self) -> &mut [i32] {
+        if self.raw.locs.is_null() {
+            return &mut [];
+        }
+        unsafe { std::slice::from_raw_parts_mut(self.raw.locs, MAX_SHADER_LOCATIONS) }
+    }
+
+    /// Get shader uniform location, -1 if not found
+    ///
+    /// Locations are looked up once per name and cached, including missing uniforms.
+    pub fn location(&self, uniform_name: &str) -> i32 {
+        if let Some(&loc) = self.uniform_locs.borrow().get(uniform_name) {
+            return loc;
+        }
+        let loc =
+            unsafe { raylib_ffi::GetShaderLocation(self.raw, raylib_ffi::rl_str!(uniform_name)) };
+        self.uniform_locs
+            .borrow_mut()
+            .insert(uniform_name.to_string(), loc);
+        loc
+    }
+
+    /// Set shader uniform value, the uniform data type is chosen from `T`
+    pub fn set_uniform<T: ShaderUniform>(&self, loc_index: i32, value: T) {
+        value.upload(self, loc_index);
+    }
+
+    /// Set shader uniform value by name, missing uniforms are ignored
+    pub fn set<T: ShaderUniform>(&self, uniform_name: &str, value: T) {
+        self.set_uniform(self.location(uniform_name), value);
+    }
+
+    /// Set shader uniform value by name, strict version of `set()`
+    ///
+    /// Returns an error if the uniform does not exist (or was optimized out by the driver).
+    pub fn try_set<T: ShaderUniform>(&self, uniform_name: &str, value: T) -> crate::Result<()> {
+        match self.location(uniform_name) {
+            -1 => Err(Error::UniformNotFound(uniform_name.to_string())),
+            loc => {
+                self.set_uniform(loc, value);
+                Ok(())
+            }
+        }
     }
 }
 
-/// End blending mode (reset to default: alpha blending)
-pub fn end_blend_mode() {
-    unsafe {
-        raylib_ffi::EndBlendMode();
+impl Drop for Shader {
+    fn drop(&mut self) {
+        // The GPU data is already gone once the window is closed,
+        // raylib also skips the default shader here
+        if is_window_open() {
+            unsafe { raylib_ffi::UnloadShader(self.raw) }
+        }
     }
 }
 
-/// Begin scissor mode (define screen area for following drawing)
-pub fn begin_scissor_mode(x: i32, y: i32, width: i32, height: i32) {
-    unsafe {
-        raylib_ffi::BeginScissorMode(x, y, width, height);
+/// Value that can be sent to a shader uniform with `Shader::set_uniform()`
+pub trait ShaderUniform {
+    /// Set the uniform at `loc_index` of `shader` to this value
+    fn upload(self, shader: &Shader, loc_index: i32);
+}
+
+/// Single uniform value sent as-is, slices of it are sent as uniform arrays
+///
+/// # Safety
+/// The type must have the exact memory layout of `UNIFORM_TYPE` (e.g. `[f32; 3]` for vec3)
+pub unsafe trait ShaderUniformElement: Copy {
+    /// Shader uniform data type of one element
+    const UNIFORM_TYPE: ShaderUniformDataType;
+}
+
+macro_rules! uniform_element {
+    ($($ty:ty => $uniform_type:ident),* $(,)?) => {
+        $(
+            unsafe impl ShaderUniformElement for $ty {
+                const UNIFORM_TYPE: ShaderUniformDataType = ShaderUniformDataType::$uniform_type;
+            }
+        )*
+    };
+}
+
+uniform_element!(
+    f32 => Float,
+    [f32; 2] => Vec2,
+    [f32; 3] => Vec3,
+    [f32; 4] => Vec4,
+    i32 => Int,
+    [i32; 2] => Ivec2,
+    [i32; 3] => Ivec3,
+    [i32; 4] => Ivec4,
+    Vector2 => Vec2,
+    Vector3 => Vec3,
+    Vector4 => Vec4,
+);
+
+impl<T: ShaderUniformElement> ShaderUniform for T {
+    fn upload(self, shader: &Shader, loc_index: i32) {
+        unsafe {
+            raylib_ffi::SetShaderValue(
+                shader.raw,
+                loc_index,
+                &self as *const T as *const std::ffi::c_void,
+                T::UNIFORM_TYPE as i32,
+            )
+        }
     }
 }
 
-/// End scissor mode
-pub fn end_scissor_mode() {
-    unsafe {
-        raylib_ffi::EndScissorMode();
+impl<T: ShaderUniformElement> ShaderUniform for &[T] {
+    fn upload(self, shader: &Shader, loc_index: i32) {
+        unsafe {
+            raylib_ffi::SetShaderValueV(
+                shader.raw,
+                loc_index,
+                self.as_ptr() as *const std::ffi::c_void,
+                T::UNIFORM_TYPE as i32,
+                self.len() as i32,
+            )
+        }
     }
 }
 
-/// Begin stereo rendering (requires VR simulator)
-pub fn begin_vr_stereo_mode(config: crate::VrStereoConfig) {
-    unsafe {
-        raylib_ffi::BeginVrStereoMode(config);
+/// Color normalized to [0..1] as expected by vec4 color uniforms
+fn normalize_color(color: Color) -> [f32; 4] {
+    [
+        color.r as f32 / 255.0,
+        color.g as f32 / 255.0,
+        color.b as f32 / 255.0,
+        color.a as f32 / 255.0,
+    ]
+}
+
+/// Sent as a normalized vec4
+impl ShaderUniform for Color {
+    fn upload(self, shader: &Shader, loc_index: i32) {
+        normalize_color(self).upload(shader, loc_index);
     }
 }
 
-/// End stereo rendering (requires VR simulator)
-pub fn end_vr_stereo_mode() {
-    unsafe {
-        raylib_ffi::EndVrStereoMode();
+/// Sent as an array of normalized vec4
+impl ShaderUniform for &[Color] {
+    fn upload(self, shader: &Shader, loc_index: i32) {
+        let colors: Vec<[f32; 4]> = self.iter().copied().map(normalize_color).collect();
+        colors.as_slice().upload(shader, loc_index);
     }
 }
 
-// VR stereo config functions for VR simulator
-/// Load VR stereo config for VR simulator device parameters
-pub fn load_vr_stereo_config(device: VrDeviceInfo) -> VrStereoConfig {
-    unsafe { raylib_ffi::LoadVrStereoConfig(device) }
+/// Sent as a mat4
+impl ShaderUniform for Matrix {
+    fn upload(self, shader: &Shader, loc_index: i32) {
+        unsafe { raylib_ffi::SetShaderValueMatrix(shader.raw, loc_index, self.into()) }
+    }
 }
 
-/// Unload VR stereo config
-pub fn unload_vr_stereo_config(config: VrStereoConfig) {
-    unsafe { raylib_ffi::UnloadVrStereoConfig(config) }
+/// Sent as an array of mat4, one location per element
+impl ShaderUniform for &[Matrix] {
+    fn upload(self, shader: &Shader, loc_index: i32) {
+        if loc_index < 0 {
+            return;
+        }
+        for (i, mat) in self.iter().enumerate() {
+            mat.upload(shader, loc_index + i as i32);
+        }
+    }
 }
 
-// Shader management functions
-/// Load shader from files and bind default locations
-pub fn load_shader(vs_file_name: &str, fs_file_name: &str) -> Shader {
-    unsafe {
+/// Load shader from files and bind default locations, None uses the default shader stage
+pub fn load_shader(
+    _rl: &RaylibHandle,
+    vs_file_name: Option<&str>,
+    fs_file_name: Option<&str>,
+) -> crate::Result<Shader> {
+    let c_vs_file_name = vs_file_name.map(|f| check_file(f, &[])).transpose()?;
+    let c_fs_file_name = fs_file_name.map(|f| check_file(f, &[])).transpose()?;
+    let shader = unsafe {
         raylib_ffi::LoadShader(
-            raylib_ffi::rl_str!(vs_file_name),
-            raylib_ffi::rl_str!(fs_file_name),
+            c_vs_file_name
+                .as_ref()
+                .map_or(std::ptr::null(), |f| f.as_ptr()),
+            c_fs_file_name
+                .as_ref()
+                .map_or(std::ptr::null(), |f| f.as_ptr()),
         )
+    };
+    match fs_file_name.or(vs_file_name) {
+        Some(path) => check_shader(path, shader, true),
+        None => check_shader("<default shader>", shader, false),
     }
 }
 
-/// Load shader from code strings and bind default locations
-pub fn load_shader_from_memory(vs_code: &str, fs_code: &str) -> Shader {
-    unsafe {
-        raylib_ffi::LoadShaderFromMemory(raylib_ffi::rl_str!(vs_code), raylib_ffi::rl_str!(fs_code))
+/// Load shader from code strings and bind default locations, None uses the default shader stage
+pub fn load_shader_from_memory(
+    _rl: &RaylibHandle,
+    vs_code: Option<&str>,
+    fs_code: Option<&str>,
+) -> crate::Result<Shader> {
+    let vs_code = vs_code
+        .map(|c| c_string("<vertex shader>", c))
+        .transpose()?;
+    let fs_code = fs_code
+        .map(|c| c_string("<fragment shader>", c))
+        .transpose()?;
+    let shader = unsafe {
+        raylib_ffi::LoadShaderFromMemory(
+            vs_code.as_ref().map_or(std::ptr::null(), |c| c.as_ptr()),
+            fs_code.as_ref().map_or(std::ptr::null(), |c| c.as_ptr()),
+        )
+    };
+    check_shader(
+        "<shader code>",
+        shader,
+        vs_code.is_some() || fs_code.is_some(),
+    )
+}
+
+extern "C" {
+    // Part of rlgl, which is compiled into raylib but not covered by the bindings
+    pub(crate) fn rlGetShaderIdDefault() -> u32;
+}
+
+/// Turn raylib's fallback to the default shader program into an error
+fn check_shader(path: &str, shader: raylib_ffi::Shader, custom: bool) -> crate::Result<Shader> {
+    let default_id = unsafe { rlGetShaderIdDefault() };
+    if shader.id == 0 || (custom && shader.id == default_id) {
+        unsafe { raylib_ffi::UnloadShader(shader) };
+        Err(Error::load(path, LoadError::GpuUploadFailed))
+    } else {
+        Ok(unsafe { Shader::from_raw(shader) })
     }
 }
 
 /// Check if a shader is ready
-pub fn is_shader_ready(shader: Shader) -> bool {
-    unsafe { raylib_ffi::IsShaderReady(shader) }
+pub fn is_shader_ready(_rl: &RaylibHandle, shader: &Shader) -> bool {
+    unsafe { raylib_ffi::IsShaderReady(shader.raw) }
 }
 
 /// Get shader uniform location
-pub fn get_shader_location(shader: Shader, uniform_name: &str) -> i32 {
-    unsafe { raylib_ffi::GetShaderLocation(shader, raylib_ffi::rl_str!(uniform_name)) }
+pub fn get_shader_location(_rl: &RaylibHandle, shader: &Shader, uniform_name: &str) -> i32 {
+    shader.location(uniform_name)
 }
 
 /// Get shader attribute location
-pub fn get_shader_location_attrib(shader: Shader, attrib_name: &str) -> i32 {
-    unsafe { raylib_ffi::GetShaderLocationAttrib(shader, raylib_ffi::rl_str!(attrib_name)) }
+pub fn get_shader_location_attrib(_rl: &RaylibHandle, shader: &Shader, attrib_name: &str) -> i32 {
+    unsafe { raylib_ffi::GetShaderLocationAttrib(shader.raw, raylib_ffi::rl_str!(attrib_name)) }
 }
 
-/// Set shader uniform value
-pub fn set_shader_value(
-    shader: Shader,
+/// Set shader uniform value (single value or array), the uniform data type is chosen from `T`
+pub fn set_shader_value<T: ShaderUniform>(
+    _rl: &RaylibHandle,
+    shader: &Shader,
     loc_index: i32,
-    value: *const std::ffi::c_void,
-    uniform_type: i32,
+    value: T,
 ) {
-    unsafe { raylib_ffi::SetShaderValue(shader, loc_index, value, uniform_type) }
-}
-
-/// Set shader uniform value vector
-pub fn set_shader_value_v(
-    shader: Shader,
-    loc_index: i32,
-    value: *const std::ffi::c_void,
-    uniform_type: i32,
-    count: i32,
-) {
-    unsafe { raylib_ffi::SetShaderValueV(shader, loc_index, value, uniform_type, count) }
+    shader.set_uniform(loc_index, value);
 }
 
 /// Set shader uniform value (matrix 4x4)
-pub fn set_shader_value_matrix(shader: Shader, loc_index: i32, mat: Matrix) {
-    unsafe { raylib_ffi::SetShaderValueMatrix(shader, loc_index, mat) }
+pub fn set_shader_value_matrix(_rl: &RaylibHandle, shader: &Shader, loc_index: i32, mat: Matrix) {
+    unsafe { raylib_ffi::SetShaderValueMatrix(shader.raw, loc_index, mat.into()) }
 }
 
 /// Set shader uniform value for texture (sampler2d)
-pub fn set_shader_value_texture(shader: Shader, loc_index: i32, texture: Texture2D) {
-    unsafe { raylib_ffi::SetShaderValueTexture(shader, loc_index, texture) }
-}
-
-/// Unload shader from GPU memory (VRAM)
-pub fn unload_shader(shader: Shader) {
-    unsafe { raylib_ffi::UnloadShader(shader) }
+pub fn set_shader_value_texture(
+    _rl: &RaylibHandle,
+    shader: &Shader,
+    loc_index: i32,
+    texture: &Texture,
+) {
+    unsafe { raylib_ffi::SetShaderValueTexture(shader.raw, loc_index, texture.as_raw()) }
 }
 
 // Screen-space-related functions
 /// Get a ray trace from mouse position
-pub fn get_mouse_ray(mouse_position: Vector2, camera: Camera) -> Ray {
-    unsafe { raylib_ffi::GetMouseRay(mouse_position, camera) }
+pub fn get_mouse_ray(_rl: &RaylibHandle, mouse_position: Vector2, camera: Camera) -> Ray {
+    unsafe { raylib_ffi::GetMouseRay(mouse_position.into(), camera.into()).into() }
 }
 
 /// Get camera transform matrix (view matrix)
 pub fn get_camera_matrix(camera: Camera) -> Matrix {
-    unsafe { raylib_ffi::GetCameraMatrix(camera) }
+    unsafe { raylib_ffi::GetCameraMatrix(camera.into()).into() }
 }
 
 /// Get camera 2d transform matrix
 pub fn get_camera_matrix_2d(camera: Camera2D) -> Matrix {
-    unsafe { raylib_ffi::GetCameraMatrix2D(camera) }
+    unsafe { raylib_ffi::GetCameraMatrix2D(camera.into()).into() }
 }
 
 /// Get the screen space position for a 3d world space position
-pub fn get_world_to_screen(position: Vector3, camera: Camera) -> Vector2 {
-    unsafe { raylib_ffi::GetWorldToScreen(position, camera) }
+pub fn get_world_to_screen(_rl: &RaylibHandle, position: Vector3, camera: Camera) -> Vector2 {
+    unsafe { raylib_ffi::GetWorldToScreen(position.into(), camera.into()).into() }
 }
 
 /// Get the world space position for a 2d camera screen space position
 pub fn get_screen_to_world_2d(position: Vector2, camera: Camera2D) -> Vector2 {
-    unsafe { raylib_ffi::GetScreenToWorld2D(position, camera) }
+    unsafe { raylib_ffi::GetScreenToWorld2D(position.into(), camera.into()).into() }
 }
 
 /// Get size position for a 3d world space position
@@ -545,32 +1235,32 @@ pub fn get_world_to_screen_ex(
     width: i32,
     height: i32,
 ) -> Vector2 {
-    unsafe { raylib_ffi::GetWorldToScreenEx(position, camera, width, height) }
+    unsafe { raylib_ffi::GetWorldToScreenEx(position.into(), camera.into(), width, height).into() }
 }
 
 /// Get the screen space position for a 2d camera world space position
 pub fn get_world_to_screen_2d(position: Vector2, camera: Camera2D) -> Vector2 {
-    unsafe { raylib_ffi::GetWorldToScreen2D(position, camera) }
+    unsafe { raylib_ffi::GetWorldToScreen2D(position.into(), camera.into()).into() }
 }
 
 // Timing-related functions
 /// Set target FPS (maximum)
-pub fn set_target_fps(fps: i32) {
+pub fn set_target_fps(_rl: &RaylibHandle, fps: i32) {
     unsafe { raylib_ffi::SetTargetFPS(fps) }
 }
 
 /// Get time in seconds for last frame drawn (delta time)
-pub fn get_frame_time() -> f32 {
+pub fn get_frame_time(_rl: &RaylibHandle) -> f32 {
     unsafe { raylib_ffi::GetFrameTime() }
 }
 
 /// Get elapsed time in seconds since InitWindow()
-pub fn get_time() -> f64 {
+pub fn get_time(_rl: &RaylibHandle) -> f64 {
     unsafe { raylib_ffi::GetTime() }
 }
 
 /// Get current FPS
-pub fn get_fps() -> i32 {
+pub fn get_fps(_rl: &RaylibHandle) -> i32 {
     unsafe { raylib_ffi::GetFPS() }
 }
 
@@ -601,16 +1291,16 @@ pub fn load_random_sequence(count: u32, min: i32, max: i32) -> Vec<i32> {
 // Misc. functions
 
 /// Takes a screenshot of the current screen (filename extension defines format).
-pub fn take_screenshot(file_name: &str) {
+pub fn take_screenshot(_rl: &RaylibHandle, file_name: &str) {
     unsafe {
         raylib_ffi::TakeScreenshot(raylib_ffi::rl_str!(file_name));
     }
 }
 
 /// Setup init configuration flags (view FLAGS).
-pub fn set_config_flags(flags: u32) {
+pub fn set_config_flags(flags: ConfigFlags) {
     unsafe {
-        raylib_ffi::SetConfigFlags(flags);
+        raylib_ffi::SetConfigFlags(flags.bits());
     }
 }
 
@@ -625,16 +1315,19 @@ pub fn open_url(url: &str) {
 //------------------------------------------------------------------
 
 /// Show trace log messages (LOG_DEBUG, LOG_INFO, LOG_WARNING, LOG_ERROR...).
-pub fn trace_log(log_level: i32, text: &str) {
+pub fn trace_log(log_level: TraceLogLevel, text: &str) {
+    // Messages logged before the window is created are forwarded too
+    #[cfg(feature = "log")]
+    install_log_forwarding();
     unsafe {
-        raylib_ffi::TraceLog(log_level, raylib_ffi::rl_str!(text));
+        raylib_ffi::TraceLog(log_level as i32, raylib_ffi::rl_str!(text));
     }
 }
 
 /// Set the current threshold (minimum) log level.
-pub fn set_trace_log_level(log_level: i32) {
+pub fn set_trace_log_level(log_level: TraceLogLevel) {
     unsafe {
-        raylib_ffi::SetTraceLogLevel(log_level);
+        raylib_ffi::SetTraceLogLevel(log_level as i32);
     }
 }
 
@@ -653,26 +1346,140 @@ pub unsafe fn mem_free(ptr: *mut std::ffi::c_void) {
     raylib_ffi::MemFree(ptr)
 }
 
-/// Set custom trace log.
-pub fn set_trace_log_callback(callback: crate::TraceLogCallback) {
+type TraceLogFn = Arc<dyn Fn(TraceLogLevel, &str) + Send + Sync>;
+
+/// Rust callback installed with `set_trace_log_callback()`
+static TRACE_LOG_CALLBACK: RwLock<Option<TraceLogFn>> = RwLock::new(None);
+
+/// Max length of one formatted trace-log message, longer messages are truncated
+const MAX_TRACELOG_MSG_LENGTH: usize = 1024;
+
+/// Extracts the platform `va_list` argument type from the `TraceLogCallback` binding
+trait TraceLogCallbackArgs {
+    type VaList;
+}
+
+impl<A> TraceLogCallbackArgs
+    for Option<unsafe extern "C" fn(std::os::raw::c_int, *const std::os::raw::c_char, A)>
+{
+    type VaList = A;
+}
+
+type VaList = <raylib_ffi::TraceLogCallback as TraceLogCallbackArgs>::VaList;
+
+extern "C" {
+    fn vsnprintf(
+        s: *mut std::os::raw::c_char,
+        n: usize,
+        format: *const std::os::raw::c_char,
+        args: VaList,
+    ) -> std::os::raw::c_int;
+}
+
+/// TraceLogCallback handed to raylib, formats the message and dispatches it
+unsafe extern "C" fn trace_log_callback(
+    log_level: std::os::raw::c_int,
+    text: *const std::os::raw::c_char,
+    args: VaList,
+) {
+    let mut buffer = [0 as std::os::raw::c_char; MAX_TRACELOG_MSG_LENGTH];
+    if vsnprintf(buffer.as_mut_ptr(), buffer.len(), text, args) < 0 {
+        return;
+    }
+    buffer[MAX_TRACELOG_MSG_LENGTH - 1] = 0;
+    let message = std::ffi::CStr::from_ptr(buffer.as_ptr()).to_string_lossy();
+    let log_level = TraceLogLevel::try_from(log_level).unwrap_or(TraceLogLevel::None);
+
+    // Cloned out of the lock so the callback can replace itself
+    let callback = TRACE_LOG_CALLBACK
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone();
+    // Unwinding into raylib is undefined behavior, the panic message was already printed
+    let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| match callback {
+        Some(callback) => callback(log_level, &message),
+        #[cfg(feature = "log")]
+        None => forward_trace_log(log_level, &message),
+        #[cfg(not(feature = "log"))]
+        None => {}
+    }));
+
+    // TraceLog() skips its exit on LOG_FATAL when a callback is set
+    if log_level == TraceLogLevel::Fatal {
+        std::process::exit(1);
+    }
+}
+
+/// Forward a raylib message to the `log` crate at the matching level
+#[cfg(feature = "log")]
+fn forward_trace_log(log_level: TraceLogLevel, message: &str) {
+    let level = match log_level {
+        TraceLogLevel::All | TraceLogLevel::Trace => log::Level::Trace,
+        TraceLogLevel::Debug => log::Level::Debug,
+        TraceLogLevel::Info => log::Level::Info,
+        TraceLogLevel::Warning => log::Level::Warn,
+        TraceLogLevel::Error | TraceLogLevel::Fatal => log::Level::Error,
+        TraceLogLevel::None => return,
+    };
+    log::log!(target: "raylib", level, "{}", message);
+}
+
+/// Route raylib output into the `log` crate, called before the window or audio device is
+/// initialized and by the trace log functions
+#[cfg(feature = "log")]
+pub(crate) fn install_log_forwarding() {
+    unsafe {
+        raylib_ffi::SetTraceLogCallback(Some(trace_log_callback));
+    }
+}
+
+/// Set custom trace log, the message is formatted before it is passed to `callback`
+///
+/// Only messages at or above the level set with `set_trace_log_level()` are passed. The
+/// callback may set or reset the trace log itself, a panic in it is caught and the message
+/// dropped. Like raylib's default output, a `TraceLogLevel::Fatal` message exits the process
+/// (with status 1) once the callback returns.
+pub fn set_trace_log_callback<F>(callback: F)
+where
+    F: Fn(TraceLogLevel, &str) + Send + Sync + 'static,
+{
+    *TRACE_LOG_CALLBACK
+        .write()
+        .unwrap_or_else(|e| e.into_inner()) = Some(Arc::new(callback));
+    unsafe {
+        raylib_ffi::SetTraceLogCallback(Some(trace_log_callback));
+    }
+}
+
+/// Remove the custom trace log, restoring raylib's default output
+///
+/// With the `log` feature enabled, output is forwarded to the `log` crate instead.
+pub fn reset_trace_log_callback() {
+    *TRACE_LOG_CALLBACK
+        .write()
+        .unwrap_or_else(|e| e.into_inner()) = None;
+    #[cfg(feature = "log")]
+    install_log_forwarding();
+    #[cfg(not(feature = "log"))]
     unsafe {
-        raylib_ffi::SetTraceLogCallback(callback);
+        raylib_ffi::SetTraceLogCallback(None);
     }
 }
 
 // Files management functions (continued)
 
 /// Load file data as a byte array (read).
-pub fn load_file_data(file_name: &str) -> Option<Vec<u8>> {
+pub fn load_file_data(file_name: &str) -> crate::Result<Vec<u8>> {
+    let c_file_name = check_file(file_name, &[])?;
     unsafe {
         let mut data_size = 0;
-        let data_ptr = raylib_ffi::LoadFileData(raylib_ffi::rl_str!(file_name), &mut data_size);
+        let data_ptr = raylib_ffi::LoadFileData(c_file_name.as_ptr(), &mut data_size);
         if data_ptr.is_null() {
-            None
+            Err(Error::load(file_name, LoadError::InvalidData))
         } else {
             let data = std::slice::from_raw_parts(data_ptr, data_size as usize).to_vec();
             raylib_ffi::UnloadFileData(data_ptr);
-            Some(data)
+            Ok(data)
         }
     }
 }
@@ -724,250 +1531,129 @@ pub fn decompress_data(comp_data: &[u8]) -> Option<Vec<u8>> {
     }
 }
 
-/// Unload automation events list from file.
-pub fn unload_automation_event_list(mut list: crate::AutomationEventList) {
-    unsafe {
-        raylib_ffi::UnloadAutomationEventList(&mut list);
-    }
-}
-
 /// Export automation events list as a text file.
-pub fn export_automation_event_list(list: crate::AutomationEventList, file_name: &str) -> bool {
-    unsafe { raylib_ffi::ExportAutomationEventList(list, raylib_ffi::rl_str!(file_name)) }
+///
+/// Returns false without writing if an event has an unknown type.
+pub fn export_automation_event_list(list: &AutomationEvents, file_name: &str) -> bool {
+    // ExportAutomationEventList() indexes its type names with the event type
+    if list.iter().any(|event| event.event_type().is_err()) {
+        return false;
+    }
+    unsafe { raylib_ffi::ExportAutomationEventList(list.as_raw(), raylib_ffi::rl_str!(file_name)) }
 }
 
 /// Set automation event list to record to.
-pub fn set_automation_event_list(list: &mut crate::AutomationEventList) {
-    unsafe {
-        raylib_ffi::SetAutomationEventList(list);
-    }
+///
+/// # Safety
+/// raylib keeps the pointer: `list` must stay valid and in place until another list is set
+/// or recording is stopped. `automation::Recorder` does this safely.
+pub unsafe fn set_automation_event_list(
+    _rl: &RaylibHandle,
+    list: *mut raylib_ffi::AutomationEventList,
+) {
+    raylib_ffi::SetAutomationEventList(list);
 }
 
 /// Set automation event internal base frame to start recording.
-pub fn set_automation_event_base_frame(frame: i32) {
+pub fn set_automation_event_base_frame(_rl: &RaylibHandle, frame: i32) {
     unsafe {
         raylib_ffi::SetAutomationEventBaseFrame(frame);
     }
 }
 
 /// Start recording automation events (AutomationEventList must be set).
-pub fn start_automation_event_recording() {
-    unsafe {
-        raylib_ffi::StartAutomationEventRecording();
-    }
+///
+/// # Safety
+/// A list must have been set with `set_automation_event_list()` and stay valid while
+/// recording. `automation::Recorder` does this safely.
+pub unsafe fn start_automation_event_recording(_rl: &RaylibHandle) {
+    raylib_ffi::StartAutomationEventRecording();
 }
 
 /// Stop recording automation events.
-pub fn stop_automation_event_recording() {
+pub fn stop_automation_event_recording(_rl: &RaylibHandle) {
     unsafe {
         raylib_ffi::StopAutomationEventRecording();
     }
 }
 
 /// Play a recorded automation event.
-pub fn play_automation_event(event: crate::AutomationEvent) {
+///
+/// Fails with `Error::InvalidAutomationEvent` without playing it if raylib can not play
+/// the event, see `AutomationEvent::validate()`.
+pub fn play_automation_event(_rl: &RaylibHandle, event: AutomationEvent) -> crate::Result<()> {
+    event.validate()?;
     unsafe {
-        raylib_ffi::PlayAutomationEvent(event);
+        raylib_ffi::PlayAutomationEvent(event.as_raw());
     }
+    Ok(())
 }
 
 /// Load automation events list from file, None for an empty list.
-pub fn load_automation_event_list(file_name: &str) -> crate::AutomationEventList {
-    unsafe { raylib_ffi::LoadAutomationEventList(raylib_ffi::rl_str!(file_name)) }
+pub fn load_automation_event_list(file_name: Option<&str>) -> crate::Result<AutomationEvents> {
+    let c_file_name = file_name.map(|f| check_file(f, &[])).transpose()?;
+    Ok(unsafe {
+        AutomationEvents::from_raw(raylib_ffi::LoadAutomationEventList(
+            c_file_name
+                .as_ref()
+                .map_or(std::ptr::null(), |f| f.as_ptr()),
+        ))
+    })
 }
 
 // Input-related functions: keyboard
-/// Helper function for converting from integer to KeyboardKey
-pub fn integer_to_keyboard_key(num: i32) -> crate::enums::KeyboardKey {
-    use crate::enums::KeyboardKey;
-    match num {
-        0 => KeyboardKey::Null,
-        39 => KeyboardKey::Apostrophe,
-        44 => KeyboardKey::Comma,
-        45 => KeyboardKey::Minus,
-        46 => KeyboardKey::Period,
-        47 => KeyboardKey::Slash,
-        48 => KeyboardKey::Zero,
-        49 => KeyboardKey::One,
-        50 => KeyboardKey::Two,
-        51 => KeyboardKey::Three,
-        52 => KeyboardKey::Four,
-        53 => KeyboardKey::Five,
-        54 => KeyboardKey::Six,
-        55 => KeyboardKey::Seven,
-        56 => KeyboardKey::Eight,
-        57 => KeyboardKey::Nine,
-        59 => KeyboardKey::Semicolon,
-        61 => KeyboardKey::Equal,
-        65 => KeyboardKey::A,
-        66 => KeyboardKey::B,
-        67 => KeyboardKey::C,
-        68 => KeyboardKey::D,
-        69 => KeyboardKey::E,
-        70 => KeyboardKey::F,
-        71 => KeyboardKey::G,
-        72 => KeyboardKey::H,
-        73 => KeyboardKey::I,
-        74 => KeyboardKey::J,
-        75 => KeyboardKey::K,
-        76 => KeyboardKey::L,
-        77 => KeyboardKey::M,
-        78 => KeyboardKey::N,
-        79 => KeyboardKey::O,
-        80 => KeyboardKey::P,
-        81 => KeyboardKey::Q,
-        82 => KeyboardKey::R,
-        83 => KeyboardKey::S,
-        84 => KeyboardKey::T,
-        85 => KeyboardKey::U,
-        86 => KeyboardKey::V,
-        87 => KeyboardKey::W,
-        88 => KeyboardKey::X,
-        89 => KeyboardKey::Y,
-        90 => KeyboardKey::Z,
-        91 => KeyboardKey::LeftBracket,
-        92 => KeyboardKey::Backslash,
-        93 => KeyboardKey::RightBracket,
-        96 => KeyboardKey::Grave,
-        32 => KeyboardKey::Space,
-        256 => KeyboardKey::Escape,
-        257 => KeyboardKey::Enter,
-        258 => KeyboardKey::Tab,
-        259 => KeyboardKey::Backspace,
-        260 => KeyboardKey::Insert,
-        261 => KeyboardKey::Delete,
-        262 => KeyboardKey::Right,
-        263 => KeyboardKey::Left,
-        264 => KeyboardKey::Down,
-        265 => KeyboardKey::Up,
-        266 => KeyboardKey::PageUp,
-        267 => KeyboardKey::PageDown,
-        268 => KeyboardKey::Home,
-        269 => KeyboardKey::End,
-        280 => KeyboardKey::CapsLock,
-        281 => KeyboardKey::ScrollLock,
-        282 => KeyboardKey::NumLock,
-        283 => KeyboardKey::PrintScreen,
-        284 => KeyboardKey::Pause,
-        290 => KeyboardKey::F1,
-        291 => KeyboardKey::F2,
-        292 => KeyboardKey::F3,
-        293 => KeyboardKey::F4,
-        294 => KeyboardKey::F5,
-        295 => KeyboardKey::F6,
-        296 => KeyboardKey::F7,
-        297 => KeyboardKey::F8,
-        298 => KeyboardKey::F9,
-        299 => KeyboardKey::F10,
-        300 => KeyboardKey::F11,
-        301 => KeyboardKey::F12,
-        340 => KeyboardKey::LeftShift,
-        341 => KeyboardKey::LeftControl,
-        342 => KeyboardKey::LeftAlt,
-        343 => KeyboardKey::LeftSuper,
-        344 => KeyboardKey::RightShift,
-        345 => KeyboardKey::RightControl,
-        346 => KeyboardKey::RightAlt,
-        347 => KeyboardKey::RightSuper,
-        348 => KeyboardKey::KbMenu,
-        320 => KeyboardKey::Kp0,
-        321 => KeyboardKey::Kp1,
-        322 => KeyboardKey::Kp2,
-        323 => KeyboardKey::Kp3,
-        324 => KeyboardKey::Kp4,
-        325 => KeyboardKey::Kp5,
-        326 => KeyboardKey::Kp6,
-        327 => KeyboardKey::Kp7,
-        328 => KeyboardKey::Kp8,
-        329 => KeyboardKey::Kp9,
-        330 => KeyboardKey::KpDecimal,
-        331 => KeyboardKey::KpDivide,
-        332 => KeyboardKey::KpMultiply,
-        333 => KeyboardKey::KpSubtract,
-        334 => KeyboardKey::KpAdd,
-        335 => KeyboardKey::KpEnter,
-        336 => KeyboardKey::KpEqual,
-        4 => KeyboardKey::Back,
-        24 => KeyboardKey::VolumeUp,
-        25 => KeyboardKey::VolumeDown,
-        _ => KeyboardKey::Null,
-    }
-}
-
 /// Check if a key has been pressed once
-pub fn is_key_pressed(key: crate::enums::KeyboardKey) -> bool {
+pub fn is_key_pressed(_rl: &RaylibHandle, key: crate::enums::KeyboardKey) -> bool {
     unsafe { raylib_ffi::IsKeyPressed(key as i32) }
 }
 
 /// Check if a key has been pressed again (Only PLATFORM_DESKTOP).
-pub fn is_key_pressed_repeat(key: crate::enums::KeyboardKey) -> bool {
+pub fn is_key_pressed_repeat(_rl: &RaylibHandle, key: crate::enums::KeyboardKey) -> bool {
     unsafe { raylib_ffi::IsKeyPressedRepeat(key as i32) }
 }
 
 /// Check if a key is being pressed.
-pub fn is_key_down(key: crate::enums::KeyboardKey) -> bool {
+pub fn is_key_down(_rl: &RaylibHandle, key: crate::enums::KeyboardKey) -> bool {
     unsafe { raylib_ffi::IsKeyDown(key as i32) }
 }
 
 /// Check if a key has been released once.
-pub fn is_key_released(key: crate::enums::KeyboardKey) -> bool {
+pub fn is_key_released(_rl: &RaylibHandle, key: crate::enums::KeyboardKey) -> bool {
     unsafe { raylib_ffi::IsKeyReleased(key as i32) }
 }
 
 /// Check if a key is NOT being pressed.
-pub fn is_key_up(key: crate::enums::KeyboardKey) -> bool {
+pub fn is_key_up(_rl: &RaylibHandle, key: crate::enums::KeyboardKey) -> bool {
     unsafe { raylib_ffi::IsKeyUp(key as i32) }
 }
 
-/// Get key pressed (keycode), call it multiple times for keys queued, returns 0 when the queue is empty.
-pub fn get_key_pressed() -> crate::enums::KeyboardKey {
-    unsafe { integer_to_keyboard_key(raylib_ffi::GetKeyPressed()) }
+/// Get key pressed (keycode), call it multiple times for keys queued, returns None when the queue is empty.
+///
+/// Keycodes without a `KeyboardKey` variant (F13-F25, WORLD_1/2...) are returned as
+/// `Error::UnknownEnumValue`, they are still removed from the queue.
+pub fn get_key_pressed(_rl: &RaylibHandle) -> Option<crate::Result<crate::enums::KeyboardKey>> {
+    let key = unsafe { raylib_ffi::GetKeyPressed() };
+    (key != 0).then(|| crate::enums::KeyboardKey::try_from(key))
 }
 
 /// Get char pressed (unicode), call it multiple times for chars queued, returns 0 when the queue is empty.
-pub fn get_char_pressed() -> i32 {
+pub fn get_char_pressed(_rl: &RaylibHandle) -> i32 {
     unsafe { raylib_ffi::GetCharPressed() }
 }
 
 /// Set a custom key to exit program (default is ESC).
-pub fn set_exit_key(key: crate::enums::KeyboardKey) {
+pub fn set_exit_key(_rl: &RaylibHandle, key: crate::enums::KeyboardKey) {
     unsafe { raylib_ffi::SetExitKey(key as i32) }
 }
 
 // Input-related functions: gamepads
-pub fn integer_to_gamepad_button(num: i32) -> crate::enums::GamepadButton {
-    use crate::enums::GamepadButton;
-
-    match num {
-        0 => GamepadButton::Unknown,
-        1 => GamepadButton::LeftFaceUp,
-        2 => GamepadButton::LeftFaceRight,
-        3 => GamepadButton::LeftFaceDown,
-        4 => GamepadButton::LeftFaceLeft,
-        5 => GamepadButton::RightFaceUp,
-        6 => GamepadButton::RightFaceRight,
-        7 => GamepadButton::RightFaceDown,
-        8 => GamepadButton::RightFaceLeft,
-        9 => GamepadButton::LeftTrigger1,
-        10 => GamepadButton::LeftTrigger2,
-        11 => GamepadButton::RightTrigger1,
-        12 => GamepadButton::RightTrigger2,
-        13 => GamepadButton::MiddleLeft,
-        14 => GamepadButton::Middle,
-        15 => GamepadButton::MiddleRight,
-        16 => GamepadButton::LeftThumb,
-        17 => GamepadButton::RightThumb,
-        _ => GamepadButton::Unknown, // If the number doesn't match any variant, return Unknown
-    }
-}
-
 /// Check if a gamepad is available.
-pub fn is_gamepad_available(gamepad: i32) -> bool {
+pub fn is_gamepad_available(_rl: &RaylibHandle, gamepad: i32) -> bool {
     unsafe { raylib_ffi::IsGamepadAvailable(gamepad) }
 }
 
 /// Get gamepad internal name id.
-pub fn get_gamepad_name(gamepad: i32) -> Option<String> {
+pub fn get_gamepad_name(_rl: &RaylibHandle, gamepad: i32) -> Option<String> {
     use std::ffi::CStr;
     unsafe {
         let name_ptr = raylib_ffi::GetGamepadName(gamepad);
@@ -980,42 +1666,63 @@ pub fn get_gamepad_name(gamepad: i32) -> Option<String> {
 }
 
 /// Check if a gamepad button has been pressed once.
-pub fn is_gamepad_button_pressed(gamepad: i32, button: crate::enums::GamepadButton) -> bool {
+pub fn is_gamepad_button_pressed(
+    _rl: &RaylibHandle,
+    gamepad: i32,
+    button: crate::enums::GamepadButton,
+) -> bool {
     unsafe { raylib_ffi::IsGamepadButtonPressed(gamepad, button as i32) }
 }
 
 /// Check if a gamepad button is being pressed.
-pub fn is_gamepad_button_down(gamepad: i32, button: crate::enums::GamepadButton) -> bool {
+pub fn is_gamepad_button_down(
+    _rl: &RaylibHandle,
+    gamepad: i32,
+    button: crate::enums::GamepadButton,
+) -> bool {
     unsafe { raylib_ffi::IsGamepadButtonDown(gamepad, button as i32) }
 }
 
 /// Check if a gamepad button has been released once.
-pub fn is_gamepad_button_released(gamepad: i32, button: crate::enums::GamepadButton) -> bool {
+pub fn is_gamepad_button_released(
+    _rl: &RaylibHandle,
+    gamepad: i32,
+    button: crate::enums::GamepadButton,
+) -> bool {
     unsafe { raylib_ffi::IsGamepadButtonReleased(gamepad, button as i32) }
 }
 
 /// Check if a gamepad button is NOT being pressed.
-pub fn is_gamepad_button_up(gamepad: i32, button: crate::enums::GamepadButton) -> bool {
+pub fn is_gamepad_button_up(
+    _rl: &RaylibHandle,
+    gamepad: i32,
+    button: crate::enums::GamepadButton,
+) -> bool {
     unsafe { raylib_ffi::IsGamepadButtonUp(gamepad, button as i32) }
 }
 
 /// Get the last gamepad button pressed.
-pub fn get_gamepad_button_pressed() -> crate::enums::GamepadButton {
-    unsafe { integer_to_gamepad_button(raylib_ffi::GetGamepadButtonPressed()) }
+pub fn get_gamepad_button_pressed(_rl: &RaylibHandle) -> crate::enums::GamepadButton {
+    let button = unsafe { raylib_ffi::GetGamepadButtonPressed() };
+    crate::enums::GamepadButton::try_from(button).unwrap_or(crate::enums::GamepadButton::Unknown)
 }
 
 /// Get gamepad axis count for a gamepad.
-pub fn get_gamepad_axis_count(gamepad: i32) -> i32 {
+pub fn get_gamepad_axis_count(_rl: &RaylibHandle, gamepad: i32) -> i32 {
     unsafe { raylib_ffi::GetGamepadAxisCount(gamepad) }
 }
 
 /// Get axis movement value for a gamepad axis.
-pub fn get_gamepad_axis_movement(gamepad: i32, axis: crate::enums::GamepadAxis) -> f32 {
+pub fn get_gamepad_axis_movement(
+    _rl: &RaylibHandle,
+    gamepad: i32,
+    axis: crate::enums::GamepadAxis,
+) -> f32 {
     unsafe { raylib_ffi::GetGamepadAxisMovement(gamepad, axis as i32) }
 }
 
 /// Set internal gamepad mappings (SDL_GameControllerDB).
-pub fn set_gamepad_mappings(mappings: &str) -> i32 {
+pub fn set_gamepad_mappings(_rl: &RaylibHandle, mappings: &str) -> i32 {
     unsafe {
         let mappings_cstring = CString::new(mappings).expect("CString::new failed");
         raylib_ffi::SetGamepadMappings(mappings_cstring.as_ptr())
@@ -1024,169 +1731,195 @@ pub fn set_gamepad_mappings(mappings: &str) -> i32 {
 
 // Input-related functions: mouse
 /// Check if a mouse button has been pressed once
-pub fn is_mouse_button_pressed(button: MouseButton) -> bool {
+pub fn is_mouse_button_pressed(_rl: &RaylibHandle, button: MouseButton) -> bool {
     unsafe { raylib_ffi::IsMouseButtonPressed(button as i32) }
 }
 
 /// Check if a mouse button is being pressed
-pub fn is_mouse_button_down(button: MouseButton) -> bool {
+pub fn is_mouse_button_down(_rl: &RaylibHandle, button: MouseButton) -> bool {
     unsafe { raylib_ffi::IsMouseButtonDown(button as i32) }
 }
 
 /// Check if a mouse button has been released once
-pub fn is_mouse_button_released(button: MouseButton) -> bool {
+pub fn is_mouse_button_released(_rl: &RaylibHandle, button: MouseButton) -> bool {
     unsafe { raylib_ffi::IsMouseButtonReleased(button as i32) }
 }
 
 /// Check if a mouse button is NOT being pressed
-pub fn is_mouse_button_up(button: MouseButton) -> bool {
+pub fn is_mouse_button_up(_rl: &RaylibHandle, button: MouseButton) -> bool {
     unsafe { raylib_ffi::IsMouseButtonUp(button as i32) }
 }
 
 /// Get mouse position X
-pub fn get_mouse_x() -> i32 {
+pub fn get_mouse_x(_rl: &RaylibHandle) -> i32 {
     unsafe { raylib_ffi::GetMouseX() }
 }
 
 /// Get mouse position Y
-pub fn get_mouse_y() -> i32 {
+pub fn get_mouse_y(_rl: &RaylibHandle) -> i32 {
     unsafe { raylib_ffi::GetMouseY() }
 }
 
 /// Get mouse position XY
-pub fn get_mouse_position() -> Vector2 {
-    unsafe { raylib_ffi::GetMousePosition() }
+pub fn get_mouse_position(_rl: &RaylibHandle) -> Vector2 {
+    unsafe { raylib_ffi::GetMousePosition().into() }
 }
 
 /// Get mouse delta between frames
-pub fn get_mouse_delta() -> Vector2 {
-    unsafe { raylib_ffi::GetMouseDelta() }
+pub fn get_mouse_delta(_rl: &RaylibHandle) -> Vector2 {
+    unsafe { raylib_ffi::GetMouseDelta().into() }
 }
 
 /// Set mouse position XY
-pub fn set_mouse_position(x: i32, y: i32) {
+pub fn set_mouse_position(_rl: &RaylibHandle, x: i32, y: i32) {
     unsafe { raylib_ffi::SetMousePosition(x, y) }
 }
 
 /// Set mouse offset
-pub fn set_mouse_offset(offset_x: i32, offset_y: i32) {
+pub fn set_mouse_offset(_rl: &RaylibHandle, offset_x: i32, offset_y: i32) {
     unsafe { raylib_ffi::SetMouseOffset(offset_x, offset_y) }
 }
 
 /// Set mouse scaling
-pub fn set_mouse_scale(scale_x: f32, scale_y: f32) {
+pub fn set_mouse_scale(_rl: &RaylibHandle, scale_x: f32, scale_y: f32) {
     unsafe { raylib_ffi::SetMouseScale(scale_x, scale_y) }
 }
 
 /// Get mouse wheel movement for X or Y, whichever is larger
-pub fn get_mouse_wheel_move() -> f32 {
+pub fn get_mouse_wheel_move(_rl: &RaylibHandle) -> f32 {
     unsafe { raylib_ffi::GetMouseWheelMove() }
 }
 
 /// Get mouse wheel movement for both X and Y
-pub fn get_mouse_wheel_move_v() -> Vector2 {
-    unsafe { raylib_ffi::GetMouseWheelMoveV() }
+pub fn get_mouse_wheel_move_v(_rl: &RaylibHandle) -> Vector2 {
+    unsafe { raylib_ffi::GetMouseWheelMoveV().into() }
 }
 
 /// Set mouse cursor
-pub fn set_mouse_cursor(cursor: i32) {
-    unsafe { raylib_ffi::SetMouseCursor(cursor) }
+pub fn set_mouse_cursor(_rl: &RaylibHandle, cursor: MouseCursor) {
+    unsafe { raylib_ffi::SetMouseCursor(cursor as i32) }
 }
 
 // Input-related functions: touch
 /// Get touch position X for touch point 0 (relative to screen size)
-pub fn get_touch_x() -> i32 {
+pub fn get_touch_x(_rl: &RaylibHandle) -> i32 {
     unsafe { raylib_ffi::GetTouchX() }
 }
 
 /// Get touch position Y for touch point 0 (relative to screen size)
-pub fn get_touch_y() -> i32 {
+pub fn get_touch_y(_rl: &RaylibHandle) -> i32 {
     unsafe { raylib_ffi::GetTouchY() }
 }
 
 /// Get touch position XY for a touch point index (relative to screen size)
-pub fn get_touch_position(index: i32) -> Vector2 {
-    unsafe { raylib_ffi::GetTouchPosition(index) }
+pub fn get_touch_position(_rl: &RaylibHandle, index: i32) -> Vector2 {
+    unsafe { raylib_ffi::GetTouchPosition(index).into() }
 }
 
 /// Get touch point identifier for given index
-pub fn get_touch_point_id(index: i32) -> i32 {
+pub fn get_touch_point_id(_rl: &RaylibHandle, index: i32) -> i32 {
     unsafe { raylib_ffi::GetTouchPointId(index) }
 }
 
 /// Get number of touch points
-pub fn get_touch_point_count() -> i32 {
+pub fn get_touch_point_count(_rl: &RaylibHandle) -> i32 {
     unsafe { raylib_ffi::GetTouchPointCount() }
 }
 
 // Gestures and Touch Handling Functions (Module: rgestures)
-/// Helper function to convert integer to gesture
-pub fn integer_to_gesture(num: i32) -> crate::enums::Gesture {
-    use crate::enums::Gesture;
-    match num {
-        0 => Gesture::None,
-        1 => Gesture::Tap,
-        2 => Gesture::Doubletap,
-        4 => Gesture::Hold,
-        8 => Gesture::Drag,
-        16 => Gesture::SwipeRight,
-        32 => Gesture::SwipeLeft,
-        64 => Gesture::SwipeUp,
-        128 => Gesture::SwipeDown,
-        256 => Gesture::PinchIn,
-        512 => Gesture::PinchOut,
-        _ => Gesture::None, // If the number doesn't match any variant, return None
-    }
-}
 /// Enable a set of gestures using flags
-pub fn set_gestures_enabled(flags: u32) {
+pub fn set_gestures_enabled(_rl: &RaylibHandle, flags: u32) {
     unsafe { raylib_ffi::SetGesturesEnabled(flags) }
 }
 
 /// Check if a gesture has been detected
-pub fn is_gesture_detected(gesture: crate::enums::Gesture) -> bool {
+pub fn is_gesture_detected(_rl: &RaylibHandle, gesture: crate::enums::Gesture) -> bool {
     unsafe { raylib_ffi::IsGestureDetected(gesture as u32) }
 }
 
 /// Get latest detected gesture
-pub fn get_gesture_detected() -> crate::enums::Gesture {
-    unsafe { integer_to_gesture(raylib_ffi::GetGestureDetected()) }
+pub fn get_gesture_detected(_rl: &RaylibHandle) -> crate::enums::Gesture {
+    let gesture = unsafe { raylib_ffi::GetGestureDetected() };
+    crate::enums::Gesture::try_from(gesture).unwrap_or(crate::enums::Gesture::None)
 }
 
 /// Get gesture hold time in milliseconds
-pub fn get_gesture_hold_duration() -> f32 {
+pub fn get_gesture_hold_duration(_rl: &RaylibHandle) -> f32 {
     unsafe { raylib_ffi::GetGestureHoldDuration() }
 }
 
 /// Get gesture drag vector
-pub fn get_gesture_drag_vector() -> Vector2 {
-    unsafe { raylib_ffi::GetGestureDragVector() }
+pub fn get_gesture_drag_vector(_rl: &RaylibHandle) -> Vector2 {
+    unsafe { raylib_ffi::GetGestureDragVector().into() }
 }
 
 /// Get gesture drag angle
-pub fn get_gesture_drag_angle() -> f32 {
+pub fn get_gesture_drag_angle(_rl: &RaylibHandle) -> f32 {
     unsafe { raylib_ffi::GetGestureDragAngle() }
 }
 
 /// Get gesture pinch delta
-pub fn get_gesture_pinch_vector() -> Vector2 {
-    unsafe { raylib_ffi::GetGesturePinchVector() }
+pub fn get_gesture_pinch_vector(_rl: &RaylibHandle) -> Vector2 {
+    unsafe { raylib_ffi::GetGesturePinchVector().into() }
 }
 
 /// Get gesture pinch angle
-pub fn get_gesture_pinch_angle() -> f32 {
+pub fn get_gesture_pinch_angle(_rl: &RaylibHandle) -> f32 {
     unsafe { raylib_ffi::GetGesturePinchAngle() }
 }
 
 // Camera System Functions
 /// Update camera position for selected mode
-pub fn update_camera(camera: &mut crate::Camera, mode: crate::enums::CameraMode) {
-    unsafe { raylib_ffi::UpdateCamera(camera, mode as i32) }
+pub fn update_camera(
+    _rl: &RaylibHandle,
+    camera: &mut crate::Camera,
+    mode: crate::enums::CameraMode,
+) {
+    unsafe {
+        raylib_ffi::UpdateCamera(
+            camera as *mut Camera as *mut raylib_ffi::Camera,
+            mode as i32,
+        )
+    }
 }
 
 /// Update camera movement/rotation
-pub fn update_camera_pro(camera: &mut Camera, movement: Vector3, rotation: Vector3, zoom: f32) {
+pub fn update_camera_pro(
+    _rl: &RaylibHandle,
+    camera: &mut Camera,
+    movement: Vector3,
+    rotation: Vector3,
+    zoom: f32,
+) {
     unsafe {
-        raylib_ffi::UpdateCameraPro(camera as *mut Camera, movement, rotation, zoom);
+        raylib_ffi::UpdateCameraPro(
+            camera as *mut Camera as *mut raylib_ffi::Camera,
+            movement.into(),
+            rotation.into(),
+            zoom,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn window_builder_flags_accumulate() {
+        let builder = WindowBuilder::new()
+            .resizable(true)
+            .config_flags(ConfigFlags::WINDOW_HIDDEN)
+            .config_flags(ConfigFlags::MSAA_4X_HINT)
+            .vsync(true);
+        assert_eq!(
+            builder.flags,
+            ConfigFlags::WINDOW_RESIZABLE
+                | ConfigFlags::WINDOW_HIDDEN
+                | ConfigFlags::MSAA_4X_HINT
+                | ConfigFlags::VSYNC_HINT
+        );
+        let builder = builder.resizable(false);
+        assert!(!builder.flags.contains(ConfigFlags::WINDOW_RESIZABLE));
     }
 }
@@ -0,0 +1,458 @@
+//! Deterministic input replay for regression tests
+//!
+//! `Replay` runs a game for a number of frames with a fixed frame time, feeding it recorded
+//! automation events, and collects a hash of the game state after every frame. Two runs of
+//! the same events must give the same hashes, `ReplayRun::first_divergence()` reports the
+//! first frame where they do not.
+//!
+//! `Replay::run_mock()` runs headless on a `MockPlatform`, `Replay::run_window()` plays the
+//! events through raylib and should be given a hidden window (`ConfigFlags::WINDOW_HIDDEN`).
+
+use std::hash::{Hash, Hasher};
+
+use crate::automation::{AutomationEvent, Player};
+use crate::enums::{GamepadAxis, GamepadButton, KeyboardKey, MouseButton};
+use crate::platform::{MockPlatform, Platform};
+use crate::rcore::{self, RaylibHandle};
+use crate::Vector2;
+
+/// Game logic run by a `Replay`
+pub trait ReplayGame {
+    /// Run the game logic for one frame, reading input and frame time from `platform`
+    fn frame<P: Platform>(&mut self, platform: &mut P);
+    /// Hash of the game state, compared between runs (see `state_hash()`)
+    fn state_hash(&self) -> u64;
+}
+
+/// Hash a value with 64-bit FNV-1a, which gives the same result on every run, Rust version
+/// and platform
+pub fn state_hash<T: Hash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = Fnv1a::default();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// 64-bit FNV-1a, integers are hashed as little endian and `usize` as `u64`
+struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for Fnv1a {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+}
+
+/// Find the first frame where two runs have a different state hash
+///
+/// If one run stopped earlier, the frame after its last frame diverges.
+pub fn first_divergence(a: &[u64], b: &[u64]) -> Option<usize> {
+    a.iter()
+        .zip(b)
+        .position(|(a, b)| a != b)
+        .or_else(|| (a.len() != b.len()).then(|| a.len().min(b.len())))
+}
+
+/// State hashes of a replay run, one per frame
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ReplayRun {
+    hashes: Vec<u64>,
+}
+
+impl ReplayRun {
+    /// State hash after every frame that was run
+    pub fn hashes(&self) -> &[u64] {
+        &self.hashes
+    }
+
+    /// Number of frames run, less than requested if the window was closed
+    pub fn frames(&self) -> usize {
+        self.hashes.len()
+    }
+
+    /// State hash after the last frame
+    pub fn final_hash(&self) -> Option<u64> {
+        self.hashes.last().copied()
+    }
+
+    /// First frame where `self` and `other` have a different state hash
+    pub fn first_divergence(&self, other: &ReplayRun) -> Option<usize> {
+        first_divergence(&self.hashes, &other.hashes)
+    }
+}
+
+/// Replay settings: number of frames, frame time and (for mock runs) screen size
+#[derive(Debug, Clone)]
+pub struct Replay {
+    frames: u32,
+    frame_time: f32,
+    screen_size: (i32, i32),
+}
+
+impl Replay {
+    /// Create a replay of `frames` frames at 60 FPS on a 800x450 screen
+    pub fn new(frames: u32) -> Self {
+        Self {
+            frames,
+            frame_time: 1.0 / 60.0,
+            screen_size: (800, 450),
+        }
+    }
+
+    /// Set the time of every frame, returned by `get_frame_time()`
+    pub fn frame_time(mut self, frame_time: f32) -> Self {
+        self.frame_time = frame_time;
+        self
+    }
+
+    /// Set the screen size of mock runs
+    pub fn screen_size(mut self, width: i32, height: i32) -> Self {
+        self.screen_size = (width, height);
+        self
+    }
+
+    /// Run `game` on a `MockPlatform`, playing `events` (sorted by frame)
+    ///
+    /// Stops early if a `WindowClose` event is played.
+    pub fn run_mock<G: ReplayGame>(&self, game: &mut G, events: &[AutomationEvent]) -> ReplayRun {
+        let mut platform = MockPlatform::new(self.screen_size.0, self.screen_size.1);
+        platform.set_frame_time(self.frame_time);
+        let mut next = 0;
+        let mut run = ReplayRun::default();
+        for frame in 0..self.frames {
+            while let Some(event) = events.get(next).filter(|e| e.frame() <= frame) {
+                platform.play_automation_event(event);
+                next += 1;
+            }
+            if platform.window_should_close() {
+                break;
+            }
+            game.frame(&mut platform);
+            run.hashes.push(game.state_hash());
+            platform.next_frame();
+        }
+        run
+    }
+
+    /// Run `game` on the window, playing `events` (sorted by frame) through raylib
+    ///
+    /// Every frame ends with an empty BeginDrawing()/EndDrawing() pair to poll the input,
+    /// `get_frame_time()` and `get_time()` return the fixed replay time. Stops early if the
+    /// window should close. Fails with `Error::InvalidAutomationEvent` before running if
+    /// raylib can not play one of the events.
+    pub fn run_window<G: ReplayGame>(
+        &self,
+        rl: &mut RaylibHandle,
+        game: &mut G,
+        events: &[AutomationEvent],
+    ) -> crate::Result<ReplayRun> {
+        let mut player = Player::from_slice(events)?;
+        let mut run = ReplayRun::default();
+        // Accumulated like MockPlatform::next_frame() so both runs see the same times
+        let mut time = 0.0;
+        for _ in 0..self.frames {
+            player.play_frame(rl);
+            if rcore::window_should_close(rl) {
+                break;
+            }
+            let mut platform = FixedTime {
+                platform: &mut *rl,
+                frame_time: self.frame_time,
+                time,
+            };
+            game.frame(&mut platform);
+            run.hashes.push(game.state_hash());
+            drop(rcore::begin_drawing(rl));
+            time += self.frame_time as f64;
+        }
+        Ok(run)
+    }
+}
+
+/// Platform with a fixed frame time, other queries go to the wrapped platform
+struct FixedTime<'a, P> {
+    platform: &'a mut P,
+    frame_time: f32,
+    time: f64,
+}
+
+impl<P: Platform> Platform for FixedTime<'_, P> {
+    fn window_should_close(&self) -> bool {
+        self.platform.window_should_close()
+    }
+
+    fn is_window_fullscreen(&self) -> bool {
+        self.platform.is_window_fullscreen()
+    }
+
+    fn is_window_minimized(&self) -> bool {
+        self.platform.is_window_minimized()
+    }
+
+    fn is_window_focused(&self) -> bool {
+        self.platform.is_window_focused()
+    }
+
+    fn is_window_resized(&self) -> bool {
+        self.platform.is_window_resized()
+    }
+
+    fn get_screen_width(&self) -> i32 {
+        self.platform.get_screen_width()
+    }
+
+    fn get_screen_height(&self) -> i32 {
+        self.platform.get_screen_height()
+    }
+
+    fn get_render_width(&self) -> i32 {
+        self.platform.get_render_width()
+    }
+
+    fn get_render_height(&self) -> i32 {
+        self.platform.get_render_height()
+    }
+
+    fn get_window_scale_dpi(&self) -> Vector2 {
+        self.platform.get_window_scale_dpi()
+    }
+
+    fn get_frame_time(&self) -> f32 {
+        self.frame_time
+    }
+
+    fn get_time(&self) -> f64 {
+        self.time
+    }
+
+    fn get_fps(&self) -> i32 {
+        if self.frame_time > 0.0 {
+            (1.0 / self.frame_time).round() as i32
+        } else {
+            0
+        }
+    }
+
+    fn is_key_pressed(&self, key: KeyboardKey) -> bool {
+        self.platform.is_key_pressed(key)
+    }
+
+    fn is_key_down(&self, key: KeyboardKey) -> bool {
+        self.platform.is_key_down(key)
+    }
+
+    fn is_key_released(&self, key: KeyboardKey) -> bool {
+        self.platform.is_key_released(key)
+    }
+
+    fn is_key_up(&self, key: KeyboardKey) -> bool {
+        self.platform.is_key_up(key)
+    }
+
+    fn get_key_pressed(&mut self) -> Option<crate::Result<KeyboardKey>> {
+        self.platform.get_key_pressed()
+    }
+
+    fn get_char_pressed(&mut self) -> i32 {
+        self.platform.get_char_pressed()
+    }
+
+    fn is_gamepad_available(&self, gamepad: i32) -> bool {
+        self.platform.is_gamepad_available(gamepad)
+    }
+
+    fn is_gamepad_button_pressed(&self, gamepad: i32, button: GamepadButton) -> bool {
+        self.platform.is_gamepad_button_pressed(gamepad, button)
+    }
+
+    fn is_gamepad_button_down(&self, gamepad: i32, button: GamepadButton) -> bool {
+        self.platform.is_gamepad_button_down(gamepad, button)
+    }
+
+    fn is_gamepad_button_released(&self, gamepad: i32, button: GamepadButton) -> bool {
+        self.platform.is_gamepad_button_released(gamepad, button)
+    }
+
+    fn is_gamepad_button_up(&self, gamepad: i32, button: GamepadButton) -> bool {
+        self.platform.is_gamepad_button_up(gamepad, button)
+    }
+
+    fn get_gamepad_axis_count(&self, gamepad: i32) -> i32 {
+        self.platform.get_gamepad_axis_count(gamepad)
+    }
+
+    fn get_gamepad_axis_movement(&self, gamepad: i32, axis: GamepadAxis) -> f32 {
+        self.platform.get_gamepad_axis_movement(gamepad, axis)
+    }
+
+    fn is_mouse_button_pressed(&self, button: MouseButton) -> bool {
+        self.platform.is_mouse_button_pressed(button)
+    }
+
+    fn is_mouse_button_down(&self, button: MouseButton) -> bool {
+        self.platform.is_mouse_button_down(button)
+    }
+
+    fn is_mouse_button_released(&self, button: MouseButton) -> bool {
+        self.platform.is_mouse_button_released(button)
+    }
+
+    fn is_mouse_button_up(&self, button: MouseButton) -> bool {
+        self.platform.is_mouse_button_up(button)
+    }
+
+    fn get_mouse_position(&self) -> Vector2 {
+        self.platform.get_mouse_position()
+    }
+
+    fn get_mouse_delta(&self) -> Vector2 {
+        self.platform.get_mouse_delta()
+    }
+
+    fn get_mouse_wheel_move(&self) -> f32 {
+        self.platform.get_mouse_wheel_move()
+    }
+
+    fn get_mouse_wheel_move_v(&self) -> Vector2 {
+        self.platform.get_mouse_wheel_move_v()
+    }
+
+    fn get_touch_point_count(&self) -> i32 {
+        self.platform.get_touch_point_count()
+    }
+
+    fn get_touch_position(&self, index: i32) -> Vector2 {
+        self.platform.get_touch_position(index)
+    }
+
+    fn get_touch_point_id(&self, index: i32) -> i32 {
+        self.platform.get_touch_point_id(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Moves with the arrow keys and counts frames with the mouse button down
+    #[derive(Default, Hash)]
+    struct Walker {
+        position: (i32, i32),
+        clicks: u32,
+        time: u64,
+    }
+
+    impl ReplayGame for Walker {
+        fn frame<P: Platform>(&mut self, platform: &mut P) {
+            if platform.is_key_down(KeyboardKey::Right) {
+                self.position.0 += 1;
+            }
+            if platform.is_key_down(KeyboardKey::Down) {
+                self.position.1 += 1;
+            }
+            if platform.is_mouse_button_down(MouseButton::Left) {
+                self.clicks += 1;
+            }
+            self.time = platform.get_time().to_bits();
+        }
+
+        fn state_hash(&self) -> u64 {
+            state_hash(self)
+        }
+    }
+
+    fn events() -> Vec<AutomationEvent> {
+        vec![
+            AutomationEvent::key_down(2, KeyboardKey::Right),
+            AutomationEvent::mouse_button_down(3, MouseButton::Left),
+            AutomationEvent::key_up(5, KeyboardKey::Right),
+            AutomationEvent::mouse_button_up(6, MouseButton::Left),
+            AutomationEvent::key_down(8, KeyboardKey::Down),
+        ]
+    }
+
+    #[test]
+    fn fnv1a_known_values() {
+        let hash = |bytes: &[u8]| {
+            let mut hasher = Fnv1a::default();
+            hasher.write(bytes);
+            hasher.finish()
+        };
+        assert_eq!(hash(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(hash(b"a"), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(hash(b"foobar"), 0x8594_4171_f739_67e8);
+    }
+
+    #[test]
+    fn state_hash_is_fixed() {
+        let mut hasher = Fnv1a::default();
+        hasher.write(&[1, 0, 0, 0]);
+        assert_eq!(state_hash(&1u32), hasher.finish());
+        assert_eq!(state_hash(&1usize), state_hash(&1u64));
+        assert_ne!(state_hash("ab"), state_hash("ba"));
+    }
+
+    #[test]
+    fn same_events_do_not_diverge() {
+        let replay = Replay::new(12);
+        let a = replay.run_mock(&mut Walker::default(), &events());
+        let b = replay.run_mock(&mut Walker::default(), &events());
+        assert_eq!(a.frames(), 12);
+        assert_eq!(a, b);
+        assert_eq!(a.first_divergence(&b), None);
+    }
+
+    #[test]
+    fn perturbed_event_diverges() {
+        let replay = Replay::new(12);
+        let a = replay.run_mock(&mut Walker::default(), &events());
+        let mut perturbed = events();
+        perturbed[2] = AutomationEvent::key_up(4, KeyboardKey::Right);
+        let b = replay.run_mock(&mut Walker::default(), &perturbed);
+        assert_eq!(a.first_divergence(&b), Some(4));
+        assert_eq!(b.first_divergence(&a), Some(4));
+    }
+
+    #[test]
+    fn window_close_stops_the_run() {
+        let replay = Replay::new(12);
+        let a = replay.run_mock(&mut Walker::default(), &events());
+        let mut closed = events();
+        closed.push(AutomationEvent::window_close(9));
+        let b = replay.run_mock(&mut Walker::default(), &closed);
+        assert_eq!(b.frames(), 9);
+        assert_eq!(a.first_divergence(&b), Some(9));
+        assert_eq!(first_divergence(&[1, 2], &[1, 3, 4]), Some(1));
+        assert_eq!(first_divergence(&[], &[]), None);
+    }
+}
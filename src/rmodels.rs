@@ -0,0 +1,1195 @@
+use crate::enums::MaterialMapIndex;
+use crate::error::check_file;
+use crate::rcore::{is_window_open, rlGetShaderIdDefault, RaylibDraw, RaylibHandle};
+use crate::rtextures::rlGetTextureIdDefault;
+use crate::{BoundingBox, Camera, Color, MaterialMap, Matrix, Ray, Rectangle, Vector2, Vector3};
+use crate::{Error, Image, LoadError, Shader, Texture};
+
+/// Maximum number of shader maps supported (MAX_MATERIAL_MAPS)
+const MAX_MATERIAL_MAPS: usize = 12;
+
+/// Mesh vertex data stored in CPU memory (RAM) and GPU memory (VRAM), unloaded on drop
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct Mesh {
+    raw: raylib_ffi::Mesh,
+}
+
+impl Mesh {
+    /// Take ownership of a raw mesh, it will be unloaded on drop
+    ///
+    /// # Safety
+    /// `raw` must be a valid mesh allocated by raylib that is not owned elsewhere
+    pub unsafe fn from_raw(raw: raylib_ffi::Mesh) -> Self {
+        Self { raw }
+    }
+
+    /// Release ownership of the raw mesh, it will no longer be unloaded on drop
+    pub fn into_raw(self) -> raylib_ffi::Mesh {
+        let raw = self.raw;
+        std::mem::forget(self);
+        raw
+    }
+
+    /// Get a copy of the raw mesh, only valid while `self` is alive
+    pub fn as_raw(&self) -> raylib_ffi::Mesh {
+        self.raw
+    }
+
+    /// Number of vertices stored in arrays
+    pub fn vertex_count(&self) -> i32 {
+        self.raw.vertexCount
+    }
+
+    /// Number of triangles stored (indexed or not)
+    pub fn triangle_count(&self) -> i32 {
+        self.raw.triangleCount
+    }
+}
+
+impl Drop for Mesh {
+    fn drop(&mut self) {
+        // UnloadMesh() also releases the vertex buffers, which needs the OpenGL context
+        if is_window_open() {
+            unsafe { raylib_ffi::UnloadMesh(self.raw) }
+        }
+    }
+}
+
+/// Material with its shader and texture maps, unloaded on drop
+///
+/// A material owns the shader and textures set on it, except the default ones.
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct Material {
+    raw: raylib_ffi::Material,
+}
+
+impl Material {
+    /// Take ownership of a raw material, it will be unloaded on drop
+    ///
+    /// # Safety
+    /// `raw` must be a valid material loaded by raylib that is not owned elsewhere
+    pub unsafe fn from_raw(raw: raylib_ffi::Material) -> Self {
+        Self { raw }
+    }
+
+    /// Release ownership of the raw material, it will no longer be unloaded on drop
+    pub fn into_raw(self) -> raylib_ffi::Material {
+        let raw = self.raw;
+        std::mem::forget(self);
+        raw
+    }
+
+    /// Get a copy of the raw material, only valid while `self` is alive
+    pub fn as_raw(&self) -> raylib_ffi::Material {
+        self.raw
+    }
+
+    /// Material maps, indexed by `MaterialMapIndex`
+    pub fn maps(&self) -> &[MaterialMap] {
+        if self.raw.maps.is_null() {
+            return &[];
+        }
+        unsafe { std::slice::from_raw_parts(self.raw.maps, MAX_MATERIAL_MAPS) }
+    }
+
+    /// Set the color of a material map
+    pub fn set_map_color(&mut self, map_type: MaterialMapIndex, color: Color) {
+        if let Some(map) = self.map_mut(map_type) {
+            map.color = color;
+        }
+    }
+
+    /// Set the value of a material map
+    pub fn set_map_value(&mut self, map_type: MaterialMapIndex, value: f32) {
+        if let Some(map) = self.map_mut(map_type) {
+            map.value = value;
+        }
+    }
+
+    /// Material generic parameters (if required)
+    pub fn params(&self) -> [f32; 4] {
+        self.raw.params
+    }
+
+    fn map_mut(&mut self, map_type: MaterialMapIndex) -> Option<&mut MaterialMap> {
+        material_map_mut(&mut self.raw, map_type)
+    }
+}
+
+fn material_map_mut(
+    raw: &mut raylib_ffi::Material,
+    map_type: MaterialMapIndex,
+) -> Option<&mut MaterialMap> {
+    if raw.maps.is_null() {
+        return None;
+    }
+    let maps = unsafe { std::slice::from_raw_parts_mut(raw.maps, MAX_MATERIAL_MAPS) };
+    maps.get_mut(map_type as usize)
+}
+
+impl Drop for Material {
+    fn drop(&mut self) {
+        if is_window_open() {
+            unsafe { raylib_ffi::UnloadMaterial(self.raw) }
+        } else {
+            // Only the maps array lives in CPU memory
+            unsafe { raylib_ffi::MemFree(self.raw.maps as *mut std::ffi::c_void) }
+        }
+    }
+}
+
+/// Model with its meshes, materials and animation data, unloaded on drop
+///
+/// Like UnloadModel(), dropping a model does not unload the shaders and textures
+/// of its materials, they can be shared between models.
+#[derive(Debug)]
+pub struct Model {
+    raw: raylib_ffi::Model,
+}
+
+impl Model {
+    /// Take ownership of a raw model, it will be unloaded on drop
+    ///
+    /// # Safety
+    /// `raw` must be a valid model loaded by raylib that is not owned elsewhere
+    pub unsafe fn from_raw(raw: raylib_ffi::Model) -> Self {
+        Self { raw }
+    }
+
+    /// Release ownership of the raw model, it will no longer be unloaded on drop
+    pub fn into_raw(self) -> raylib_ffi::Model {
+        let raw = self.raw;
+        std::mem::forget(self);
+        raw
+    }
+
+    /// Get a copy of the raw model, only valid while `self` is alive
+    pub fn as_raw(&self) -> raylib_ffi::Model {
+        self.raw
+    }
+
+    /// Local transform matrix
+    pub fn transform(&self) -> Matrix {
+        self.raw.transform.into()
+    }
+
+    /// Set the local transform matrix
+    pub fn set_transform(&mut self, transform: Matrix) {
+        self.raw.transform = transform.into();
+    }
+
+    /// Meshes array
+    pub fn meshes(&self) -> &[Mesh] {
+        if self.raw.meshes.is_null() {
+            return &[];
+        }
+        // Mesh is repr(transparent) over the raw mesh
+        unsafe {
+            std::slice::from_raw_parts(self.raw.meshes as *const Mesh, self.raw.meshCount as usize)
+        }
+    }
+
+    /// Materials array
+    pub fn materials(&self) -> &[Material] {
+        if self.raw.materials.is_null() {
+            return &[];
+        }
+        // Material is repr(transparent) over the raw material
+        unsafe {
+            std::slice::from_raw_parts(
+                self.raw.materials as *const Material,
+                self.raw.materialCount as usize,
+            )
+        }
+    }
+
+    /// Mutable access to the material at `index`, None if out of range
+    pub fn material_mut(&mut self, index: usize) -> Option<MaterialRef<'_>> {
+        if self.raw.materials.is_null() || index >= self.raw.materialCount as usize {
+            return None;
+        }
+        Some(MaterialRef {
+            raw: unsafe { &mut *self.raw.materials.add(index) },
+        })
+    }
+
+    /// Mesh material number, one per mesh
+    pub fn mesh_material(&self) -> &[i32] {
+        if self.raw.meshMaterial.is_null() {
+            return &[];
+        }
+        unsafe { std::slice::from_raw_parts(self.raw.meshMaterial, self.raw.meshCount as usize) }
+    }
+}
+
+impl Drop for Model {
+    fn drop(&mut self) {
+        if is_window_open() {
+            unsafe { raylib_ffi::UnloadModel(self.raw) }
+        }
+    }
+}
+
+/// Material of a model, borrowed from the model that owns it
+///
+/// Gives access to the material maps without moving the material out of the model.
+#[derive(Debug)]
+pub struct MaterialRef<'a> {
+    raw: &'a mut raylib_ffi::Material,
+}
+
+impl MaterialRef<'_> {
+    /// Material maps, indexed by `MaterialMapIndex`
+    pub fn maps(&self) -> &[MaterialMap] {
+        if self.raw.maps.is_null() {
+            return &[];
+        }
+        unsafe { std::slice::from_raw_parts(self.raw.maps, MAX_MATERIAL_MAPS) }
+    }
+
+    /// Set the color of a material map
+    pub fn set_map_color(&mut self, map_type: MaterialMapIndex, color: Color) {
+        if let Some(map) = material_map_mut(self.raw, map_type) {
+            map.color = color;
+        }
+    }
+
+    /// Set the value of a material map
+    pub fn set_map_value(&mut self, map_type: MaterialMapIndex, value: f32) {
+        if let Some(map) = material_map_mut(self.raw, map_type) {
+            map.value = value;
+        }
+    }
+
+    /// Set the texture of a material map
+    ///
+    /// The model does not own the texture (UnloadModel() keeps the textures of its
+    /// materials), it must outlive the draws of the model.
+    pub fn set_map_texture(&mut self, map_type: MaterialMapIndex, texture: &Texture) {
+        if let Some(map) = material_map_mut(self.raw, map_type) {
+            map.texture = texture.as_raw();
+        }
+    }
+
+    /// Material generic parameters (if required)
+    pub fn params(&self) -> [f32; 4] {
+        self.raw.params
+    }
+}
+
+/// Model animation data stored in CPU memory (RAM), unloaded on drop
+#[derive(Debug)]
+pub struct ModelAnimation {
+    raw: raylib_ffi::ModelAnimation,
+}
+
+impl ModelAnimation {
+    /// Get a copy of the raw animation, only valid while `self` is alive
+    pub fn as_raw(&self) -> raylib_ffi::ModelAnimation {
+        self.raw
+    }
+
+    /// Number of bones
+    pub fn bone_count(&self) -> i32 {
+        self.raw.boneCount
+    }
+
+    /// Number of animation frames
+    pub fn frame_count(&self) -> i32 {
+        self.raw.frameCount
+    }
+
+    /// Animation name
+    pub fn name(&self) -> String {
+        let name = unsafe { std::ffi::CStr::from_ptr(self.raw.name.as_ptr()) };
+        name.to_string_lossy().into_owned()
+    }
+}
+
+impl Drop for ModelAnimation {
+    fn drop(&mut self) {
+        unsafe { raylib_ffi::UnloadModelAnimation(self.raw) }
+    }
+}
+
+/// Ray hit information, returned by the GetRayCollision*() functions when the ray hits
+#[derive(Debug, Clone, Copy)]
+pub struct RayCollision {
+    /// Distance to the nearest hit
+    pub distance: f32,
+    /// Point of the nearest hit
+    pub point: Vector3,
+    /// Surface normal of hit
+    pub normal: Vector3,
+}
+
+impl RayCollision {
+    fn from_raw(raw: raylib_ffi::RayCollision) -> Option<Self> {
+        raw.hit.then_some(Self {
+            distance: raw.distance,
+            point: raw.point.into(),
+            normal: raw.normal.into(),
+        })
+    }
+}
+
+/// Model file types accepted by LoadModel()
+const MODEL_FILE_EXTENSIONS: &[&str] = &[".obj", ".iqm", ".gltf", ".glb", ".vox", ".m3d"];
+
+/// Model file types accepted by LoadModelAnimations()
+const ANIMATION_FILE_EXTENSIONS: &[&str] = &[".iqm", ".gltf", ".glb", ".m3d"];
+
+// Basic geometric 3D shapes drawing functions
+/// Draw a line in 3D world space
+pub fn draw_line_3d(_d: &mut impl RaylibDraw, start_pos: Vector3, end_pos: Vector3, color: Color) {
+    unsafe { raylib_ffi::DrawLine3D(start_pos.into(), end_pos.into(), color) }
+}
+
+/// Draw a point in 3D space, actually a small line
+pub fn draw_point_3d(_d: &mut impl RaylibDraw, position: Vector3, color: Color) {
+    unsafe { raylib_ffi::DrawPoint3D(position.into(), color) }
+}
+
+/// Draw a circle in 3D world space
+pub fn draw_circle_3d(
+    _d: &mut impl RaylibDraw,
+    center: Vector3,
+    radius: f32,
+    rotation_axis: Vector3,
+    rotation_angle: f32,
+    color: Color,
+) {
+    unsafe {
+        raylib_ffi::DrawCircle3D(
+            center.into(),
+            radius,
+            rotation_axis.into(),
+            rotation_angle,
+            color,
+        )
+    }
+}
+
+/// Draw a color-filled triangle (vertex in counter-clockwise order!)
+pub fn draw_triangle_3d(
+    _d: &mut impl RaylibDraw,
+    v1: Vector3,
+    v2: Vector3,
+    v3: Vector3,
+    color: Color,
+) {
+    unsafe { raylib_ffi::DrawTriangle3D(v1.into(), v2.into(), v3.into(), color) }
+}
+
+/// Draw a triangle strip defined by points
+pub fn draw_triangle_strip_3d(_d: &mut impl RaylibDraw, points: &[Vector3], color: Color) {
+    // The points are only read, the pointer is mut in the C signature only
+    unsafe {
+        raylib_ffi::DrawTriangleStrip3D(
+            points.as_ptr() as *mut raylib_ffi::Vector3,
+            points.len() as i32,
+            color,
+        )
+    }
+}
+
+/// Draw cube
+pub fn draw_cube(
+    _d: &mut impl RaylibDraw,
+    position: Vector3,
+    width: f32,
+    height: f32,
+    length: f32,
+    color: Color,
+) {
+    unsafe { raylib_ffi::DrawCube(position.into(), width, height, length, color) }
+}
+
+/// Draw cube (Vector version)
+pub fn draw_cube_v(_d: &mut impl RaylibDraw, position: Vector3, size: Vector3, color: Color) {
+    unsafe { raylib_ffi::DrawCubeV(position.into(), size.into(), color) }
+}
+
+/// Draw cube wires
+pub fn draw_cube_wires(
+    _d: &mut impl RaylibDraw,
+    position: Vector3,
+    width: f32,
+    height: f32,
+    length: f32,
+    color: Color,
+) {
+    unsafe { raylib_ffi::DrawCubeWires(position.into(), width, height, length, color) }
+}
+
+/// Draw cube wires (Vector version)
+pub fn draw_cube_wires_v(_d: &mut impl RaylibDraw, position: Vector3, size: Vector3, color: Color) {
+    unsafe { raylib_ffi::DrawCubeWiresV(position.into(), size.into(), color) }
+}
+
+/// Draw sphere
+pub fn draw_sphere(_d: &mut impl RaylibDraw, center_pos: Vector3, radius: f32, color: Color) {
+    unsafe { raylib_ffi::DrawSphere(center_pos.into(), radius, color) }
+}
+
+/// Draw sphere with extended parameters
+pub fn draw_sphere_ex(
+    _d: &mut impl RaylibDraw,
+    center_pos: Vector3,
+    radius: f32,
+    rings: i32,
+    slices: i32,
+    color: Color,
+) {
+    unsafe { raylib_ffi::DrawSphereEx(center_pos.into(), radius, rings, slices, color) }
+}
+
+/// Draw sphere wires
+pub fn draw_sphere_wires(
+    _d: &mut impl RaylibDraw,
+    center_pos: Vector3,
+    radius: f32,
+    rings: i32,
+    slices: i32,
+    color: Color,
+) {
+    unsafe { raylib_ffi::DrawSphereWires(center_pos.into(), radius, rings, slices, color) }
+}
+
+/// Draw a cylinder/cone
+pub fn draw_cylinder(
+    _d: &mut impl RaylibDraw,
+    position: Vector3,
+    radius_top: f32,
+    radius_bottom: f32,
+    height: f32,
+    slices: i32,
+    color: Color,
+) {
+    unsafe {
+        raylib_ffi::DrawCylinder(
+            position.into(),
+            radius_top,
+            radius_bottom,
+            height,
+            slices,
+            color,
+        )
+    }
+}
+
+/// Draw a cylinder with base at startPos and top at endPos
+pub fn draw_cylinder_ex(
+    _d: &mut impl RaylibDraw,
+    start_pos: Vector3,
+    end_pos: Vector3,
+    start_radius: f32,
+    end_radius: f32,
+    sides: i32,
+    color: Color,
+) {
+    unsafe {
+        raylib_ffi::DrawCylinderEx(
+            start_pos.into(),
+            end_pos.into(),
+            start_radius,
+            end_radius,
+            sides,
+            color,
+        )
+    }
+}
+
+/// Draw a cylinder/cone wires
+pub fn draw_cylinder_wires(
+    _d: &mut impl RaylibDraw,
+    position: Vector3,
+    radius_top: f32,
+    radius_bottom: f32,
+    height: f32,
+    slices: i32,
+    color: Color,
+) {
+    unsafe {
+        raylib_ffi::DrawCylinderWires(
+            position.into(),
+            radius_top,
+            radius_bottom,
+            height,
+            slices,
+            color,
+        )
+    }
+}
+
+/// Draw a cylinder wires with base at startPos and top at endPos
+pub fn draw_cylinder_wires_ex(
+    _d: &mut impl RaylibDraw,
+    start_pos: Vector3,
+    end_pos: Vector3,
+    start_radius: f32,
+    end_radius: f32,
+    sides: i32,
+    color: Color,
+) {
+    unsafe {
+        raylib_ffi::DrawCylinderWiresEx(
+            start_pos.into(),
+            end_pos.into(),
+            start_radius,
+            end_radius,
+            sides,
+            color,
+        )
+    }
+}
+
+/// Draw a capsule with the center of its sphere caps at startPos and endPos
+pub fn draw_capsule(
+    _d: &mut impl RaylibDraw,
+    start_pos: Vector3,
+    end_pos: Vector3,
+    radius: f32,
+    slices: i32,
+    rings: i32,
+    color: Color,
+) {
+    unsafe {
+        raylib_ffi::DrawCapsule(
+            start_pos.into(),
+            end_pos.into(),
+            radius,
+            slices,
+            rings,
+            color,
+        )
+    }
+}
+
+/// Draw capsule wireframe with the center of its sphere caps at startPos and endPos
+pub fn draw_capsule_wires(
+    _d: &mut impl RaylibDraw,
+    start_pos: Vector3,
+    end_pos: Vector3,
+    radius: f32,
+    slices: i32,
+    rings: i32,
+    color: Color,
+) {
+    unsafe {
+        raylib_ffi::DrawCapsuleWires(
+            start_pos.into(),
+            end_pos.into(),
+            radius,
+            slices,
+            rings,
+            color,
+        )
+    }
+}
+
+/// Draw a plane XZ
+pub fn draw_plane(_d: &mut impl RaylibDraw, center_pos: Vector3, size: Vector2, color: Color) {
+    unsafe { raylib_ffi::DrawPlane(center_pos.into(), size.into(), color) }
+}
+
+/// Draw a ray line
+pub fn draw_ray(_d: &mut impl RaylibDraw, ray: Ray, color: Color) {
+    unsafe { raylib_ffi::DrawRay(ray.into(), color) }
+}
+
+/// Draw a grid (centered at (0, 0, 0))
+pub fn draw_grid(_d: &mut impl RaylibDraw, slices: i32, spacing: f32) {
+    unsafe { raylib_ffi::DrawGrid(slices, spacing) }
+}
+
+// Model management functions
+/// Load model from files (meshes and materials)
+pub fn load_model(_rl: &RaylibHandle, file_name: &str) -> crate::Result<Model> {
+    let c_file_name = check_file(file_name, MODEL_FILE_EXTENSIONS)?;
+    let model = unsafe { raylib_ffi::LoadModel(c_file_name.as_ptr()) };
+    if model.meshCount == 0 || model.meshes.is_null() {
+        unsafe { raylib_ffi::UnloadModel(model) };
+        return Err(Error::load(file_name, LoadError::InvalidData));
+    }
+    Ok(Model { raw: model })
+}
+
+/// Load model from generated mesh (default material), the model takes ownership of the mesh
+pub fn load_model_from_mesh(_rl: &RaylibHandle, mesh: Mesh) -> Model {
+    Model {
+        raw: unsafe { raylib_ffi::LoadModelFromMesh(mesh.into_raw()) },
+    }
+}
+
+/// Check if a model is ready
+pub fn is_model_ready(model: &Model) -> bool {
+    unsafe { raylib_ffi::IsModelReady(model.raw) }
+}
+
+/// Compute model bounding box limits (considers all meshes)
+pub fn get_model_bounding_box(model: &Model) -> BoundingBox {
+    unsafe { raylib_ffi::GetModelBoundingBox(model.raw).into() }
+}
+
+// Model drawing functions
+/// Draw a model (with texture if set)
+pub fn draw_model(
+    _d: &mut impl RaylibDraw,
+    model: &Model,
+    position: Vector3,
+    scale: f32,
+    tint: Color,
+) {
+    unsafe { raylib_ffi::DrawModel(model.raw, position.into(), scale, tint) }
+}
+
+/// Draw a model with extended parameters
+pub fn draw_model_ex(
+    _d: &mut impl RaylibDraw,
+    model: &Model,
+    position: Vector3,
+    rotation_axis: Vector3,
+    rotation_angle: f32,
+    scale: Vector3,
+    tint: Color,
+) {
+    unsafe {
+        raylib_ffi::DrawModelEx(
+            model.raw,
+            position.into(),
+            rotation_axis.into(),
+            rotation_angle,
+            scale.into(),
+            tint,
+        )
+    }
+}
+
+/// Draw a model wires (with texture if set)
+pub fn draw_model_wires(
+    _d: &mut impl RaylibDraw,
+    model: &Model,
+    position: Vector3,
+    scale: f32,
+    tint: Color,
+) {
+    unsafe { raylib_ffi::DrawModelWires(model.raw, position.into(), scale, tint) }
+}
+
+/// Draw a model wires (with texture if set) with extended parameters
+pub fn draw_model_wires_ex(
+    _d: &mut impl RaylibDraw,
+    model: &Model,
+    position: Vector3,
+    rotation_axis: Vector3,
+    rotation_angle: f32,
+    scale: Vector3,
+    tint: Color,
+) {
+    unsafe {
+        raylib_ffi::DrawModelWiresEx(
+            model.raw,
+            position.into(),
+            rotation_axis.into(),
+            rotation_angle,
+            scale.into(),
+            tint,
+        )
+    }
+}
+
+/// Draw bounding box (wires)
+pub fn draw_bounding_box(_d: &mut impl RaylibDraw, box_: BoundingBox, color: Color) {
+    unsafe { raylib_ffi::DrawBoundingBox(box_.into(), color) }
+}
+
+/// Draw a billboard texture
+pub fn draw_billboard(
+    _d: &mut impl RaylibDraw,
+    camera: Camera,
+    texture: &Texture,
+    position: Vector3,
+    size: f32,
+    tint: Color,
+) {
+    unsafe {
+        raylib_ffi::DrawBillboard(camera.into(), texture.as_raw(), position.into(), size, tint)
+    }
+}
+
+/// Draw a billboard texture defined by source
+pub fn draw_billboard_rec(
+    _d: &mut impl RaylibDraw,
+    camera: Camera,
+    texture: &Texture,
+    source: Rectangle,
+    position: Vector3,
+    size: Vector2,
+    tint: Color,
+) {
+    unsafe {
+        raylib_ffi::DrawBillboardRec(
+            camera.into(),
+            texture.as_raw(),
+            source,
+            position.into(),
+            size.into(),
+            tint,
+        )
+    }
+}
+
+/// Draw a billboard texture defined by source and rotation
+#[allow(clippy::too_many_arguments)]
+pub fn draw_billboard_pro(
+    _d: &mut impl RaylibDraw,
+    camera: Camera,
+    texture: &Texture,
+    source: Rectangle,
+    position: Vector3,
+    up: Vector3,
+    size: Vector2,
+    origin: Vector2,
+    rotation: f32,
+    tint: Color,
+) {
+    unsafe {
+        raylib_ffi::DrawBillboardPro(
+            camera.into(),
+            texture.as_raw(),
+            source,
+            position.into(),
+            up.into(),
+            size.into(),
+            origin.into(),
+            rotation,
+            tint,
+        )
+    }
+}
+
+// Mesh management functions
+/// Draw a 3d mesh with material and transform
+pub fn draw_mesh(_d: &mut impl RaylibDraw, mesh: &Mesh, material: &Material, transform: Matrix) {
+    unsafe { raylib_ffi::DrawMesh(mesh.raw, material.raw, transform.into()) }
+}
+
+/// Draw multiple mesh instances with material and different transforms
+pub fn draw_mesh_instanced(
+    _d: &mut impl RaylibDraw,
+    mesh: &Mesh,
+    material: &Material,
+    transforms: &[Matrix],
+) {
+    unsafe {
+        raylib_ffi::DrawMeshInstanced(
+            mesh.raw,
+            material.raw,
+            transforms.as_ptr() as *const raylib_ffi::Matrix,
+            transforms.len() as i32,
+        )
+    }
+}
+
+/// Export mesh data to file, returns true on success
+pub fn export_mesh(mesh: &Mesh, file_name: &str) -> bool {
+    unsafe { raylib_ffi::ExportMesh(mesh.raw, raylib_ffi::rl_str!(file_name)) }
+}
+
+/// Compute mesh tangents
+pub fn gen_mesh_tangents(_rl: &RaylibHandle, mesh: &mut Mesh) {
+    unsafe { raylib_ffi::GenMeshTangents(&mut mesh.raw) }
+}
+
+// Mesh generation functions
+/// Generate polygonal mesh
+pub fn gen_mesh_poly(_rl: &RaylibHandle, sides: i32, radius: f32) -> Mesh {
+    Mesh {
+        raw: unsafe { raylib_ffi::GenMeshPoly(sides, radius) },
+    }
+}
+
+/// Generate plane mesh (with subdivisions)
+pub fn gen_mesh_plane(_rl: &RaylibHandle, width: f32, length: f32, res_x: i32, res_z: i32) -> Mesh {
+    Mesh {
+        raw: unsafe { raylib_ffi::GenMeshPlane(width, length, res_x, res_z) },
+    }
+}
+
+/// Generate cuboid mesh
+pub fn gen_mesh_cube(_rl: &RaylibHandle, width: f32, height: f32, length: f32) -> Mesh {
+    Mesh {
+        raw: unsafe { raylib_ffi::GenMeshCube(width, height, length) },
+    }
+}
+
+/// Generate sphere mesh (standard sphere)
+pub fn gen_mesh_sphere(_rl: &RaylibHandle, radius: f32, rings: i32, slices: i32) -> Mesh {
+    Mesh {
+        raw: unsafe { raylib_ffi::GenMeshSphere(radius, rings, slices) },
+    }
+}
+
+/// Generate half-sphere mesh (no bottom cap)
+pub fn gen_mesh_hemi_sphere(_rl: &RaylibHandle, radius: f32, rings: i32, slices: i32) -> Mesh {
+    Mesh {
+        raw: unsafe { raylib_ffi::GenMeshHemiSphere(radius, rings, slices) },
+    }
+}
+
+/// Generate cylinder mesh
+pub fn gen_mesh_cylinder(_rl: &RaylibHandle, radius: f32, height: f32, slices: i32) -> Mesh {
+    Mesh {
+        raw: unsafe { raylib_ffi::GenMeshCylinder(radius, height, slices) },
+    }
+}
+
+/// Generate cone/pyramid mesh
+pub fn gen_mesh_cone(_rl: &RaylibHandle, radius: f32, height: f32, slices: i32) -> Mesh {
+    Mesh {
+        raw: unsafe { raylib_ffi::GenMeshCone(radius, height, slices) },
+    }
+}
+
+/// Generate torus mesh
+pub fn gen_mesh_torus(
+    _rl: &RaylibHandle,
+    radius: f32,
+    size: f32,
+    rad_seg: i32,
+    sides: i32,
+) -> Mesh {
+    Mesh {
+        raw: unsafe { raylib_ffi::GenMeshTorus(radius, size, rad_seg, sides) },
+    }
+}
+
+/// Generate trefoil knot mesh
+pub fn gen_mesh_knot(_rl: &RaylibHandle, radius: f32, size: f32, rad_seg: i32, sides: i32) -> Mesh {
+    Mesh {
+        raw: unsafe { raylib_ffi::GenMeshKnot(radius, size, rad_seg, sides) },
+    }
+}
+
+/// Generate heightmap mesh from image data
+pub fn gen_mesh_heightmap(_rl: &RaylibHandle, heightmap: &Image, size: Vector3) -> Mesh {
+    Mesh {
+        raw: unsafe { raylib_ffi::GenMeshHeightmap(heightmap.as_raw(), size.into()) },
+    }
+}
+
+/// Generate cubes-based map mesh from image data
+pub fn gen_mesh_cubicmap(_rl: &RaylibHandle, cubicmap: &Image, cube_size: Vector3) -> Mesh {
+    Mesh {
+        raw: unsafe { raylib_ffi::GenMeshCubicmap(cubicmap.as_raw(), cube_size.into()) },
+    }
+}
+
+// Material loading/unloading functions
+/// Load materials from model file
+pub fn load_materials(_rl: &RaylibHandle, file_name: &str) -> crate::Result<Vec<Material>> {
+    let c_file_name = check_file(file_name, &[".mtl"])?;
+    unsafe {
+        let mut material_count = 0;
+        let materials_ptr = raylib_ffi::LoadMaterials(c_file_name.as_ptr(), &mut material_count);
+        if materials_ptr.is_null() {
+            return Err(Error::load(file_name, LoadError::InvalidData));
+        }
+        // Each material owns its maps, only the array itself is released here
+        let materials = std::slice::from_raw_parts(materials_ptr, material_count as usize)
+            .iter()
+            .map(|&raw| Material { raw })
+            .collect();
+        raylib_ffi::MemFree(materials_ptr as *mut std::ffi::c_void);
+        Ok(materials)
+    }
+}
+
+/// Load default material (Supports: DIFFUSE, SPECULAR, NORMAL maps)
+pub fn load_material_default(_rl: &RaylibHandle) -> Material {
+    Material {
+        raw: unsafe { raylib_ffi::LoadMaterialDefault() },
+    }
+}
+
+/// Check if a material is ready
+pub fn is_material_ready(material: &Material) -> bool {
+    unsafe { raylib_ffi::IsMaterialReady(material.raw) }
+}
+
+/// Set texture for a material map type (MATERIAL_MAP_DIFFUSE, MATERIAL_MAP_SPECULAR...)
+///
+/// The material takes ownership of the texture, the previous one is unloaded unless it is the default.
+pub fn set_material_texture(material: &mut Material, map_type: MaterialMapIndex, texture: Texture) {
+    let Some(map) = material.map_mut(map_type) else {
+        return;
+    };
+    let previous = map.texture;
+    unsafe {
+        if previous.id != rlGetTextureIdDefault() && previous.id != texture.id() {
+            raylib_ffi::UnloadTexture(previous);
+        }
+        raylib_ffi::SetMaterialTexture(&mut material.raw, map_type as i32, texture.into_raw());
+    }
+}
+
+/// Set shader for a material
+///
+/// The material takes ownership of the shader, the previous one is unloaded unless it is the default.
+pub fn set_material_shader(material: &mut Material, shader: Shader) {
+    let previous = material.raw.shader;
+    unsafe {
+        if previous.id != rlGetShaderIdDefault() && previous.id != shader.id() {
+            raylib_ffi::UnloadShader(previous);
+        }
+    }
+    material.raw.shader = shader.into_raw();
+}
+
+/// Set material for a mesh, returns false if either index is out of range
+pub fn set_model_mesh_material(model: &mut Model, mesh_id: usize, material_id: usize) -> bool {
+    if model.raw.meshMaterial.is_null()
+        || mesh_id >= model.raw.meshCount as usize
+        || material_id >= model.raw.materialCount as usize
+    {
+        return false;
+    }
+    unsafe { raylib_ffi::SetModelMeshMaterial(&mut model.raw, mesh_id as i32, material_id as i32) }
+    true
+}
+
+// Model animations loading/unloading functions
+/// Load model animations from file
+pub fn load_model_animations(file_name: &str) -> crate::Result<Vec<ModelAnimation>> {
+    let c_file_name = check_file(file_name, ANIMATION_FILE_EXTENSIONS)?;
+    unsafe {
+        let mut anim_count = 0;
+        let anims_ptr = raylib_ffi::LoadModelAnimations(c_file_name.as_ptr(), &mut anim_count);
+        if anims_ptr.is_null() {
+            return Err(Error::load(file_name, LoadError::InvalidData));
+        }
+        // Each animation owns its bones and poses, only the array itself is released here
+        let anims = std::slice::from_raw_parts(anims_ptr, anim_count as usize)
+            .iter()
+            .map(|&raw| ModelAnimation { raw })
+            .collect();
+        raylib_ffi::MemFree(anims_ptr as *mut std::ffi::c_void);
+        Ok(anims)
+    }
+}
+
+/// Update model animation pose
+///
+/// Returns false without updating if `frame` is negative or the animation skeleton does not
+/// match the model, frames past the end wrap around.
+pub fn update_model_animation(model: &mut Model, anim: &ModelAnimation, frame: i32) -> bool {
+    // UpdateModelAnimation() indexes the frame poses with the frame and the mesh bone ids
+    if frame < 0 || !is_model_animation_valid(model, anim) {
+        return false;
+    }
+    unsafe { raylib_ffi::UpdateModelAnimation(model.raw, anim.raw, frame) }
+    true
+}
+
+/// Check model animation skeleton match
+pub fn is_model_animation_valid(model: &Model, anim: &ModelAnimation) -> bool {
+    unsafe { raylib_ffi::IsModelAnimationValid(model.raw, anim.raw) }
+}
+
+// Collision detection functions
+/// Check collision between two spheres
+pub fn check_collision_spheres(
+    center1: Vector3,
+    radius1: f32,
+    center2: Vector3,
+    radius2: f32,
+) -> bool {
+    unsafe { raylib_ffi::CheckCollisionSpheres(center1.into(), radius1, center2.into(), radius2) }
+}
+
+/// Check collision between two bounding boxes
+pub fn check_collision_boxes(box1: BoundingBox, box2: BoundingBox) -> bool {
+    unsafe { raylib_ffi::CheckCollisionBoxes(box1.into(), box2.into()) }
+}
+
+/// Check collision between box and sphere
+pub fn check_collision_box_sphere(box_: BoundingBox, center: Vector3, radius: f32) -> bool {
+    unsafe { raylib_ffi::CheckCollisionBoxSphere(box_.into(), center.into(), radius) }
+}
+
+/// Get collision info between ray and sphere, None if the ray misses
+pub fn get_ray_collision_sphere(ray: Ray, center: Vector3, radius: f32) -> Option<RayCollision> {
+    RayCollision::from_raw(unsafe {
+        raylib_ffi::GetRayCollisionSphere(ray.into(), center.into(), radius)
+    })
+}
+
+/// Get collision info between ray and box, None if the ray misses
+pub fn get_ray_collision_box(ray: Ray, box_: BoundingBox) -> Option<RayCollision> {
+    RayCollision::from_raw(unsafe { raylib_ffi::GetRayCollisionBox(ray.into(), box_.into()) })
+}
+
+/// Get collision info between ray and mesh, None if the ray misses
+pub fn get_ray_collision_mesh(ray: Ray, mesh: &Mesh, transform: Matrix) -> Option<RayCollision> {
+    RayCollision::from_raw(unsafe {
+        raylib_ffi::GetRayCollisionMesh(ray.into(), mesh.raw, transform.into())
+    })
+}
+
+/// Get collision info between ray and triangle, None if the ray misses
+pub fn get_ray_collision_triangle(
+    ray: Ray,
+    p1: Vector3,
+    p2: Vector3,
+    p3: Vector3,
+) -> Option<RayCollision> {
+    RayCollision::from_raw(unsafe {
+        raylib_ffi::GetRayCollisionTriangle(ray.into(), p1.into(), p2.into(), p3.into())
+    })
+}
+
+/// Get collision info between ray and quad, None if the ray misses
+pub fn get_ray_collision_quad(
+    ray: Ray,
+    p1: Vector3,
+    p2: Vector3,
+    p3: Vector3,
+    p4: Vector3,
+) -> Option<RayCollision> {
+    RayCollision::from_raw(unsafe {
+        raylib_ffi::GetRayCollisionQuad(ray.into(), p1.into(), p2.into(), p3.into(), p4.into())
+    })
+}
+
+/// Compute mesh bounding box limits
+pub fn get_mesh_bounding_box(mesh: &Mesh) -> BoundingBox {
+    unsafe { raylib_ffi::GetMeshBoundingBox(mesh.raw).into() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ray(x: f32, y: f32, direction_z: f32) -> Ray {
+        Ray {
+            position: Vector3::new(x, y, -5.0),
+            direction: Vector3::new(0.0, 0.0, direction_z),
+        }
+    }
+
+    fn unit_box(center: Vector3) -> BoundingBox {
+        BoundingBox {
+            min: Vector3::new(center.x - 1.0, center.y - 1.0, center.z - 1.0),
+            max: Vector3::new(center.x + 1.0, center.y + 1.0, center.z + 1.0),
+        }
+    }
+
+    #[test]
+    fn sphere_collisions() {
+        let origin = Vector3::new(0.0, 0.0, 0.0);
+        assert!(check_collision_spheres(
+            origin,
+            1.0,
+            Vector3::new(1.5, 0.0, 0.0),
+            1.0
+        ));
+        assert!(check_collision_spheres(
+            origin,
+            1.0,
+            Vector3::new(0.0, 2.0, 0.0),
+            1.0
+        ));
+        assert!(!check_collision_spheres(
+            origin,
+            1.0,
+            Vector3::new(3.0, 0.0, 0.0),
+            1.0
+        ));
+    }
+
+    #[test]
+    fn box_collisions() {
+        let a = unit_box(Vector3::new(0.0, 0.0, 0.0));
+        assert!(check_collision_boxes(
+            a,
+            unit_box(Vector3::new(1.5, 1.5, 0.0))
+        ));
+        assert!(check_collision_boxes(
+            a,
+            unit_box(Vector3::new(0.0, 0.0, 2.0))
+        ));
+        assert!(!check_collision_boxes(
+            a,
+            unit_box(Vector3::new(0.0, 3.0, 0.0))
+        ));
+
+        assert!(check_collision_box_sphere(
+            a,
+            Vector3::new(2.0, 0.0, 0.0),
+            1.5
+        ));
+        assert!(!check_collision_box_sphere(
+            a,
+            Vector3::new(2.0, 0.0, 0.0),
+            0.5
+        ));
+        assert!(!check_collision_box_sphere(
+            a,
+            Vector3::new(2.0, 2.0, 0.0),
+            1.2
+        ));
+    }
+
+    #[test]
+    fn ray_sphere_collision() {
+        let center = Vector3::new(0.0, 0.0, 0.0);
+        let hit = get_ray_collision_sphere(ray(0.0, 0.0, 1.0), center, 1.0).unwrap();
+        assert_eq!(hit.distance, 4.0);
+        assert_eq!(hit.point, Vector3::new(0.0, 0.0, -1.0));
+        assert_eq!(hit.normal, Vector3::new(0.0, 0.0, -1.0));
+        assert!(get_ray_collision_sphere(ray(2.0, 0.0, 1.0), center, 1.0).is_none());
+    }
+
+    #[test]
+    fn ray_box_collision() {
+        let box_ = unit_box(Vector3::new(0.0, 0.0, 0.0));
+        let hit = get_ray_collision_box(ray(0.0, 0.0, 1.0), box_).unwrap();
+        assert_eq!(hit.distance, 4.0);
+        assert_eq!(hit.point, Vector3::new(0.0, 0.0, -1.0));
+        assert_eq!(hit.normal, Vector3::new(0.0, 0.0, -1.0));
+        assert!(get_ray_collision_box(ray(3.0, 0.0, 1.0), box_).is_none());
+        assert!(get_ray_collision_box(ray(0.0, 0.0, -1.0), box_).is_none());
+    }
+
+    #[test]
+    fn ray_triangle_collision() {
+        let p1 = Vector3::new(-1.0, -1.0, 0.0);
+        let p2 = Vector3::new(1.0, -1.0, 0.0);
+        let p3 = Vector3::new(0.0, 1.0, 0.0);
+        let hit = get_ray_collision_triangle(ray(0.0, 0.0, 1.0), p1, p2, p3).unwrap();
+        assert_eq!(hit.distance, 5.0);
+        assert_eq!(hit.point, Vector3::new(0.0, 0.0, 0.0));
+        assert_eq!(hit.normal, Vector3::new(0.0, 0.0, 1.0));
+        assert!(get_ray_collision_triangle(ray(0.9, 0.9, 1.0), p1, p2, p3).is_none());
+        assert!(get_ray_collision_triangle(ray(0.0, 0.0, -1.0), p1, p2, p3).is_none());
+    }
+
+    #[test]
+    fn ray_quad_collision() {
+        let p1 = Vector3::new(-1.0, -1.0, 0.0);
+        let p2 = Vector3::new(1.0, -1.0, 0.0);
+        let p3 = Vector3::new(1.0, 1.0, 0.0);
+        let p4 = Vector3::new(-1.0, 1.0, 0.0);
+        // One point in each of the two triangles of the quad
+        for (x, y) in [(-0.5, -0.5), (0.9, 0.9)] {
+            let hit = get_ray_collision_quad(ray(x, y, 1.0), p1, p2, p3, p4).unwrap();
+            assert_eq!(hit.distance, 5.0);
+            assert_eq!(hit.point, Vector3::new(x, y, 0.0));
+        }
+        assert!(get_ray_collision_quad(ray(2.0, 0.0, 1.0), p1, p2, p3, p4).is_none());
+        assert!(get_ray_collision_quad(ray(0.0, 0.0, -1.0), p1, p2, p3, p4).is_none());
+    }
+}
@@ -1,66 +1,128 @@
+use std::marker::PhantomData;
+
+use crate::rcore::RaylibDraw;
 use crate::Color;
 use crate::Rectangle;
-use crate::Texture2D;
+use crate::Texture;
 use crate::Vector2;
 
+/// Texture used on shapes drawing, resets to the default white pixel on drop
+#[derive(Debug)]
+pub struct ShapesTexture<'a> {
+    _texture: PhantomData<&'a Texture>,
+}
+
+impl Drop for ShapesTexture<'_> {
+    fn drop(&mut self) {
+        let default_texture = raylib_ffi::Texture {
+            id: 0,
+            width: 0,
+            height: 0,
+            mipmaps: 0,
+            format: 0,
+        };
+        let default_source = Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: 0.0,
+            height: 0.0,
+        };
+        unsafe { raylib_ffi::SetShapesTexture(default_texture, default_source) }
+    }
+}
+
 /// Set texture and rectangle to be used on shapes drawing
-pub fn set_shapes_texture(texture: Texture2D, source: Rectangle) {
+///
+/// The texture stays borrowed until the returned guard is dropped.
+pub fn set_shapes_texture(texture: &Texture, source: Rectangle) -> ShapesTexture<'_> {
     unsafe {
-        raylib_ffi::SetShapesTexture(texture, source);
+        raylib_ffi::SetShapesTexture(texture.as_raw(), source);
+    }
+    ShapesTexture {
+        _texture: PhantomData,
     }
 }
 
 /// Draw a pixel
-pub fn draw_pixel(pos_x: i32, pos_y: i32, color: Color) {
+pub fn draw_pixel(_d: &mut impl RaylibDraw, pos_x: i32, pos_y: i32, color: Color) {
     unsafe {
         raylib_ffi::DrawPixel(pos_x, pos_y, color);
     }
 }
 
 /// Draw a pixel (Vector version)
-pub fn draw_pixel_v(position: Vector2, color: Color) {
+pub fn draw_pixel_v(_d: &mut impl RaylibDraw, position: Vector2, color: Color) {
     unsafe {
-        raylib_ffi::DrawPixelV(position, color);
+        raylib_ffi::DrawPixelV(position.into(), color);
     }
 }
 
 /// Draw a line
-pub fn draw_line(start_pos_x: i32, start_pos_y: i32, end_pos_x: i32, end_pos_y: i32, color: Color) {
+pub fn draw_line(
+    _d: &mut impl RaylibDraw,
+    start_pos_x: i32,
+    start_pos_y: i32,
+    end_pos_x: i32,
+    end_pos_y: i32,
+    color: Color,
+) {
     unsafe {
         raylib_ffi::DrawLine(start_pos_x, start_pos_y, end_pos_x, end_pos_y, color);
     }
 }
 
 /// Draw a line (using gl lines)
-pub fn draw_line_v(start_pos: Vector2, end_pos: Vector2, color: Color) {
+pub fn draw_line_v(_d: &mut impl RaylibDraw, start_pos: Vector2, end_pos: Vector2, color: Color) {
     unsafe {
-        raylib_ffi::DrawLineV(start_pos, end_pos, color);
+        raylib_ffi::DrawLineV(start_pos.into(), end_pos.into(), color);
     }
 }
 
 /// Draw a line (using triangles/quads)
-pub fn draw_line_ex(start_pos: Vector2, end_pos: Vector2, thick: f32, color: Color) {
+pub fn draw_line_ex(
+    _d: &mut impl RaylibDraw,
+    start_pos: Vector2,
+    end_pos: Vector2,
+    thick: f32,
+    color: Color,
+) {
     unsafe {
-        raylib_ffi::DrawLineEx(start_pos, end_pos, thick, color);
+        raylib_ffi::DrawLineEx(start_pos.into(), end_pos.into(), thick, color);
     }
 }
 
 /// Draw lines sequence (using gl lines)
-pub fn draw_line_strip(points: &[Vector2], color: Color) {
+pub fn draw_line_strip(_d: &mut impl RaylibDraw, points: &[Vector2], color: Color) {
     unsafe {
-        raylib_ffi::DrawLineStrip(points.as_ptr() as *mut Vector2, points.len() as i32, color);
+        raylib_ffi::DrawLineStrip(
+            points.as_ptr() as *mut raylib_ffi::Vector2,
+            points.len() as i32,
+            color,
+        );
     }
 }
 
 /// Draw line segment cubic-bezier in-out interpolation
-pub fn draw_line_bezier(start_pos: Vector2, end_pos: Vector2, thick: f32, color: Color) {
+pub fn draw_line_bezier(
+    _d: &mut impl RaylibDraw,
+    start_pos: Vector2,
+    end_pos: Vector2,
+    thick: f32,
+    color: Color,
+) {
     unsafe {
-        raylib_ffi::DrawLineBezier(start_pos, end_pos, thick, color);
+        raylib_ffi::DrawLineBezier(start_pos.into(), end_pos.into(), thick, color);
     }
 }
 
 /// Draw a color-filled circle
-pub fn draw_circle(center_x: i32, center_y: i32, radius: f32, color: Color) {
+pub fn draw_circle(
+    _d: &mut impl RaylibDraw,
+    center_x: i32,
+    center_y: i32,
+    radius: f32,
+    color: Color,
+) {
     unsafe {
         raylib_ffi::DrawCircle(center_x, center_y, radius, color);
     }
@@ -68,6 +130,7 @@ pub fn draw_circle(center_x: i32, center_y: i32, radius: f32, color: Color) {
 
 /// Draw a piece of a circle
 pub fn draw_circle_sector(
+    _d: &mut impl RaylibDraw,
     center: Vector2,
     radius: f32,
     start_angle: f32,
@@ -76,12 +139,20 @@ pub fn draw_circle_sector(
     color: Color,
 ) {
     unsafe {
-        raylib_ffi::DrawCircleSector(center, radius, start_angle, end_angle, segments, color);
+        raylib_ffi::DrawCircleSector(
+            center.into(),
+            radius,
+            start_angle,
+            end_angle,
+            segments,
+            color,
+        );
     }
 }
 
 /// Draw circle sector outline
 pub fn draw_circle_sector_lines(
+    _d: &mut impl RaylibDraw,
     center: Vector2,
     radius: f32,
     start_angle: f32,
@@ -90,12 +161,20 @@ pub fn draw_circle_sector_lines(
     color: Color,
 ) {
     unsafe {
-        raylib_ffi::DrawCircleSectorLines(center, radius, start_angle, end_angle, segments, color);
+        raylib_ffi::DrawCircleSectorLines(
+            center.into(),
+            radius,
+            start_angle,
+            end_angle,
+            segments,
+            color,
+        );
     }
 }
 
 /// Draw a gradient-filled circle
 pub fn draw_circle_gradient(
+    _d: &mut impl RaylibDraw,
     center_x: i32,
     center_y: i32,
     radius: f32,
@@ -108,28 +187,41 @@ pub fn draw_circle_gradient(
 }
 
 /// Draw a color-filled circle (Vector version)
-pub fn draw_circle_v(center: Vector2, radius: f32, color: Color) {
+pub fn draw_circle_v(_d: &mut impl RaylibDraw, center: Vector2, radius: f32, color: Color) {
     unsafe {
-        raylib_ffi::DrawCircleV(center, radius, color);
+        raylib_ffi::DrawCircleV(center.into(), radius, color);
     }
 }
 
 /// Draw circle outline
-pub fn draw_circle_lines(center_x: i32, center_y: i32, radius: f32, color: Color) {
+pub fn draw_circle_lines(
+    _d: &mut impl RaylibDraw,
+    center_x: i32,
+    center_y: i32,
+    radius: f32,
+    color: Color,
+) {
     unsafe {
         raylib_ffi::DrawCircleLines(center_x, center_y, radius, color);
     }
 }
 
 /// Draw circle outline (Vector version)
-pub fn draw_circle_lines_v(center: Vector2, radius: f32, color: Color) {
+pub fn draw_circle_lines_v(_d: &mut impl RaylibDraw, center: Vector2, radius: f32, color: Color) {
     unsafe {
-        raylib_ffi::DrawCircleLinesV(center, radius, color);
+        raylib_ffi::DrawCircleLinesV(center.into(), radius, color);
     }
 }
 
 /// Draw ellipse
-pub fn draw_ellipse(center_x: i32, center_y: i32, radius_h: f32, radius_v: f32, color: Color) {
+pub fn draw_ellipse(
+    _d: &mut impl RaylibDraw,
+    center_x: i32,
+    center_y: i32,
+    radius_h: f32,
+    radius_v: f32,
+    color: Color,
+) {
     unsafe {
         raylib_ffi::DrawEllipse(center_x, center_y, radius_h, radius_v, color);
     }
@@ -137,6 +229,7 @@ pub fn draw_ellipse(center_x: i32, center_y: i32, radius_h: f32, radius_v: f32,
 
 /// Draw ellipse outline
 pub fn draw_ellipse_lines(
+    _d: &mut impl RaylibDraw,
     center_x: i32,
     center_y: i32,
     radius_h: f32,
@@ -149,7 +242,9 @@ pub fn draw_ellipse_lines(
 }
 
 /// Draw ring
+#[allow(clippy::too_many_arguments)]
 pub fn draw_ring(
+    _d: &mut impl RaylibDraw,
     center: Vector2,
     inner_radius: f32,
     outer_radius: f32,
@@ -160,7 +255,7 @@ pub fn draw_ring(
 ) {
     unsafe {
         raylib_ffi::DrawRing(
-            center,
+            center.into(),
             inner_radius,
             outer_radius,
             start_angle,
@@ -172,7 +267,9 @@ pub fn draw_ring(
 }
 
 /// Draw ring outline
+#[allow(clippy::too_many_arguments)]
 pub fn draw_ring_lines(
+    _d: &mut impl RaylibDraw,
     center: Vector2,
     inner_radius: f32,
     outer_radius: f32,
@@ -183,7 +280,7 @@ pub fn draw_ring_lines(
 ) {
     unsafe {
         raylib_ffi::DrawRingLines(
-            center,
+            center.into(),
             inner_radius,
             outer_radius,
             start_angle,
@@ -195,35 +292,49 @@ pub fn draw_ring_lines(
 }
 
 /// Draw a color-filled rectangle
-pub fn draw_rectangle(pos_x: i32, pos_y: i32, width: i32, height: i32, color: Color) {
+pub fn draw_rectangle(
+    _d: &mut impl RaylibDraw,
+    pos_x: i32,
+    pos_y: i32,
+    width: i32,
+    height: i32,
+    color: Color,
+) {
     unsafe {
         raylib_ffi::DrawRectangle(pos_x, pos_y, width, height, color);
     }
 }
 
 /// Draw a color-filled rectangle (Vector version)
-pub fn draw_rectangle_v(position: Vector2, size: Vector2, color: Color) {
+pub fn draw_rectangle_v(_d: &mut impl RaylibDraw, position: Vector2, size: Vector2, color: Color) {
     unsafe {
-        raylib_ffi::DrawRectangleV(position, size, color);
+        raylib_ffi::DrawRectangleV(position.into(), size.into(), color);
     }
 }
 
 /// Draw a color-filled rectangle
-pub fn draw_rectangle_rec(rec: Rectangle, color: Color) {
+pub fn draw_rectangle_rec(_d: &mut impl RaylibDraw, rec: Rectangle, color: Color) {
     unsafe {
         raylib_ffi::DrawRectangleRec(rec, color);
     }
 }
 
 /// Draw a color-filled rectangle with pro parameters
-pub fn draw_rectangle_pro(rec: Rectangle, origin: Vector2, rotation: f32, color: Color) {
+pub fn draw_rectangle_pro(
+    _d: &mut impl RaylibDraw,
+    rec: Rectangle,
+    origin: Vector2,
+    rotation: f32,
+    color: Color,
+) {
     unsafe {
-        raylib_ffi::DrawRectanglePro(rec, origin, rotation, color);
+        raylib_ffi::DrawRectanglePro(rec, origin.into(), rotation, color);
     }
 }
 
 /// Draw a vertical-gradient-filled rectangle
 pub fn draw_rectangle_gradient_v(
+    _d: &mut impl RaylibDraw,
     pos_x: i32,
     pos_y: i32,
     width: i32,
@@ -238,6 +349,7 @@ pub fn draw_rectangle_gradient_v(
 
 /// Draw a horizontal-gradient-filled rectangle
 pub fn draw_rectangle_gradient_h(
+    _d: &mut impl RaylibDraw,
     pos_x: i32,
     pos_y: i32,
     width: i32,
@@ -252,6 +364,7 @@ pub fn draw_rectangle_gradient_h(
 
 /// Draw a gradient-filled rectangle with custom vertex colors
 pub fn draw_rectangle_gradient_ex(
+    _d: &mut impl RaylibDraw,
     rec: Rectangle,
     col1: Color,
     col2: Color,
@@ -264,21 +377,39 @@ pub fn draw_rectangle_gradient_ex(
 }
 
 /// Draw rectangle outline
-pub fn draw_rectangle_lines(pos_x: i32, pos_y: i32, width: i32, height: i32, color: Color) {
+pub fn draw_rectangle_lines(
+    _d: &mut impl RaylibDraw,
+    pos_x: i32,
+    pos_y: i32,
+    width: i32,
+    height: i32,
+    color: Color,
+) {
     unsafe {
         raylib_ffi::DrawRectangleLines(pos_x, pos_y, width, height, color);
     }
 }
 
 /// Draw rectangle outline with extended parameters
-pub fn draw_rectangle_lines_ex(rec: Rectangle, line_thick: f32, color: Color) {
+pub fn draw_rectangle_lines_ex(
+    _d: &mut impl RaylibDraw,
+    rec: Rectangle,
+    line_thick: f32,
+    color: Color,
+) {
     unsafe {
         raylib_ffi::DrawRectangleLinesEx(rec, line_thick, color);
     }
 }
 
 /// Draw rectangle with rounded edges
-pub fn draw_rectangle_rounded(rec: Rectangle, roundness: f32, segments: i32, color: Color) {
+pub fn draw_rectangle_rounded(
+    _d: &mut impl RaylibDraw,
+    rec: Rectangle,
+    roundness: f32,
+    segments: i32,
+    color: Color,
+) {
     unsafe {
         raylib_ffi::DrawRectangleRounded(rec, roundness, segments, color);
     }
@@ -286,6 +417,7 @@ pub fn draw_rectangle_rounded(rec: Rectangle, roundness: f32, segments: i32, col
 
 /// Draw rectangle with rounded edges outline
 pub fn draw_rectangle_rounded_lines(
+    _d: &mut impl RaylibDraw,
     rec: Rectangle,
     roundness: f32,
     segments: i32,
@@ -298,49 +430,94 @@ pub fn draw_rectangle_rounded_lines(
 }
 
 /// Draw a color-filled triangle (vertex in counter-clockwise order!)
-pub fn draw_triangle(v1: Vector2, v2: Vector2, v3: Vector2, color: Color) {
+pub fn draw_triangle(
+    _d: &mut impl RaylibDraw,
+    v1: Vector2,
+    v2: Vector2,
+    v3: Vector2,
+    color: Color,
+) {
     unsafe {
-        raylib_ffi::DrawTriangle(v1, v2, v3, color);
+        raylib_ffi::DrawTriangle(v1.into(), v2.into(), v3.into(), color);
     }
 }
 
 /// Draw triangle outline (vertex in counter-clockwise order!)
-pub fn draw_triangle_lines(v1: Vector2, v2: Vector2, v3: Vector2, color: Color) {
+pub fn draw_triangle_lines(
+    _d: &mut impl RaylibDraw,
+    v1: Vector2,
+    v2: Vector2,
+    v3: Vector2,
+    color: Color,
+) {
     unsafe {
-        raylib_ffi::DrawTriangleLines(v1, v2, v3, color);
+        raylib_ffi::DrawTriangleLines(v1.into(), v2.into(), v3.into(), color);
     }
 }
 
 /// Draw a triangle fan defined by points (first vertex is the center)
-pub fn draw_triangle_fan(points: &[Vector2], point_count: i32, color: Color) {
+pub fn draw_triangle_fan(
+    _d: &mut impl RaylibDraw,
+    points: &[Vector2],
+    point_count: i32,
+    color: Color,
+) {
     unsafe {
-        raylib_ffi::DrawTriangleFan(points.as_ptr() as *mut Vector2, point_count, color);
+        raylib_ffi::DrawTriangleFan(
+            points.as_ptr() as *mut raylib_ffi::Vector2,
+            point_count,
+            color,
+        );
     }
 }
 
 /// Draw a triangle strip defined by points
-pub fn draw_triangle_strip(points: &[Vector2], point_count: i32, color: Color) {
+pub fn draw_triangle_strip(
+    _d: &mut impl RaylibDraw,
+    points: &[Vector2],
+    point_count: i32,
+    color: Color,
+) {
     unsafe {
-        raylib_ffi::DrawTriangleStrip(points.as_ptr() as *mut Vector2, point_count, color);
+        raylib_ffi::DrawTriangleStrip(
+            points.as_ptr() as *mut raylib_ffi::Vector2,
+            point_count,
+            color,
+        );
     }
 }
 
 /// Draw a regular polygon (Vector version)
-pub fn draw_poly(center: Vector2, sides: i32, radius: f32, rotation: f32, color: Color) {
+pub fn draw_poly(
+    _d: &mut impl RaylibDraw,
+    center: Vector2,
+    sides: i32,
+    radius: f32,
+    rotation: f32,
+    color: Color,
+) {
     unsafe {
-        raylib_ffi::DrawPoly(center, sides, radius, rotation, color);
+        raylib_ffi::DrawPoly(center.into(), sides, radius, rotation, color);
     }
 }
 
 /// Draw a polygon outline of n sides
-pub fn draw_poly_lines(center: Vector2, sides: i32, radius: f32, rotation: f32, color: Color) {
+pub fn draw_poly_lines(
+    _d: &mut impl RaylibDraw,
+    center: Vector2,
+    sides: i32,
+    radius: f32,
+    rotation: f32,
+    color: Color,
+) {
     unsafe {
-        raylib_ffi::DrawPolyLines(center, sides, radius, rotation, color);
+        raylib_ffi::DrawPolyLines(center.into(), sides, radius, rotation, color);
     }
 }
 
 /// Draw a polygon outline of n sides with extended parameters
 pub fn draw_poly_lines_ex(
+    _d: &mut impl RaylibDraw,
     center: Vector2,
     sides: i32,
     radius: f32,
@@ -349,15 +526,15 @@ pub fn draw_poly_lines_ex(
     color: Color,
 ) {
     unsafe {
-        raylib_ffi::DrawPolyLinesEx(center, sides, radius, rotation, line_thick, color);
+        raylib_ffi::DrawPolyLinesEx(center.into(), sides, radius, rotation, line_thick, color);
     }
 }
 
 /// Draw spline: Linear, minimum 2 points
-pub fn draw_spline_linear(points: &[Vector2], thick: f32, color: Color) {
+pub fn draw_spline_linear(_d: &mut impl RaylibDraw, points: &[Vector2], thick: f32, color: Color) {
     unsafe {
         raylib_ffi::DrawSplineLinear(
-            points.as_ptr() as *mut Vector2,
+            points.as_ptr() as *mut raylib_ffi::Vector2,
             points.len() as i32,
             thick,
             color,
@@ -366,10 +543,10 @@ pub fn draw_spline_linear(points: &[Vector2], thick: f32, color: Color) {
 }
 
 /// Draw spline: B-Spline, minimum 4 points
-pub fn draw_spline_basis(points: &[Vector2], thick: f32, color: Color) {
+pub fn draw_spline_basis(_d: &mut impl RaylibDraw, points: &[Vector2], thick: f32, color: Color) {
     unsafe {
         raylib_ffi::DrawSplineBasis(
-            points.as_ptr() as *mut Vector2,
+            points.as_ptr() as *mut raylib_ffi::Vector2,
             points.len() as i32,
             thick,
             color,
@@ -378,10 +555,15 @@ pub fn draw_spline_basis(points: &[Vector2], thick: f32, color: Color) {
 }
 
 /// Draw spline: Catmull-Rom, minimum 4 points
-pub fn draw_spline_catmull_rom(points: &[Vector2], thick: f32, color: Color) {
+pub fn draw_spline_catmull_rom(
+    _d: &mut impl RaylibDraw,
+    points: &[Vector2],
+    thick: f32,
+    color: Color,
+) {
     unsafe {
         raylib_ffi::DrawSplineCatmullRom(
-            points.as_ptr() as *mut Vector2,
+            points.as_ptr() as *mut raylib_ffi::Vector2,
             points.len() as i32,
             thick,
             color,
@@ -390,10 +572,15 @@ pub fn draw_spline_catmull_rom(points: &[Vector2], thick: f32, color: Color) {
 }
 
 /// Draw spline: Quadratic Bezier, minimum 3 points (1 control point): [p1, c2, p3, c4...]
-pub fn draw_spline_bezier_quadratic(points: &[Vector2], thick: f32, color: Color) {
+pub fn draw_spline_bezier_quadratic(
+    _d: &mut impl RaylibDraw,
+    points: &[Vector2],
+    thick: f32,
+    color: Color,
+) {
     unsafe {
         raylib_ffi::DrawSplineBezierQuadratic(
-            points.as_ptr() as *mut Vector2,
+            points.as_ptr() as *mut raylib_ffi::Vector2,
             points.len() as i32,
             thick,
             color,
@@ -402,10 +589,15 @@ pub fn draw_spline_bezier_quadratic(points: &[Vector2], thick: f32, color: Color
 }
 
 /// Draw spline: Cubic Bezier, minimum 4 points (2 control points): [p1, c2, c3, p4, c5, c6...]
-pub fn draw_spline_bezier_cubic(points: &[Vector2], thick: f32, color: Color) {
+pub fn draw_spline_bezier_cubic(
+    _d: &mut impl RaylibDraw,
+    points: &[Vector2],
+    thick: f32,
+    color: Color,
+) {
     unsafe {
         raylib_ffi::DrawSplineBezierCubic(
-            points.as_ptr() as *mut Vector2,
+            points.as_ptr() as *mut raylib_ffi::Vector2,
             points.len() as i32,
             thick,
             color,
@@ -414,14 +606,21 @@ pub fn draw_spline_bezier_cubic(points: &[Vector2], thick: f32, color: Color) {
 }
 
 /// Draw spline segment: Linear, 2 points
-pub fn draw_spline_segment_linear(p1: Vector2, p2: Vector2, thick: f32, color: Color) {
+pub fn draw_spline_segment_linear(
+    _d: &mut impl RaylibDraw,
+    p1: Vector2,
+    p2: Vector2,
+    thick: f32,
+    color: Color,
+) {
     unsafe {
-        raylib_ffi::DrawSplineSegmentLinear(p1, p2, thick, color);
+        raylib_ffi::DrawSplineSegmentLinear(p1.into(), p2.into(), thick, color);
     }
 }
 
 /// Draw spline segment: B-Spline, 4 points
 pub fn draw_spline_segment_basis(
+    _d: &mut impl RaylibDraw,
     p1: Vector2,
     p2: Vector2,
     p3: Vector2,
@@ -430,12 +629,20 @@ pub fn draw_spline_segment_basis(
     color: Color,
 ) {
     unsafe {
-        raylib_ffi::DrawSplineSegmentBasis(p1, p2, p3, p4, thick, color);
+        raylib_ffi::DrawSplineSegmentBasis(
+            p1.into(),
+            p2.into(),
+            p3.into(),
+            p4.into(),
+            thick,
+            color,
+        );
     }
 }
 
 /// Draw spline segment: Catmull-Rom, 4 points
 pub fn draw_spline_segment_catmull_rom(
+    _d: &mut impl RaylibDraw,
     p1: Vector2,
     p2: Vector2,
     p3: Vector2,
@@ -444,12 +651,20 @@ pub fn draw_spline_segment_catmull_rom(
     color: Color,
 ) {
     unsafe {
-        raylib_ffi::DrawSplineSegmentCatmullRom(p1, p2, p3, p4, thick, color);
+        raylib_ffi::DrawSplineSegmentCatmullRom(
+            p1.into(),
+            p2.into(),
+            p3.into(),
+            p4.into(),
+            thick,
+            color,
+        );
     }
 }
 
 /// Draw spline segment: Quadratic Bezier, 2 points, 1 control point
 pub fn draw_spline_segment_bezier_quadratic(
+    _d: &mut impl RaylibDraw,
     p1: Vector2,
     c2: Vector2,
     p3: Vector2,
@@ -457,12 +672,13 @@ pub fn draw_spline_segment_bezier_quadratic(
     color: Color,
 ) {
     unsafe {
-        raylib_ffi::DrawSplineSegmentBezierQuadratic(p1, c2, p3, thick, color);
+        raylib_ffi::DrawSplineSegmentBezierQuadratic(p1.into(), c2.into(), p3.into(), thick, color);
     }
 }
 
 /// Draw spline segment: Cubic Bezier, 2 points, 2 control points
 pub fn draw_spline_segment_bezier_cubic(
+    _d: &mut impl RaylibDraw,
     p1: Vector2,
     c2: Vector2,
     c3: Vector2,
@@ -471,13 +687,20 @@ pub fn draw_spline_segment_bezier_cubic(
     color: Color,
 ) {
     unsafe {
-        raylib_ffi::DrawSplineSegmentBezierCubic(p1, c2, c3, p4, thick, color);
+        raylib_ffi::DrawSplineSegmentBezierCubic(
+            p1.into(),
+            c2.into(),
+            c3.into(),
+            p4.into(),
+            thick,
+            color,
+        );
     }
 }
 
 /// Get (evaluate) spline point: Linear
 pub fn get_spline_point_linear(start_pos: Vector2, end_pos: Vector2, t: f32) -> Vector2 {
-    unsafe { raylib_ffi::GetSplinePointLinear(start_pos, end_pos, t) }
+    unsafe { raylib_ffi::GetSplinePointLinear(start_pos.into(), end_pos.into(), t).into() }
 }
 
 /// Get (evaluate) spline point: B-Spline
@@ -488,7 +711,7 @@ pub fn get_spline_point_basis(
     p4: Vector2,
     t: f32,
 ) -> Vector2 {
-    unsafe { raylib_ffi::GetSplinePointBasis(p1, p2, p3, p4, t) }
+    unsafe { raylib_ffi::GetSplinePointBasis(p1.into(), p2.into(), p3.into(), p4.into(), t).into() }
 }
 
 /// Get (evaluate) spline point: Catmull-Rom
@@ -499,12 +722,14 @@ pub fn get_spline_point_catmull_rom(
     p4: Vector2,
     t: f32,
 ) -> Vector2 {
-    unsafe { raylib_ffi::GetSplinePointCatmullRom(p1, p2, p3, p4, t) }
+    unsafe {
+        raylib_ffi::GetSplinePointCatmullRom(p1.into(), p2.into(), p3.into(), p4.into(), t).into()
+    }
 }
 
 /// Get (evaluate) spline point: Quadratic Bezier
 pub fn get_spline_point_bezier_quad(p1: Vector2, c2: Vector2, p3: Vector2, t: f32) -> Vector2 {
-    unsafe { raylib_ffi::GetSplinePointBezierQuad(p1, c2, p3, t) }
+    unsafe { raylib_ffi::GetSplinePointBezierQuad(p1.into(), c2.into(), p3.into(), t).into() }
 }
 
 /// Get (evaluate) spline point: Cubic Bezier
@@ -515,7 +740,9 @@ pub fn get_spline_point_bezier_cubic(
     p4: Vector2,
     t: f32,
 ) -> Vector2 {
-    unsafe { raylib_ffi::GetSplinePointBezierCubic(p1, c2, c3, p4, t) }
+    unsafe {
+        raylib_ffi::GetSplinePointBezierCubic(p1.into(), c2.into(), c3.into(), p4.into(), t).into()
+    }
 }
 
 /// Check collision between two rectangles
@@ -530,22 +757,22 @@ pub fn check_collision_circles(
     center2: Vector2,
     radius2: f32,
 ) -> bool {
-    unsafe { raylib_ffi::CheckCollisionCircles(center1, radius1, center2, radius2) }
+    unsafe { raylib_ffi::CheckCollisionCircles(center1.into(), radius1, center2.into(), radius2) }
 }
 
 /// Check collision between circle and rectangle
 pub fn check_collision_circle_rec(center: Vector2, radius: f32, rec: Rectangle) -> bool {
-    unsafe { raylib_ffi::CheckCollisionCircleRec(center, radius, rec) }
+    unsafe { raylib_ffi::CheckCollisionCircleRec(center.into(), radius, rec) }
 }
 
 /// Check if point is inside rectangle
 pub fn check_collision_point_rec(point: Vector2, rec: Rectangle) -> bool {
-    unsafe { raylib_ffi::CheckCollisionPointRec(point, rec) }
+    unsafe { raylib_ffi::CheckCollisionPointRec(point.into(), rec) }
 }
 
 /// Check if point is inside circle
 pub fn check_collision_point_circle(point: Vector2, center: Vector2, radius: f32) -> bool {
-    unsafe { raylib_ffi::CheckCollisionPointCircle(point, center, radius) }
+    unsafe { raylib_ffi::CheckCollisionPointCircle(point.into(), center.into(), radius) }
 }
 
 /// Check if point is inside a triangle
@@ -555,15 +782,17 @@ pub fn check_collision_point_triangle(
     p2: Vector2,
     p3: Vector2,
 ) -> bool {
-    unsafe { raylib_ffi::CheckCollisionPointTriangle(point, p1, p2, p3) }
+    unsafe {
+        raylib_ffi::CheckCollisionPointTriangle(point.into(), p1.into(), p2.into(), p3.into())
+    }
 }
 
 /// Check if point is within a polygon described by array of vertices
 pub fn check_collision_point_poly(point: Vector2, points: &[Vector2]) -> bool {
     unsafe {
         raylib_ffi::CheckCollisionPointPoly(
-            point,
-            points.as_ptr() as *mut Vector2,
+            point.into(),
+            points.as_ptr() as *mut raylib_ffi::Vector2,
             points.len() as i32,
         )
     }
@@ -576,18 +805,18 @@ pub fn check_collision_lines(
     start_pos2: Vector2,
     end_pos2: Vector2,
 ) -> Option<Vector2> {
-    let mut collision_point = Vector2 { x: 0.0, y: 0.0 };
+    let mut collision_point = raylib_ffi::Vector2 { x: 0.0, y: 0.0 };
     let collision = unsafe {
         raylib_ffi::CheckCollisionLines(
-            start_pos1,
-            end_pos1,
-            start_pos2,
-            end_pos2,
+            start_pos1.into(),
+            end_pos1.into(),
+            start_pos2.into(),
+            end_pos2.into(),
             &mut collision_point,
         )
     };
     if collision {
-        Some(collision_point)
+        Some(collision_point.into())
     } else {
         None
     }
@@ -600,7 +829,7 @@ pub fn check_collision_point_line(
     p2: Vector2,
     threshold: i32,
 ) -> bool {
-    unsafe { raylib_ffi::CheckCollisionPointLine(point, p1, p2, threshold) }
+    unsafe { raylib_ffi::CheckCollisionPointLine(point.into(), p1.into(), p2.into(), threshold) }
 }
 
 /// Get collision rectangle for two rectangles collision
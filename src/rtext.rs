@@ -1,76 +1,231 @@
-use crate::{Color, Font, GlyphInfo, Image, Rectangle, Vector2};
+use crate::enums::FontType;
+use crate::error::{c_string, check_file, has_extension};
+use crate::rcore::{RaylibDraw, RaylibHandle};
+use crate::rtextures::is_image_ready;
+use crate::{Color, GlyphInfo, Image, Rectangle, Texture, Vector2};
+use crate::{Error, LoadError};
+use std::ops::Deref;
+
+/// Font texture and glyph data loaded into GPU memory (VRAM), unloaded on drop
+#[derive(Debug)]
+pub struct Font {
+    raw: raylib_ffi::Font,
+}
+
+impl Font {
+    /// Take ownership of a raw font, it will be unloaded on drop
+    ///
+    /// # Safety
+    /// `raw` must be a valid font loaded by raylib that is not owned elsewhere
+    pub unsafe fn from_raw(raw: raylib_ffi::Font) -> Self {
+        Self { raw }
+    }
+
+    /// Release ownership of the raw font, it will no longer be unloaded on drop
+    pub fn into_raw(self) -> raylib_ffi::Font {
+        let raw = self.raw;
+        std::mem::forget(self);
+        raw
+    }
+
+    /// Get a copy of the raw font, only valid while `self` is alive
+    pub fn as_raw(&self) -> raylib_ffi::Font {
+        self.raw
+    }
+
+    /// Base size (default chars height)
+    pub fn base_size(&self) -> i32 {
+        self.raw.baseSize
+    }
+
+    /// Padding around the glyph characters
+    pub fn glyph_padding(&self) -> i32 {
+        self.raw.glyphPadding
+    }
+
+    /// Texture atlas containing the glyphs
+    pub fn texture(&self) -> &Texture {
+        Texture::from_raw_ref(&self.raw.texture)
+    }
+
+    /// Glyphs info data
+    pub fn glyphs(&self) -> &[GlyphInfo] {
+        if self.raw.glyphs.is_null() {
+            return &[];
+        }
+        unsafe { std::slice::from_raw_parts(self.raw.glyphs, self.raw.glyphCount as usize) }
+    }
+
+    /// Rectangles in texture for the glyphs
+    pub fn recs(&self) -> &[Rectangle] {
+        if self.raw.recs.is_null() {
+            return &[];
+        }
+        unsafe { std::slice::from_raw_parts(self.raw.recs, self.raw.glyphCount as usize) }
+    }
+
+    /// Measure string size for this font
+    pub fn measure_text(&self, text: &str, font_size: f32, spacing: f32) -> Vector2 {
+        measure_text_ex(self, text, font_size, spacing)
+    }
+
+    /// Get glyph index position in font for a codepoint (unicode character), fallback to '?' if not found
+    pub fn glyph_index(&self, codepoint: i32) -> usize {
+        get_glyph_index(self, codepoint) as usize
+    }
+
+    /// Get glyph font info data for a codepoint (unicode character), fallback to '?' if not found
+    pub fn glyph_info(&self, codepoint: i32) -> Option<&GlyphInfo> {
+        get_glyph_info(self, codepoint)
+    }
+
+    /// Get glyph rectangle in font atlas for a codepoint (unicode character), fallback to '?' if not found
+    pub fn glyph_atlas_rec(&self, codepoint: i32) -> Option<Rectangle> {
+        get_glyph_atlas_rec(self, codepoint)
+    }
+}
+
+impl Drop for Font {
+    fn drop(&mut self) {
+        // The GPU data is already gone once the window is closed,
+        // raylib also skips the default font here
+        if crate::rcore::is_window_open() {
+            unsafe { raylib_ffi::UnloadFont(self.raw) }
+        }
+    }
+}
+
+/// Glyphs info data loaded into CPU memory (RAM), unloaded on drop
+#[derive(Debug)]
+pub struct Glyphs {
+    glyphs: *mut GlyphInfo,
+    count: usize,
+}
+
+impl Deref for Glyphs {
+    type Target = [GlyphInfo];
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { std::slice::from_raw_parts(self.glyphs, self.count) }
+    }
+}
+
+impl Drop for Glyphs {
+    fn drop(&mut self) {
+        unsafe { raylib_ffi::UnloadFontData(self.glyphs, self.count as i32) }
+    }
+}
+
 // Text drawing functions
-/// Get the default Font
-pub fn get_font_default() -> Font {
-    unsafe { raylib_ffi::GetFontDefault() }
+/// Get the default Font, dropping it does not unload it
+pub fn get_font_default(_rl: &RaylibHandle) -> Font {
+    Font {
+        raw: unsafe { raylib_ffi::GetFontDefault() },
+    }
 }
 
 /// Load font from file into GPU memory (VRAM)
-pub fn load_font(file_name: &str) -> Font {
-    unsafe { raylib_ffi::LoadFont(raylib_ffi::rl_str!(file_name)) }
+pub fn load_font(_rl: &RaylibHandle, file_name: &str) -> crate::Result<Font> {
+    let c_file_name = check_file(file_name, FONT_FILE_EXTENSIONS)?;
+    let font = unsafe { raylib_ffi::LoadFont(c_file_name.as_ptr()) };
+    check_font(file_name, font)
 }
 
-/// Load font from file with extended parameters, use NULL for codepoints and 0 for codepointCount to load the default character set
+/// Load font from file with extended parameters, use None for codepoints to load the default character set
 pub fn load_font_ex(
+    _rl: &RaylibHandle,
     file_name: &str,
     font_size: i32,
     codepoints: Option<&[i32]>,
-    codepoint_count: i32,
-) -> Font {
-    let codepoints_ptr: *mut i32 = match codepoints {
-        Some(cp) => {
-            let mut cp_vec = cp.to_vec();
-            let cp = &mut cp_vec[..];
-            cp.as_mut_ptr()
-        }
+) -> crate::Result<Font> {
+    let c_file_name = check_file(file_name, &[".ttf", ".otf"])?;
+    let (mut codepoints, codepoint_count) = codepoints_arg(codepoints);
+    let codepoints_ptr = match codepoints {
+        Some(ref mut cp) => cp.as_mut_ptr(),
         None => std::ptr::null_mut(),
     };
-    unsafe {
+    let font = unsafe {
         raylib_ffi::LoadFontEx(
-            raylib_ffi::rl_str!(file_name),
+            c_file_name.as_ptr(),
             font_size,
             codepoints_ptr,
             codepoint_count,
         )
-    }
+    };
+    check_font(file_name, font)
 }
 
 /// Load font from Image (XNA style)
-pub fn load_font_from_image(image: Image, key: Color, first_char: i32) -> Font {
-    unsafe { raylib_ffi::LoadFontFromImage(image, key, first_char) }
+pub fn load_font_from_image(
+    _rl: &RaylibHandle,
+    image: &Image,
+    key: Color,
+    first_char: i32,
+) -> crate::Result<Font> {
+    if !is_image_ready(image) {
+        return Err(Error::load("<image>", LoadError::InvalidData));
+    }
+    let font = unsafe { raylib_ffi::LoadFontFromImage(image.as_raw(), key, first_char) };
+    check_font("<image>", font)
 }
 
 /// Load font from memory buffer, fileType refers to extension: i.e. '.ttf'
 pub fn load_font_from_memory(
+    _rl: &RaylibHandle,
     file_type: &str,
     file_data: &[u8],
     font_size: i32,
     codepoints: Option<&[i32]>,
-    codepoint_count: i32,
-) -> Font {
+) -> crate::Result<Font> {
+    let c_file_type = c_string(file_type, file_type)?;
+    if !has_extension(file_type, &[".ttf", ".otf"]) {
+        return Err(Error::load(file_type, LoadError::UnsupportedFormat));
+    }
+    let (mut codepoints, codepoint_count) = codepoints_arg(codepoints);
     let codepoints_ptr = match codepoints {
-        Some(cp) => {
-            let mut cp_vec = cp.to_vec();
-            let cp = &mut cp_vec[..];
-            cp.as_mut_ptr()
-        }
+        Some(ref mut cp) => cp.as_mut_ptr(),
         None => std::ptr::null_mut(),
     };
-    unsafe {
+    let font = unsafe {
         raylib_ffi::LoadFontFromMemory(
-            raylib_ffi::rl_str!(file_type),
+            c_file_type.as_ptr(),
             file_data.as_ptr(),
             file_data.len() as i32,
             font_size,
             codepoints_ptr,
             codepoint_count,
         )
+    };
+    check_font(file_type, font)
+}
+
+/// Font file types accepted by LoadFont(), images are loaded as XNA style fonts
+const FONT_FILE_EXTENSIONS: &[&str] = &[".ttf", ".otf", ".fnt", ".png", ".gif", ".qoi", ".dds"];
+
+/// Turn raylib's fallback to the default font into an error
+fn check_font(path: &str, font: raylib_ffi::Font) -> crate::Result<Font> {
+    if font.texture.id == 0 {
+        unsafe { raylib_ffi::UnloadFont(font) };
+        Err(Error::load(path, LoadError::GpuUploadFailed))
+    } else if font.texture.id == unsafe { raylib_ffi::GetFontDefault() }.texture.id {
+        Err(Error::load(path, LoadError::InvalidData))
+    } else {
+        Ok(Font { raw: font })
     }
 }
 
+/// Copy of the codepoints and their count for the font loaders
+///
+/// Without codepoints (None or empty) raylib loads the 95 ASCII glyphs.
+fn codepoints_arg(codepoints: Option<&[i32]>) -> (Option<Vec<i32>>, i32) {
+    let codepoints = codepoints.filter(|cp| !cp.is_empty());
+    let count = codepoints.map_or(95, <[i32]>::len) as i32;
+    (codepoints.map(<[i32]>::to_vec), count)
+}
+
 /// Check if a font is ready
-pub fn is_font_ready(font: Font) -> bool {
-    unsafe { raylib_ffi::IsFontReady(font) }
+pub fn is_font_ready(font: &Font) -> bool {
+    unsafe { raylib_ffi::IsFontReady(font.raw) }
 }
 
 /// Load font data for further use
@@ -78,78 +233,80 @@ pub fn load_font_data(
     file_data: &[u8],
     font_size: i32,
     codepoints: Option<&[i32]>,
-    codepoint_count: i32,
-    type_: i32,
-) -> *mut GlyphInfo {
+    type_: FontType,
+) -> crate::Result<Glyphs> {
+    let (mut codepoints, codepoint_count) = codepoints_arg(codepoints);
     let codepoints_ptr = match codepoints {
-        Some(cp) => {
-            let mut cp_vec = cp.to_vec();
-            let cp = &mut cp_vec[..];
-            cp.as_mut_ptr()
-        }
+        Some(ref mut cp) => cp.as_mut_ptr(),
         None => std::ptr::null_mut(),
     };
-    unsafe {
+    let glyphs = unsafe {
         raylib_ffi::LoadFontData(
             file_data.as_ptr(),
             file_data.len() as i32,
             font_size,
             codepoints_ptr,
             codepoint_count,
-            type_,
+            type_ as i32,
         )
+    };
+    if glyphs.is_null() {
+        Err(Error::load("<font data>", LoadError::InvalidData))
+    } else {
+        Ok(Glyphs {
+            glyphs,
+            count: codepoint_count as usize,
+        })
     }
 }
 
-/// Generate image font atlas using chars info
+/// Generate image font atlas using chars info, returns the atlas and the glyph rectangles in it
 pub fn gen_image_font_atlas(
     glyphs: &[GlyphInfo],
-    glyph_recs: &mut Option<&mut [Rectangle]>,
-    glyph_count: i32,
     font_size: i32,
     padding: i32,
     pack_method: i32,
-) -> Image {
-    let mut glyph_recs_ptr = match glyph_recs {
-        Some(ref mut gr) => gr.as_mut_ptr(),
-        None => std::ptr::null_mut(),
-    };
+) -> (Image, Vec<Rectangle>) {
+    let mut glyph_recs_ptr: *mut Rectangle = std::ptr::null_mut();
     unsafe {
-        raylib_ffi::GenImageFontAtlas(
+        let atlas = raylib_ffi::GenImageFontAtlas(
             glyphs.as_ptr(),
             &mut glyph_recs_ptr,
-            glyph_count,
+            glyphs.len() as i32,
             font_size,
             padding,
             pack_method,
-        )
+        );
+        let mut glyph_recs = Vec::new();
+        if !glyph_recs_ptr.is_null() {
+            glyph_recs.extend_from_slice(std::slice::from_raw_parts(glyph_recs_ptr, glyphs.len()));
+            raylib_ffi::MemFree(glyph_recs_ptr as *mut std::ffi::c_void);
+        }
+        (Image::from_raw(atlas), glyph_recs)
     }
 }
 
-/// Unload font chars info data (RAM)
-pub fn unload_font_data(glyphs: *mut GlyphInfo, glyph_count: i32) {
-    unsafe { raylib_ffi::UnloadFontData(glyphs, glyph_count) }
-}
-
-/// Unload font from GPU memory (VRAM)
-pub fn unload_font(font: Font) {
-    unsafe { raylib_ffi::UnloadFont(font) }
-}
-
 /// Export font as code file, returns true on success
-pub fn export_font_as_code(font: Font, file_name: &str) -> bool {
-    unsafe { raylib_ffi::ExportFontAsCode(font, raylib_ffi::rl_str!(file_name)) }
+pub fn export_font_as_code(font: &Font, file_name: &str) -> bool {
+    unsafe { raylib_ffi::ExportFontAsCode(font.raw, raylib_ffi::rl_str!(file_name)) }
 }
 
 /// Draw current FPS
-pub fn draw_fps(pos_x: i32, pos_y: i32) {
+pub fn draw_fps(_d: &mut impl RaylibDraw, pos_x: i32, pos_y: i32) {
     unsafe {
         raylib_ffi::DrawFPS(pos_x, pos_y);
     }
 }
 
 /// Draw text (using default font)
-pub fn draw_text(text: &str, pos_x: i32, pos_y: i32, font_size: i32, color: Color) {
+pub fn draw_text(
+    _d: &mut impl RaylibDraw,
+    text: &str,
+    pos_x: i32,
+    pos_y: i32,
+    font_size: i32,
+    color: Color,
+) {
     unsafe {
         raylib_ffi::DrawText(raylib_ffi::rl_str!(text), pos_x, pos_y, font_size, color);
     }
@@ -157,7 +314,8 @@ pub fn draw_text(text: &str, pos_x: i32, pos_y: i32, font_size: i32, color: Colo
 
 /// Draw text using font and additional parameters
 pub fn draw_text_ex(
-    font: Font,
+    _d: &mut impl RaylibDraw,
+    font: &Font,
     text: &str,
     position: Vector2,
     font_size: f32,
@@ -166,9 +324,9 @@ pub fn draw_text_ex(
 ) {
     unsafe {
         raylib_ffi::DrawTextEx(
-            font,
+            font.raw,
             raylib_ffi::rl_str!(text),
-            position,
+            position.into(),
             font_size,
             spacing,
             tint,
@@ -177,8 +335,10 @@ pub fn draw_text_ex(
 }
 
 /// Draw text using Font and pro parameters (rotation)
+#[allow(clippy::too_many_arguments)]
 pub fn draw_text_pro(
-    font: Font,
+    _d: &mut impl RaylibDraw,
+    font: &Font,
     text: &str,
     position: Vector2,
     origin: Vector2,
@@ -189,10 +349,10 @@ pub fn draw_text_pro(
 ) {
     unsafe {
         raylib_ffi::DrawTextPro(
-            font,
+            font.raw,
             raylib_ffi::rl_str!(text),
-            position,
-            origin,
+            position.into(),
+            origin.into(),
             rotation,
             font_size,
             spacing,
@@ -203,20 +363,22 @@ pub fn draw_text_pro(
 
 /// Draw one character (codepoint)
 pub fn draw_text_codepoint(
-    font: Font,
+    _d: &mut impl RaylibDraw,
+    font: &Font,
     codepoint: i32,
     position: Vector2,
     font_size: f32,
     tint: Color,
 ) {
     unsafe {
-        raylib_ffi::DrawTextCodepoint(font, codepoint, position, font_size, tint);
+        raylib_ffi::DrawTextCodepoint(font.raw, codepoint, position.into(), font_size, tint);
     }
 }
 
 /// Draw multiple characters (codepoints)
 pub fn draw_text_codepoints(
-    font: Font,
+    _d: &mut impl RaylibDraw,
+    font: &Font,
     codepoints: &[i32],
     position: Vector2,
     font_size: f32,
@@ -225,10 +387,10 @@ pub fn draw_text_codepoints(
 ) {
     unsafe {
         raylib_ffi::DrawTextCodepoints(
-            font,
+            font.raw,
             codepoints.as_ptr(),
             codepoints.len() as i32,
-            position,
+            position.into(),
             font_size,
             spacing,
             tint,
@@ -249,23 +411,33 @@ pub fn measure_text(text: &str, font_size: i32) -> i32 {
 }
 
 /// Measure string size for Font
-pub fn measure_text_ex(font: Font, text: &str, font_size: f32, spacing: f32) -> Vector2 {
-    unsafe { raylib_ffi::MeasureTextEx(font, raylib_ffi::rl_str!(text), font_size, spacing) }
+pub fn measure_text_ex(font: &Font, text: &str, font_size: f32, spacing: f32) -> Vector2 {
+    unsafe {
+        raylib_ffi::MeasureTextEx(font.raw, raylib_ffi::rl_str!(text), font_size, spacing).into()
+    }
 }
 
 /// Get glyph index position in font for a codepoint (unicode character), fallback to '?' if not found
-pub fn get_glyph_index(font: Font, codepoint: i32) -> i32 {
-    unsafe { raylib_ffi::GetGlyphIndex(font, codepoint) }
+pub fn get_glyph_index(font: &Font, codepoint: i32) -> i32 {
+    unsafe { raylib_ffi::GetGlyphIndex(font.raw, codepoint) }
 }
 
 /// Get glyph font info data for a codepoint (unicode character), fallback to '?' if not found
-pub fn get_glyph_info(font: Font, codepoint: i32) -> GlyphInfo {
-    unsafe { raylib_ffi::GetGlyphInfo(font, codepoint) }
+///
+/// None if the font has no glyphs.
+pub fn get_glyph_info(font: &Font, codepoint: i32) -> Option<&GlyphInfo> {
+    font.glyphs().get(get_glyph_index(font, codepoint) as usize)
 }
 
 /// Get glyph rectangle in font atlas for a codepoint (unicode character), fallback to '?' if not found
-pub fn get_glyph_atlas_rec(font: Font, codepoint: i32) -> Rectangle {
-    unsafe { raylib_ffi::GetGlyphAtlasRec(font, codepoint) }
+///
+/// None if the font has no glyphs.
+pub fn get_glyph_atlas_rec(font: &Font, codepoint: i32) -> Option<Rectangle> {
+    // GetGlyphAtlasRec() reads the rectangle of the glyph index without bounds checks
+    if font.glyphs().is_empty() || font.raw.recs.is_null() {
+        return None;
+    }
+    Some(unsafe { raylib_ffi::GetGlyphAtlasRec(font.raw, codepoint) })
 }
 
 /// Load UTF-8 text encoded from codepoints array
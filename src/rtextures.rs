@@ -0,0 +1,1168 @@
+use crate::enums::{CubemapLayout, PixelFormat, TextureFilter, TextureWrap};
+use crate::error::{check_file, has_extension};
+use crate::rcore::{is_window_open, RaylibDraw, RaylibHandle};
+use crate::rtext::Font;
+use crate::{Color, NPatchInfo, Rectangle, Vector2};
+use crate::{Error, LoadError};
+
+/// Image data stored in CPU memory (RAM), unloaded on drop
+#[derive(Debug)]
+pub struct Image {
+    raw: raylib_ffi::Image,
+}
+
+impl Image {
+    /// Take ownership of a raw image, it will be unloaded on drop
+    ///
+    /// # Safety
+    /// `raw` must be a valid image allocated by raylib that is not owned elsewhere
+    pub unsafe fn from_raw(raw: raylib_ffi::Image) -> Self {
+        Self { raw }
+    }
+
+    /// Release ownership of the raw image, it will no longer be unloaded on drop
+    pub fn into_raw(self) -> raylib_ffi::Image {
+        let raw = self.raw;
+        std::mem::forget(self);
+        raw
+    }
+
+    /// Get a copy of the raw image, only valid while `self` is alive and unmodified
+    pub fn as_raw(&self) -> raylib_ffi::Image {
+        self.raw
+    }
+
+    /// Image base width
+    pub fn width(&self) -> i32 {
+        self.raw.width
+    }
+
+    /// Image base height
+    pub fn height(&self) -> i32 {
+        self.raw.height
+    }
+
+    /// Mipmap levels, 1 by default
+    pub fn mipmaps(&self) -> i32 {
+        self.raw.mipmaps
+    }
+
+    /// Data format (PixelFormat type)
+    pub fn format(&self) -> i32 {
+        self.raw.format
+    }
+}
+
+impl Clone for Image {
+    fn clone(&self) -> Self {
+        image_copy(self)
+    }
+}
+
+impl Drop for Image {
+    fn drop(&mut self) {
+        unsafe { raylib_ffi::UnloadImage(self.raw) }
+    }
+}
+
+/// Texture loaded in GPU memory (VRAM), unloaded on drop
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct Texture {
+    raw: raylib_ffi::Texture,
+}
+
+impl Texture {
+    /// Take ownership of a raw texture, it will be unloaded on drop
+    ///
+    /// # Safety
+    /// `raw` must be a valid texture loaded by raylib that is not owned elsewhere
+    pub unsafe fn from_raw(raw: raylib_ffi::Texture) -> Self {
+        Self { raw }
+    }
+
+    /// Borrow a raw texture owned by another resource (font atlas, render texture...)
+    pub(crate) fn from_raw_ref(raw: &raylib_ffi::Texture) -> &Self {
+        // Texture is repr(transparent) over the raw texture
+        unsafe { &*(raw as *const raylib_ffi::Texture as *const Self) }
+    }
+
+    /// Release ownership of the raw texture, it will no longer be unloaded on drop
+    pub fn into_raw(self) -> raylib_ffi::Texture {
+        let raw = self.raw;
+        std::mem::forget(self);
+        raw
+    }
+
+    /// Get a copy of the raw texture, only valid while `self` is alive
+    pub fn as_raw(&self) -> raylib_ffi::Texture {
+        self.raw
+    }
+
+    /// OpenGL texture id
+    pub fn id(&self) -> u32 {
+        self.raw.id
+    }
+
+    /// Texture base width
+    pub fn width(&self) -> i32 {
+        self.raw.width
+    }
+
+    /// Texture base height
+    pub fn height(&self) -> i32 {
+        self.raw.height
+    }
+
+    /// Mipmap levels, 1 by default
+    pub fn mipmaps(&self) -> i32 {
+        self.raw.mipmaps
+    }
+
+    /// Data format (PixelFormat type)
+    pub fn format(&self) -> i32 {
+        self.raw.format
+    }
+}
+
+impl Drop for Texture {
+    fn drop(&mut self) {
+        // The GPU data is already gone once the window is closed
+        if is_window_open() {
+            unsafe { raylib_ffi::UnloadTexture(self.raw) }
+        }
+    }
+}
+
+/// Framebuffer with color and depth textures loaded in GPU memory (VRAM), unloaded on drop
+#[derive(Debug)]
+pub struct RenderTexture {
+    raw: raylib_ffi::RenderTexture,
+}
+
+impl RenderTexture {
+    /// Take ownership of a raw render texture, it will be unloaded on drop
+    ///
+    /// # Safety
+    /// `raw` must be a valid render texture loaded by raylib that is not owned elsewhere
+    pub unsafe fn from_raw(raw: raylib_ffi::RenderTexture) -> Self {
+        Self { raw }
+    }
+
+    /// Release ownership of the raw render texture, it will no longer be unloaded on drop
+    pub fn into_raw(self) -> raylib_ffi::RenderTexture {
+        let raw = self.raw;
+        std::mem::forget(self);
+        raw
+    }
+
+    /// Get a copy of the raw render texture, only valid while `self` is alive
+    pub fn as_raw(&self) -> raylib_ffi::RenderTexture {
+        self.raw
+    }
+
+    /// OpenGL framebuffer object id
+    pub fn id(&self) -> u32 {
+        self.raw.id
+    }
+
+    /// Color buffer attachment texture
+    pub fn texture(&self) -> &Texture {
+        Texture::from_raw_ref(&self.raw.texture)
+    }
+
+    /// Depth buffer attachment texture
+    pub fn depth(&self) -> &Texture {
+        Texture::from_raw_ref(&self.raw.depth)
+    }
+}
+
+impl Drop for RenderTexture {
+    fn drop(&mut self) {
+        // Unloads the framebuffer together with its color and depth textures
+        if is_window_open() {
+            unsafe { raylib_ffi::UnloadRenderTexture(self.raw) }
+        }
+    }
+}
+
+extern "C" {
+    // Part of rlgl, which is compiled into raylib but not covered by the bindings
+    pub(crate) fn rlGetTextureIdDefault() -> u32;
+}
+
+/// Image file types accepted by LoadImage()
+pub(crate) const IMAGE_FILE_EXTENSIONS: &[&str] = &[".png", ".gif", ".qoi", ".dds"];
+
+/// Turn an image without data into an error
+fn check_image(path: &str, image: raylib_ffi::Image) -> crate::Result<Image> {
+    if image.data.is_null() {
+        Err(Error::load(path, LoadError::InvalidData))
+    } else {
+        Ok(Image { raw: image })
+    }
+}
+
+// Image loading functions
+/// Load image from file into CPU memory (RAM)
+pub fn load_image(file_name: &str) -> crate::Result<Image> {
+    let c_file_name = check_file(file_name, IMAGE_FILE_EXTENSIONS)?;
+    let image = unsafe { raylib_ffi::LoadImage(c_file_name.as_ptr()) };
+    check_image(file_name, image)
+}
+
+/// Load image from RAW file data
+pub fn load_image_raw(
+    file_name: &str,
+    width: i32,
+    height: i32,
+    format: PixelFormat,
+    header_size: i32,
+) -> crate::Result<Image> {
+    let c_file_name = check_file(file_name, &[])?;
+    let image = unsafe {
+        raylib_ffi::LoadImageRaw(
+            c_file_name.as_ptr(),
+            width,
+            height,
+            format as i32,
+            header_size,
+        )
+    };
+    check_image(file_name, image)
+}
+
+/// Load image sequence from file (frames appended to image.data), returns the image and frame count
+pub fn load_image_anim(file_name: &str) -> crate::Result<(Image, i32)> {
+    let c_file_name = check_file(file_name, IMAGE_FILE_EXTENSIONS)?;
+    let mut frames = 0;
+    let image = unsafe { raylib_ffi::LoadImageAnim(c_file_name.as_ptr(), &mut frames) };
+    Ok((check_image(file_name, image)?, frames))
+}
+
+/// Load image from memory buffer, fileType refers to extension: i.e. '.png'
+pub fn load_image_from_memory(file_type: &str, file_data: &[u8]) -> crate::Result<Image> {
+    if !has_extension(file_type, IMAGE_FILE_EXTENSIONS) {
+        return Err(Error::load(file_type, LoadError::UnsupportedFormat));
+    }
+    let image = unsafe {
+        raylib_ffi::LoadImageFromMemory(
+            raylib_ffi::rl_str!(file_type),
+            file_data.as_ptr(),
+            file_data.len() as i32,
+        )
+    };
+    check_image(file_type, image)
+}
+
+/// Load image from screen buffer and (screenshot)
+pub fn load_image_from_screen(_rl: &RaylibHandle) -> Image {
+    Image {
+        raw: unsafe { raylib_ffi::LoadImageFromScreen() },
+    }
+}
+
+/// Load image from GPU texture data
+pub fn load_image_from_texture(_rl: &RaylibHandle, texture: &Texture) -> Image {
+    Image {
+        raw: unsafe { raylib_ffi::LoadImageFromTexture(texture.raw) },
+    }
+}
+
+/// Check if an image is ready
+pub fn is_image_ready(image: &Image) -> bool {
+    unsafe { raylib_ffi::IsImageReady(image.raw) }
+}
+
+/// Export image data to file, returns true on success
+pub fn export_image(image: &Image, file_name: &str) -> bool {
+    unsafe { raylib_ffi::ExportImage(image.raw, raylib_ffi::rl_str!(file_name)) }
+}
+
+/// Export image to memory buffer, fileType refers to extension: i.e. '.png'
+pub fn export_image_to_memory(image: &Image, file_type: &str) -> Option<Vec<u8>> {
+    unsafe {
+        let mut file_size = 0;
+        let data_ptr = raylib_ffi::ExportImageToMemory(
+            image.raw,
+            raylib_ffi::rl_str!(file_type),
+            &mut file_size,
+        );
+        if data_ptr.is_null() {
+            return None;
+        }
+        let data = std::slice::from_raw_parts(data_ptr, file_size as usize).to_vec();
+        raylib_ffi::MemFree(data_ptr as *mut std::ffi::c_void);
+        Some(data)
+    }
+}
+
+/// Export image as code file defining an array of bytes, returns true on success
+pub fn export_image_as_code(image: &Image, file_name: &str) -> bool {
+    unsafe { raylib_ffi::ExportImageAsCode(image.raw, raylib_ffi::rl_str!(file_name)) }
+}
+
+// Image generation functions
+/// Generate image: plain color
+pub fn gen_image_color(width: i32, height: i32, color: Color) -> Image {
+    Image {
+        raw: unsafe { raylib_ffi::GenImageColor(width, height, color) },
+    }
+}
+
+/// Generate image: linear gradient, direction in degrees [0..360], 0=Vertical gradient
+pub fn gen_image_gradient_linear(
+    width: i32,
+    height: i32,
+    direction: i32,
+    start: Color,
+    end: Color,
+) -> Image {
+    Image {
+        raw: unsafe { raylib_ffi::GenImageGradientLinear(width, height, direction, start, end) },
+    }
+}
+
+/// Generate image: radial gradient
+pub fn gen_image_gradient_radial(
+    width: i32,
+    height: i32,
+    density: f32,
+    inner: Color,
+    outer: Color,
+) -> Image {
+    Image {
+        raw: unsafe { raylib_ffi::GenImageGradientRadial(width, height, density, inner, outer) },
+    }
+}
+
+/// Generate image: square gradient
+pub fn gen_image_gradient_square(
+    width: i32,
+    height: i32,
+    density: f32,
+    inner: Color,
+    outer: Color,
+) -> Image {
+    Image {
+        raw: unsafe { raylib_ffi::GenImageGradientSquare(width, height, density, inner, outer) },
+    }
+}
+
+/// Generate image: checked
+pub fn gen_image_checked(
+    width: i32,
+    height: i32,
+    checks_x: i32,
+    checks_y: i32,
+    col1: Color,
+    col2: Color,
+) -> Image {
+    Image {
+        raw: unsafe { raylib_ffi::GenImageChecked(width, height, checks_x, checks_y, col1, col2) },
+    }
+}
+
+/// Generate image: white noise
+pub fn gen_image_white_noise(width: i32, height: i32, factor: f32) -> Image {
+    Image {
+        raw: unsafe { raylib_ffi::GenImageWhiteNoise(width, height, factor) },
+    }
+}
+
+/// Generate image: perlin noise
+pub fn gen_image_perlin_noise(
+    width: i32,
+    height: i32,
+    offset_x: i32,
+    offset_y: i32,
+    scale: f32,
+) -> Image {
+    Image {
+        raw: unsafe { raylib_ffi::GenImagePerlinNoise(width, height, offset_x, offset_y, scale) },
+    }
+}
+
+/// Generate image: cellular algorithm, bigger tileSize means bigger cells
+pub fn gen_image_cellular(width: i32, height: i32, tile_size: i32) -> Image {
+    Image {
+        raw: unsafe { raylib_ffi::GenImageCellular(width, height, tile_size) },
+    }
+}
+
+/// Generate image: grayscale image from text data
+pub fn gen_image_text(width: i32, height: i32, text: &str) -> Image {
+    Image {
+        raw: unsafe { raylib_ffi::GenImageText(width, height, raylib_ffi::rl_str!(text)) },
+    }
+}
+
+// Image manipulation functions
+/// Create an image duplicate (useful for transformations)
+pub fn image_copy(image: &Image) -> Image {
+    Image {
+        raw: unsafe { raylib_ffi::ImageCopy(image.raw) },
+    }
+}
+
+/// Create an image from another image piece
+///
+/// `rec` is clamped to the image, None if nothing is left or the image is compressed.
+pub fn image_from_image(image: &Image, rec: Rectangle) -> Option<Image> {
+    if !is_image_ready(image) || image.format() >= PixelFormat::Dxt1Rgb as i32 {
+        return None;
+    }
+    // ImageFromImage() copies whole rows without checking the source bounds
+    let (x, y) = (rec.x as i32, rec.y as i32);
+    let x0 = x.max(0);
+    let y0 = y.max(0);
+    let x1 = x.saturating_add(rec.width as i32).min(image.width());
+    let y1 = y.saturating_add(rec.height as i32).min(image.height());
+    if x1 <= x0 || y1 <= y0 {
+        return None;
+    }
+    let rec = Rectangle {
+        x: x0 as f32,
+        y: y0 as f32,
+        width: (x1 - x0) as f32,
+        height: (y1 - y0) as f32,
+    };
+    Some(Image {
+        raw: unsafe { raylib_ffi::ImageFromImage(image.raw, rec) },
+    })
+}
+
+/// Create an image from text (default font)
+pub fn image_text(_rl: &RaylibHandle, text: &str, font_size: i32, color: Color) -> Image {
+    Image {
+        raw: unsafe { raylib_ffi::ImageText(raylib_ffi::rl_str!(text), font_size, color) },
+    }
+}
+
+/// Create an image from text (custom sprite font)
+pub fn image_text_ex(font: &Font, text: &str, font_size: f32, spacing: f32, tint: Color) -> Image {
+    Image {
+        raw: unsafe {
+            raylib_ffi::ImageTextEx(
+                font.as_raw(),
+                raylib_ffi::rl_str!(text),
+                font_size,
+                spacing,
+                tint,
+            )
+        },
+    }
+}
+
+/// Convert image data to desired format
+pub fn image_format(image: &mut Image, new_format: PixelFormat) {
+    unsafe { raylib_ffi::ImageFormat(&mut image.raw, new_format as i32) }
+}
+
+/// Convert image to POT (power-of-two)
+pub fn image_to_pot(image: &mut Image, fill: Color) {
+    unsafe { raylib_ffi::ImageToPOT(&mut image.raw, fill) }
+}
+
+/// Crop an image to a defined rectangle
+pub fn image_crop(image: &mut Image, crop: Rectangle) {
+    unsafe { raylib_ffi::ImageCrop(&mut image.raw, crop) }
+}
+
+/// Crop image depending on alpha value
+pub fn image_alpha_crop(image: &mut Image, threshold: f32) {
+    unsafe { raylib_ffi::ImageAlphaCrop(&mut image.raw, threshold) }
+}
+
+/// Clear alpha channel to desired color
+pub fn image_alpha_clear(image: &mut Image, color: Color, threshold: f32) {
+    unsafe { raylib_ffi::ImageAlphaClear(&mut image.raw, color, threshold) }
+}
+
+/// Apply alpha mask to image
+pub fn image_alpha_mask(image: &mut Image, alpha_mask: &Image) {
+    unsafe { raylib_ffi::ImageAlphaMask(&mut image.raw, alpha_mask.raw) }
+}
+
+/// Premultiply alpha channel
+pub fn image_alpha_premultiply(image: &mut Image) {
+    unsafe { raylib_ffi::ImageAlphaPremultiply(&mut image.raw) }
+}
+
+/// Apply Gaussian blur using a box blur approximation
+pub fn image_blur_gaussian(image: &mut Image, blur_size: i32) {
+    unsafe { raylib_ffi::ImageBlurGaussian(&mut image.raw, blur_size) }
+}
+
+/// Resize image (Bicubic scaling algorithm)
+pub fn image_resize(image: &mut Image, new_width: i32, new_height: i32) {
+    unsafe { raylib_ffi::ImageResize(&mut image.raw, new_width, new_height) }
+}
+
+/// Resize image (Nearest-Neighbor scaling algorithm)
+pub fn image_resize_nn(image: &mut Image, new_width: i32, new_height: i32) {
+    unsafe { raylib_ffi::ImageResizeNN(&mut image.raw, new_width, new_height) }
+}
+
+/// Resize canvas and fill with color
+pub fn image_resize_canvas(
+    image: &mut Image,
+    new_width: i32,
+    new_height: i32,
+    offset_x: i32,
+    offset_y: i32,
+    fill: Color,
+) {
+    unsafe {
+        raylib_ffi::ImageResizeCanvas(
+            &mut image.raw,
+            new_width,
+            new_height,
+            offset_x,
+            offset_y,
+            fill,
+        )
+    }
+}
+
+/// Compute all mipmap levels for a provided image
+pub fn image_mipmaps(image: &mut Image) {
+    unsafe { raylib_ffi::ImageMipmaps(&mut image.raw) }
+}
+
+/// Dither image data to 16bpp or lower (Floyd-Steinberg dithering)
+pub fn image_dither(image: &mut Image, r_bpp: i32, g_bpp: i32, b_bpp: i32, a_bpp: i32) {
+    unsafe { raylib_ffi::ImageDither(&mut image.raw, r_bpp, g_bpp, b_bpp, a_bpp) }
+}
+
+/// Flip image vertically
+pub fn image_flip_vertical(image: &mut Image) {
+    unsafe { raylib_ffi::ImageFlipVertical(&mut image.raw) }
+}
+
+/// Flip image horizontally
+pub fn image_flip_horizontal(image: &mut Image) {
+    unsafe { raylib_ffi::ImageFlipHorizontal(&mut image.raw) }
+}
+
+/// Rotate image by input angle in degrees (-359 to 359)
+pub fn image_rotate(image: &mut Image, degrees: i32) {
+    unsafe { raylib_ffi::ImageRotate(&mut image.raw, degrees) }
+}
+
+/// Rotate image clockwise 90deg
+pub fn image_rotate_cw(image: &mut Image) {
+    unsafe { raylib_ffi::ImageRotateCW(&mut image.raw) }
+}
+
+/// Rotate image counter-clockwise 90deg
+pub fn image_rotate_ccw(image: &mut Image) {
+    unsafe { raylib_ffi::ImageRotateCCW(&mut image.raw) }
+}
+
+/// Modify image color: tint
+pub fn image_color_tint(image: &mut Image, color: Color) {
+    unsafe { raylib_ffi::ImageColorTint(&mut image.raw, color) }
+}
+
+/// Modify image color: invert
+pub fn image_color_invert(image: &mut Image) {
+    unsafe { raylib_ffi::ImageColorInvert(&mut image.raw) }
+}
+
+/// Modify image color: grayscale
+pub fn image_color_grayscale(image: &mut Image) {
+    unsafe { raylib_ffi::ImageColorGrayscale(&mut image.raw) }
+}
+
+/// Modify image color: contrast (-100 to 100)
+pub fn image_color_contrast(image: &mut Image, contrast: f32) {
+    unsafe { raylib_ffi::ImageColorContrast(&mut image.raw, contrast) }
+}
+
+/// Modify image color: brightness (-255 to 255)
+pub fn image_color_brightness(image: &mut Image, brightness: i32) {
+    unsafe { raylib_ffi::ImageColorBrightness(&mut image.raw, brightness) }
+}
+
+/// Modify image color: replace color
+pub fn image_color_replace(image: &mut Image, color: Color, replace: Color) {
+    unsafe { raylib_ffi::ImageColorReplace(&mut image.raw, color, replace) }
+}
+
+/// Load color data from image as a Color array (RGBA - 32bit)
+pub fn load_image_colors(image: &Image) -> Vec<Color> {
+    unsafe {
+        let colors_ptr = raylib_ffi::LoadImageColors(image.raw);
+        if colors_ptr.is_null() {
+            return Vec::new();
+        }
+        let count = (image.raw.width * image.raw.height) as usize;
+        let colors = std::slice::from_raw_parts(colors_ptr, count).to_vec();
+        raylib_ffi::UnloadImageColors(colors_ptr);
+        colors
+    }
+}
+
+/// Load colors palette from image as a Color array (RGBA - 32bit)
+pub fn load_image_palette(image: &Image, max_palette_size: i32) -> Vec<Color> {
+    unsafe {
+        let mut color_count = 0;
+        let palette_ptr =
+            raylib_ffi::LoadImagePalette(image.raw, max_palette_size, &mut color_count);
+        if palette_ptr.is_null() {
+            return Vec::new();
+        }
+        let palette = std::slice::from_raw_parts(palette_ptr, color_count as usize).to_vec();
+        raylib_ffi::UnloadImagePalette(palette_ptr);
+        palette
+    }
+}
+
+/// Get image alpha border rectangle
+pub fn get_image_alpha_border(image: &Image, threshold: f32) -> Rectangle {
+    unsafe { raylib_ffi::GetImageAlphaBorder(image.raw, threshold) }
+}
+
+/// Get image pixel color at (x, y) position
+pub fn get_image_color(image: &Image, x: i32, y: i32) -> Color {
+    unsafe { raylib_ffi::GetImageColor(image.raw, x, y) }
+}
+
+// Image drawing functions
+/// Clear image background with given color
+pub fn image_clear_background(dst: &mut Image, color: Color) {
+    unsafe { raylib_ffi::ImageClearBackground(&mut dst.raw, color) }
+}
+
+/// Draw pixel within an image
+pub fn image_draw_pixel(dst: &mut Image, pos_x: i32, pos_y: i32, color: Color) {
+    unsafe { raylib_ffi::ImageDrawPixel(&mut dst.raw, pos_x, pos_y, color) }
+}
+
+/// Draw pixel within an image (Vector version)
+pub fn image_draw_pixel_v(dst: &mut Image, position: Vector2, color: Color) {
+    unsafe { raylib_ffi::ImageDrawPixelV(&mut dst.raw, position.into(), color) }
+}
+
+/// Draw line within an image
+pub fn image_draw_line(
+    dst: &mut Image,
+    start_pos_x: i32,
+    start_pos_y: i32,
+    end_pos_x: i32,
+    end_pos_y: i32,
+    color: Color,
+) {
+    unsafe {
+        raylib_ffi::ImageDrawLine(
+            &mut dst.raw,
+            start_pos_x,
+            start_pos_y,
+            end_pos_x,
+            end_pos_y,
+            color,
+        )
+    }
+}
+
+/// Draw line within an image (Vector version)
+pub fn image_draw_line_v(dst: &mut Image, start: Vector2, end: Vector2, color: Color) {
+    unsafe { raylib_ffi::ImageDrawLineV(&mut dst.raw, start.into(), end.into(), color) }
+}
+
+/// Draw a filled circle within an image
+pub fn image_draw_circle(dst: &mut Image, center_x: i32, center_y: i32, radius: i32, color: Color) {
+    unsafe { raylib_ffi::ImageDrawCircle(&mut dst.raw, center_x, center_y, radius, color) }
+}
+
+/// Draw a filled circle within an image (Vector version)
+pub fn image_draw_circle_v(dst: &mut Image, center: Vector2, radius: i32, color: Color) {
+    unsafe { raylib_ffi::ImageDrawCircleV(&mut dst.raw, center.into(), radius, color) }
+}
+
+/// Draw circle outline within an image
+pub fn image_draw_circle_lines(
+    dst: &mut Image,
+    center_x: i32,
+    center_y: i32,
+    radius: i32,
+    color: Color,
+) {
+    unsafe { raylib_ffi::ImageDrawCircleLines(&mut dst.raw, center_x, center_y, radius, color) }
+}
+
+/// Draw circle outline within an image (Vector version)
+pub fn image_draw_circle_lines_v(dst: &mut Image, center: Vector2, radius: i32, color: Color) {
+    unsafe { raylib_ffi::ImageDrawCircleLinesV(&mut dst.raw, center.into(), radius, color) }
+}
+
+/// Draw rectangle within an image
+pub fn image_draw_rectangle(
+    dst: &mut Image,
+    pos_x: i32,
+    pos_y: i32,
+    width: i32,
+    height: i32,
+    color: Color,
+) {
+    unsafe { raylib_ffi::ImageDrawRectangle(&mut dst.raw, pos_x, pos_y, width, height, color) }
+}
+
+/// Draw rectangle within an image (Vector version)
+pub fn image_draw_rectangle_v(dst: &mut Image, position: Vector2, size: Vector2, color: Color) {
+    unsafe { raylib_ffi::ImageDrawRectangleV(&mut dst.raw, position.into(), size.into(), color) }
+}
+
+/// Draw rectangle within an image
+pub fn image_draw_rectangle_rec(dst: &mut Image, rec: Rectangle, color: Color) {
+    unsafe { raylib_ffi::ImageDrawRectangleRec(&mut dst.raw, rec, color) }
+}
+
+/// Draw rectangle lines within an image
+pub fn image_draw_rectangle_lines(dst: &mut Image, rec: Rectangle, thick: i32, color: Color) {
+    unsafe { raylib_ffi::ImageDrawRectangleLines(&mut dst.raw, rec, thick, color) }
+}
+
+/// Draw a source image within a destination image (tint applied to source)
+pub fn image_draw(
+    dst: &mut Image,
+    src: &Image,
+    src_rec: Rectangle,
+    dst_rec: Rectangle,
+    tint: Color,
+) {
+    unsafe { raylib_ffi::ImageDraw(&mut dst.raw, src.raw, src_rec, dst_rec, tint) }
+}
+
+/// Draw text (using default font) within an image (destination)
+pub fn image_draw_text(
+    _rl: &RaylibHandle,
+    dst: &mut Image,
+    text: &str,
+    pos_x: i32,
+    pos_y: i32,
+    font_size: i32,
+    color: Color,
+) {
+    unsafe {
+        raylib_ffi::ImageDrawText(
+            &mut dst.raw,
+            raylib_ffi::rl_str!(text),
+            pos_x,
+            pos_y,
+            font_size,
+            color,
+        )
+    }
+}
+
+/// Draw text (custom sprite font) within an image (destination)
+pub fn image_draw_text_ex(
+    dst: &mut Image,
+    font: &Font,
+    text: &str,
+    position: Vector2,
+    font_size: f32,
+    spacing: f32,
+    tint: Color,
+) {
+    unsafe {
+        raylib_ffi::ImageDrawTextEx(
+            &mut dst.raw,
+            font.as_raw(),
+            raylib_ffi::rl_str!(text),
+            position.into(),
+            font_size,
+            spacing,
+            tint,
+        )
+    }
+}
+
+// Texture loading functions
+/// Turn a texture that failed to upload into an error
+fn check_texture(path: &str, texture: raylib_ffi::Texture) -> crate::Result<Texture> {
+    if texture.id == 0 {
+        Err(Error::load(path, LoadError::GpuUploadFailed))
+    } else {
+        Ok(Texture { raw: texture })
+    }
+}
+
+/// Load texture from file into GPU memory (VRAM)
+pub fn load_texture(_rl: &RaylibHandle, file_name: &str) -> crate::Result<Texture> {
+    // Loaded through an Image to tell decoding and upload errors apart
+    let image = load_image(file_name)?;
+    let texture = unsafe { raylib_ffi::LoadTextureFromImage(image.raw) };
+    check_texture(file_name, texture)
+}
+
+/// Load texture from image data
+pub fn load_texture_from_image(_rl: &RaylibHandle, image: &Image) -> crate::Result<Texture> {
+    if !is_image_ready(image) {
+        return Err(Error::load("<image>", LoadError::InvalidData));
+    }
+    let texture = unsafe { raylib_ffi::LoadTextureFromImage(image.raw) };
+    check_texture("<image>", texture)
+}
+
+/// Load cubemap from image, multiple image cubemap layouts supported
+pub fn load_texture_cubemap(
+    _rl: &RaylibHandle,
+    image: &Image,
+    layout: CubemapLayout,
+) -> crate::Result<Texture> {
+    if !is_image_ready(image) {
+        return Err(Error::load("<image>", LoadError::InvalidData));
+    }
+    let cubemap = unsafe { raylib_ffi::LoadTextureCubemap(image.raw, layout as i32) };
+    check_texture("<image>", cubemap)
+}
+
+/// Load texture for rendering (framebuffer)
+pub fn load_render_texture(
+    _rl: &RaylibHandle,
+    width: i32,
+    height: i32,
+) -> crate::Result<RenderTexture> {
+    let target = unsafe { raylib_ffi::LoadRenderTexture(width, height) };
+    if target.id == 0 {
+        return Err(Error::load("<render texture>", LoadError::GpuUploadFailed));
+    }
+    Ok(RenderTexture { raw: target })
+}
+
+/// Check if a texture is ready
+pub fn is_texture_ready(texture: &Texture) -> bool {
+    unsafe { raylib_ffi::IsTextureReady(texture.raw) }
+}
+
+/// Check if a render texture is ready
+pub fn is_render_texture_ready(target: &RenderTexture) -> bool {
+    unsafe { raylib_ffi::IsRenderTextureReady(target.raw) }
+}
+
+/// Update GPU texture with new data, `pixels` must be in the texture format
+///
+/// # Panics
+/// If `pixels` is smaller than the texture data size
+pub fn update_texture(_rl: &RaylibHandle, texture: &mut Texture, pixels: &[u8]) {
+    let size = get_pixel_data_size(texture.width(), texture.height(), texture.format());
+    assert!(
+        pixels.len() >= size,
+        "update_texture: {} bytes of pixel data, texture needs {}",
+        pixels.len(),
+        size
+    );
+    unsafe { raylib_ffi::UpdateTexture(texture.raw, pixels.as_ptr() as *const std::ffi::c_void) }
+}
+
+/// Update GPU texture rectangle with new data, `pixels` must be in the texture format
+///
+/// # Panics
+/// If `pixels` is smaller than the rectangle data size
+pub fn update_texture_rec(
+    _rl: &RaylibHandle,
+    texture: &mut Texture,
+    rec: Rectangle,
+    pixels: &[u8],
+) {
+    let size = get_pixel_data_size(rec.width as i32, rec.height as i32, texture.format());
+    assert!(
+        pixels.len() >= size,
+        "update_texture_rec: {} bytes of pixel data, rectangle needs {}",
+        pixels.len(),
+        size
+    );
+    unsafe {
+        raylib_ffi::UpdateTextureRec(texture.raw, rec, pixels.as_ptr() as *const std::ffi::c_void)
+    }
+}
+
+/// Get pixel data size in bytes for certain format
+pub fn get_pixel_data_size(width: i32, height: i32, format: i32) -> usize {
+    unsafe { raylib_ffi::GetPixelDataSize(width, height, format) as usize }
+}
+
+// Texture configuration functions
+/// Generate GPU mipmaps for a texture
+pub fn gen_texture_mipmaps(_rl: &RaylibHandle, texture: &mut Texture) {
+    unsafe { raylib_ffi::GenTextureMipmaps(&mut texture.raw) }
+}
+
+/// Set texture scaling filter mode
+pub fn set_texture_filter(_rl: &RaylibHandle, texture: &Texture, filter: TextureFilter) {
+    unsafe { raylib_ffi::SetTextureFilter(texture.raw, filter as i32) }
+}
+
+/// Set texture wrapping mode
+pub fn set_texture_wrap(_rl: &RaylibHandle, texture: &Texture, wrap: TextureWrap) {
+    unsafe { raylib_ffi::SetTextureWrap(texture.raw, wrap as i32) }
+}
+
+// Texture drawing functions
+/// Draw a Texture2D
+pub fn draw_texture(
+    _d: &mut impl RaylibDraw,
+    texture: &Texture,
+    pos_x: i32,
+    pos_y: i32,
+    tint: Color,
+) {
+    unsafe { raylib_ffi::DrawTexture(texture.raw, pos_x, pos_y, tint) }
+}
+
+/// Draw a Texture2D with position defined as Vector2
+pub fn draw_texture_v(_d: &mut impl RaylibDraw, texture: &Texture, position: Vector2, tint: Color) {
+    unsafe { raylib_ffi::DrawTextureV(texture.raw, position.into(), tint) }
+}
+
+/// Draw a Texture2D with extended parameters
+pub fn draw_texture_ex(
+    _d: &mut impl RaylibDraw,
+    texture: &Texture,
+    position: Vector2,
+    rotation: f32,
+    scale: f32,
+    tint: Color,
+) {
+    unsafe { raylib_ffi::DrawTextureEx(texture.raw, position.into(), rotation, scale, tint) }
+}
+
+/// Draw a part of a texture defined by a rectangle
+pub fn draw_texture_rec(
+    _d: &mut impl RaylibDraw,
+    texture: &Texture,
+    source: Rectangle,
+    position: Vector2,
+    tint: Color,
+) {
+    unsafe { raylib_ffi::DrawTextureRec(texture.raw, source, position.into(), tint) }
+}
+
+/// Draw a part of a texture defined by a rectangle with 'pro' parameters
+pub fn draw_texture_pro(
+    _d: &mut impl RaylibDraw,
+    texture: &Texture,
+    source: Rectangle,
+    dest: Rectangle,
+    origin: Vector2,
+    rotation: f32,
+    tint: Color,
+) {
+    unsafe { raylib_ffi::DrawTexturePro(texture.raw, source, dest, origin.into(), rotation, tint) }
+}
+
+/// Draws a texture (or part of it) that stretches or shrinks nicely
+pub fn draw_texture_npatch(
+    _d: &mut impl RaylibDraw,
+    texture: &Texture,
+    n_patch_info: NPatchInfo,
+    dest: Rectangle,
+    origin: Vector2,
+    rotation: f32,
+    tint: Color,
+) {
+    unsafe {
+        raylib_ffi::DrawTextureNPatch(
+            texture.raw,
+            n_patch_info,
+            dest,
+            origin.into(),
+            rotation,
+            tint,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: Color = Color {
+        r: 230,
+        g: 41,
+        b: 55,
+        a: 255,
+    };
+    const WHITE: Color = Color {
+        r: 255,
+        g: 255,
+        b: 255,
+        a: 255,
+    };
+    const BLACK: Color = Color {
+        r: 0,
+        g: 0,
+        b: 0,
+        a: 255,
+    };
+    const BLANK: Color = Color {
+        r: 0,
+        g: 0,
+        b: 0,
+        a: 0,
+    };
+
+    fn rgba(color: Color) -> [u8; 4] {
+        [color.r, color.g, color.b, color.a]
+    }
+
+    fn pixel(image: &Image, x: i32, y: i32) -> [u8; 4] {
+        rgba(get_image_color(image, x, y))
+    }
+
+    #[test]
+    fn gen_image_color_fills_every_pixel() {
+        let image = gen_image_color(4, 3, RED);
+        assert!(is_image_ready(&image));
+        assert_eq!((image.width(), image.height()), (4, 3));
+        assert_eq!(image.mipmaps(), 1);
+        assert_eq!(image.format(), PixelFormat::R8g8b8a8 as i32);
+        let colors = load_image_colors(&image);
+        assert_eq!(colors.len(), 12);
+        assert!(colors.iter().all(|&c| rgba(c) == rgba(RED)));
+    }
+
+    #[test]
+    fn gen_image_checked_alternates() {
+        let image = gen_image_checked(4, 4, 2, 2, WHITE, BLACK);
+        assert_eq!(pixel(&image, 0, 0), rgba(WHITE));
+        assert_eq!(pixel(&image, 1, 1), rgba(WHITE));
+        assert_eq!(pixel(&image, 2, 0), rgba(BLACK));
+        assert_eq!(pixel(&image, 0, 3), rgba(BLACK));
+        assert_eq!(pixel(&image, 3, 3), rgba(WHITE));
+
+        let palette = load_image_palette(&image, 8);
+        assert_eq!(palette.len(), 2);
+        assert!(palette.iter().any(|&c| rgba(c) == rgba(WHITE)));
+        assert!(palette.iter().any(|&c| rgba(c) == rgba(BLACK)));
+    }
+
+    #[test]
+    fn gen_image_gradient_linear_is_vertical() {
+        let image = gen_image_gradient_linear(2, 4, 0, BLACK, WHITE);
+        assert_eq!(pixel(&image, 0, 0), rgba(BLACK));
+        assert_eq!(pixel(&image, 1, 0), rgba(BLACK));
+        for y in 1..4 {
+            let above = pixel(&image, 0, y - 1);
+            let here = pixel(&image, 0, y);
+            assert!(here[0] > above[0]);
+            assert_eq!(here[3], 255);
+        }
+    }
+
+    #[test]
+    fn gen_image_white_noise_factor() {
+        let black = load_image_colors(&gen_image_white_noise(8, 8, 0.0));
+        assert!(black.iter().all(|&c| rgba(c) == rgba(BLACK)));
+        let white = load_image_colors(&gen_image_white_noise(8, 8, 1.0));
+        assert!(white.iter().all(|&c| rgba(c) == rgba(WHITE)));
+    }
+
+    #[test]
+    fn image_crop_clamps_to_the_image() {
+        let mut image = gen_image_checked(4, 4, 2, 2, WHITE, BLACK);
+        image_crop(
+            &mut image,
+            Rectangle {
+                x: 2.0,
+                y: 0.0,
+                width: 2.0,
+                height: 2.0,
+            },
+        );
+        assert_eq!((image.width(), image.height()), (2, 2));
+        assert!(load_image_colors(&image)
+            .iter()
+            .all(|&c| rgba(c) == rgba(BLACK)));
+
+        let mut image = gen_image_checked(4, 4, 2, 2, WHITE, BLACK);
+        image_crop(
+            &mut image,
+            Rectangle {
+                x: 2.0,
+                y: 2.0,
+                width: 10.0,
+                height: 10.0,
+            },
+        );
+        assert_eq!((image.width(), image.height()), (2, 2));
+        assert_eq!(pixel(&image, 0, 0), rgba(WHITE));
+    }
+
+    #[test]
+    fn image_from_image_clamps_the_rectangle() {
+        let image = gen_image_checked(4, 4, 2, 2, WHITE, BLACK);
+        let piece = image_from_image(
+            &image,
+            Rectangle {
+                x: -3.0,
+                y: 2.0,
+                width: 5.0,
+                height: 100.0,
+            },
+        )
+        .unwrap();
+        assert_eq!((piece.width(), piece.height()), (2, 2));
+        assert_eq!(pixel(&piece, 0, 0), rgba(BLACK));
+
+        let outside = Rectangle {
+            x: 4.0,
+            y: 0.0,
+            width: 2.0,
+            height: 2.0,
+        };
+        assert!(image_from_image(&image, outside).is_none());
+        let negative = Rectangle {
+            x: -8.0,
+            y: -8.0,
+            width: 4.0,
+            height: 4.0,
+        };
+        assert!(image_from_image(&image, negative).is_none());
+    }
+
+    #[test]
+    fn image_resize_changes_size() {
+        let mut image = gen_image_checked(2, 2, 1, 1, WHITE, BLACK);
+        image_resize_nn(&mut image, 4, 4);
+        assert_eq!((image.width(), image.height()), (4, 4));
+        assert_eq!(image.format(), PixelFormat::R8g8b8a8 as i32);
+        assert_eq!(pixel(&image, 1, 1), rgba(WHITE));
+        assert_eq!(pixel(&image, 2, 1), rgba(BLACK));
+        assert_eq!(pixel(&image, 2, 2), rgba(WHITE));
+
+        let mut image = gen_image_color(8, 6, RED);
+        image_resize(&mut image, 3, 2);
+        assert_eq!((image.width(), image.height()), (3, 2));
+        assert_eq!(load_image_colors(&image).len(), 6);
+    }
+
+    #[test]
+    fn image_resize_canvas_and_alpha_border() {
+        let mut image = gen_image_color(2, 2, RED);
+        image_resize_canvas(&mut image, 6, 6, 1, 2, BLANK);
+        assert_eq!((image.width(), image.height()), (6, 6));
+        assert_eq!(pixel(&image, 0, 0), rgba(BLANK));
+        assert_eq!(pixel(&image, 1, 2), rgba(RED));
+
+        let border = get_image_alpha_border(&image, 0.5);
+        assert_eq!(
+            (border.x, border.y, border.width, border.height),
+            (1.0, 2.0, 2.0, 2.0)
+        );
+        let empty = get_image_alpha_border(&gen_image_color(2, 2, BLANK), 0.5);
+        assert_eq!((empty.width, empty.height), (0.0, 0.0));
+    }
+
+    #[test]
+    fn image_format_converts_colors() {
+        let mut image = gen_image_color(2, 2, WHITE);
+        image_format(&mut image, PixelFormat::Grayscale);
+        assert_eq!(image.format(), PixelFormat::Grayscale as i32);
+        assert_eq!(get_pixel_data_size(2, 2, image.format()), 4);
+        assert_eq!(pixel(&image, 1, 1), rgba(WHITE));
+    }
+}